@@ -7,14 +7,17 @@ use leptos::{
     prelude::*,
     tachys::{html::directive::IntoDirective, reactive_graph::OwnedView},
 };
-use leptos_meta::{Html, provide_meta_context};
+use leptos_meta::{Html, Meta, provide_meta_context};
 use leptos_use::UseCookieOptions;
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use crate::{
     Scope,
+    detectors::{self, LocaleDetectors},
     fetch_locale::{self, signal_maybe_once_then},
     locale_traits::*,
+    preferences::{MeasurementSystem, PreferencesContext, UserPreferences, Weekday},
+    storage::{LocaleStorage, StorageHandle},
 };
 
 pub use leptos_use::UseLocalesOptions;
@@ -34,6 +37,17 @@ pub struct I18nContext<L: Locale, S: Scope<L> = <L as Locale>::Keys> {
     locale_signal: RwSignal<AnyLocale>,
     #[cfg(not(feature = "unified_contexts"))]
     locale_signal: RwSignal<L>,
+    preferences: RwSignal<UserPreferences>,
+    #[cfg(feature = "key_inspector")]
+    inspect_keys: RwSignal<bool>,
+    #[cfg(feature = "display_mode")]
+    display_mode: RwSignal<crate::display_mode::DisplayMode>,
+    #[cfg(feature = "track_fallbacks")]
+    fallback_hooks: crate::fallbacks::FallbackHooks<L>,
+    #[cfg(feature = "translation_overrides")]
+    overrides: crate::overrides::TranslationOverrides<L>,
+    #[cfg(feature = "dynamic_load")]
+    translations_error: RwSignal<Option<crate::loading::TranslationsLoadError>>,
     locale_marker: PhantomData<L>,
     scope_marker: PhantomData<S>,
 }
@@ -117,11 +131,204 @@ impl<L: Locale, S: Scope<L>> I18nContext<L, S> {
         }
     }
 
+    /// Return the formatting preferences of the user, subscribing to changes.
+    #[inline]
+    #[track_caller]
+    pub fn preferences(self) -> UserPreferences {
+        self.preferences.get()
+    }
+
+    /// Return the formatting preferences of the user but does not subscribe to changes.
+    #[inline]
+    #[track_caller]
+    pub fn preferences_untracked(self) -> UserPreferences {
+        self.preferences.get_untracked()
+    }
+
+    /// Set the formatting preferences of the user, see `leptos_i18n::preferences`.
+    ///
+    /// Subcontexts share the preferences of the context they are created under.
+    #[inline]
+    #[track_caller]
+    pub fn set_preferences(self, preferences: UserPreferences) {
+        self.preferences.set(preferences);
+    }
+
+    /// Update the formatting preferences of the user in place.
+    #[inline]
+    #[track_caller]
+    pub fn update_preferences(self, f: impl FnOnce(&mut UserPreferences)) {
+        self.preferences.update(f);
+    }
+
+    /// Return the preferred currency of the user, subscribing to changes.
+    #[inline]
+    #[track_caller]
+    pub fn currency(self) -> Option<Cow<'static, str>> {
+        self.preferences
+            .with(|preferences| preferences.currency.clone())
+    }
+
+    /// Return the time zone of the user, subscribing to changes.
+    #[inline]
+    #[track_caller]
+    pub fn time_zone(self) -> Option<Cow<'static, str>> {
+        self.preferences
+            .with(|preferences| preferences.time_zone.clone())
+    }
+
+    /// Return the preferred units of measurement of the user, subscribing to changes.
+    #[inline]
+    #[track_caller]
+    pub fn measurement_system(self) -> Option<MeasurementSystem> {
+        self.preferences
+            .with(|preferences| preferences.measurement_system)
+    }
+
+    /// Return the day weeks start on for the user, subscribing to changes.
+    #[inline]
+    #[track_caller]
+    pub fn first_day_of_week(self) -> Option<Weekday> {
+        self.preferences
+            .with(|preferences| preferences.first_day_of_week)
+    }
+
+    /// Load the translations for the given locale, so switching to it doesn't wait for them.
+    ///
+    /// With namespaces, every namespace is loaded, use `preload_scope` to only load one.
+    /// Without the "dynamic_load" feature, this resolves immediately.
+    pub fn preload(self, locale: L) -> impl Future<Output = ()> + Send + Sync + 'static {
+        <L::Keys as LocaleKeys>::preload(locale)
+    }
+
+    /// Load the translations of the given scope for the given locale, for example a single namespace.
+    pub fn preload_scope<NS: Scope<L>>(
+        self,
+        locale: L,
+    ) -> impl Future<Output = ()> + Send + Sync + 'static {
+        <NS::Keys as LocaleKeys>::preload(locale)
+    }
+
+    /// Start loading, in the background, the translations used by the current locale for the given locale.
+    pub fn prefetch(self, locale: L) {
+        if !<L::Keys as LocaleKeys>::is_loaded(locale) {
+            let current_locale = self.get_locale_untracked();
+            leptos::task::spawn_local(<L::Keys as LocaleKeys>::preload_matching(
+                locale,
+                current_locale,
+            ));
+        }
+    }
+
+    /// Set the locale once the translations used by the current locale are loaded for the new one,
+    /// so the whole page switches at once instead of showing the translations as they arrive.
+    ///
+    /// Without the "dynamic_load" feature, this is the same as `set_locale`.
+    pub fn set_locale_when_loaded(self, lang: L) {
+        let current_locale = self.get_locale_untracked();
+        if <L::Keys as LocaleKeys>::is_loaded(lang) {
+            self.set_locale(lang);
+            return;
+        }
+        let preload = <L::Keys as LocaleKeys>::preload_matching(lang, current_locale);
+        leptos::task::spawn_local(async move {
+            preload.await;
+            // the context could have been dropped or the locale changed while loading
+            if !self.locale_signal.is_disposed() && self.get_locale_untracked() == current_locale {
+                self.set_locale(lang);
+            }
+        });
+    }
+
+    /// Return the last error that happened while loading translations, subscribing to any changes.
+    #[cfg(feature = "dynamic_load")]
+    pub fn translations_error(self) -> Option<crate::loading::TranslationsLoadError> {
+        self.translations_error.get()
+    }
+
+    /// Return the last error that happened while loading translations without subscribing to changes.
+    #[cfg(feature = "dynamic_load")]
+    pub fn translations_error_untracked(self) -> Option<crate::loading::TranslationsLoadError> {
+        self.translations_error.get_untracked()
+    }
+
+    /// Clear the last loading error, for exemple once a banner showing it is dismissed.
+    #[cfg(feature = "dynamic_load")]
+    pub fn clear_translations_error(self) {
+        self.translations_error.set(None);
+    }
+
+    /// Return a handle to the runtime overrides of the translations, see `leptos_i18n::overrides`.
+    ///
+    /// Subcontexts share the overrides of the context they are created under.
+    #[cfg(feature = "translation_overrides")]
+    pub const fn overrides(self) -> crate::overrides::TranslationOverrides<L> {
+        self.overrides
+    }
+
+    /// Enable or disable the key inspector, see `leptos_i18n::inspector`.
+    ///
+    /// Does nothing in release builds.
+    #[cfg(feature = "key_inspector")]
+    pub fn set_inspect_keys(self, enabled: bool) {
+        self.inspect_keys.set(enabled);
+    }
+
+    /// Return `true` if the key inspector is enabled, subscribing to changes.
+    ///
+    /// Always `false` in release builds.
+    #[cfg(feature = "key_inspector")]
+    pub fn inspect_keys(self) -> bool {
+        cfg!(debug_assertions) && self.inspect_keys.get()
+    }
+
+    /// Set how the translations are displayed, the views are updated to the new mode.
+    ///
+    /// Subcontexts share the mode of the context they are created under, see `leptos_i18n::display_mode`.
+    #[cfg(feature = "display_mode")]
+    pub fn set_display_mode(self, mode: crate::display_mode::DisplayMode) {
+        self.display_mode.set(mode);
+    }
+
+    /// Return the current display mode, subscribing to changes.
+    #[cfg(feature = "display_mode")]
+    pub fn display_mode(self) -> crate::display_mode::DisplayMode {
+        self.display_mode.get()
+    }
+
+    /// Register a hook called each time a key is rendered with the value of another locale,
+    /// with the current locale and the path of the key. See `leptos_i18n::fallbacks`.
+    ///
+    /// The hook is removed when the current reactive owner is cleaned up.
+    #[cfg(feature = "track_fallbacks")]
+    pub fn on_fallback(self, hook: impl Fn(L, &'static str) + Send + Sync + 'static) {
+        let hooks = self.fallback_hooks;
+        let id = hooks.add(hook);
+        on_cleanup(move || hooks.remove(id));
+    }
+
+    /// Same as `translations_error` but as a `Result`, which can be rendered inside an `ErrorBoundary`.
+    #[cfg(feature = "dynamic_load")]
+    pub fn translations_result(self) -> Result<(), crate::loading::TranslationsLoadError> {
+        self.translations_error().map_or(Ok(()), Err)
+    }
+
     /// Map the context to a new scope
     #[inline]
     pub const fn scope<NS: Scope<L>>(self) -> I18nContext<L, NS> {
         I18nContext {
             locale_signal: self.locale_signal,
+            preferences: self.preferences,
+            #[cfg(feature = "key_inspector")]
+            inspect_keys: self.inspect_keys,
+            #[cfg(feature = "display_mode")]
+            display_mode: self.display_mode,
+            #[cfg(feature = "track_fallbacks")]
+            fallback_hooks: self.fallback_hooks,
+            #[cfg(feature = "translation_overrides")]
+            overrides: self.overrides,
+            #[cfg(feature = "dynamic_load")]
+            translations_error: self.translations_error,
             locale_marker: PhantomData,
             scope_marker: PhantomData,
         }
@@ -131,8 +338,39 @@ impl<L: Locale, S: Scope<L>> I18nContext<L, S> {
         #[cfg(feature = "unified_contexts")]
         {
             let locale_signal = use_context::<RwSignal<AnyLocale>>()?;
+            let PreferencesContext(preferences) = use_context()?;
+            #[cfg(feature = "key_inspector")]
+            let crate::inspector::InspectorContext(inspect_keys) = use_context()?;
+            #[cfg(feature = "display_mode")]
+            let crate::display_mode::DisplayModeContext(display_mode) = use_context()?;
+            // the hooks are per locale type, a context of another type may have been initialized.
+            #[cfg(feature = "track_fallbacks")]
+            let fallback_hooks = use_context().unwrap_or_else(|| {
+                let hooks = crate::fallbacks::FallbackHooks::new();
+                provide_context(hooks);
+                hooks
+            });
+            #[cfg(feature = "translation_overrides")]
+            let overrides = use_context().unwrap_or_else(|| {
+                let overrides = crate::overrides::TranslationOverrides::new();
+                provide_context(overrides);
+                overrides
+            });
+            #[cfg(feature = "dynamic_load")]
+            let crate::loading::TranslationsErrorContext(translations_error) = use_context()?;
             Some(Self {
                 locale_signal,
+                preferences,
+                #[cfg(feature = "key_inspector")]
+                inspect_keys,
+                #[cfg(feature = "display_mode")]
+                display_mode,
+                #[cfg(feature = "track_fallbacks")]
+                fallback_hooks,
+                #[cfg(feature = "translation_overrides")]
+                overrides,
+                #[cfg(feature = "dynamic_load")]
+                translations_error,
                 locale_marker: PhantomData,
                 scope_marker: PhantomData,
             })
@@ -144,6 +382,19 @@ impl<L: Locale, S: Scope<L>> I18nContext<L, S> {
     }
 
     pub(crate) fn provide(this: Self) {
+        provide_context(PreferencesContext(this.preferences));
+        #[cfg(feature = "key_inspector")]
+        provide_context(crate::inspector::InspectorContext(this.inspect_keys));
+        #[cfg(feature = "display_mode")]
+        provide_context(crate::display_mode::DisplayModeContext(this.display_mode));
+        #[cfg(feature = "track_fallbacks")]
+        provide_context(this.fallback_hooks);
+        #[cfg(feature = "translation_overrides")]
+        provide_context(this.overrides);
+        #[cfg(feature = "dynamic_load")]
+        provide_context(crate::loading::TranslationsErrorContext(
+            this.translations_error,
+        ));
         #[cfg(feature = "unified_contexts")]
         provide_context(this.locale_signal);
         #[cfg(not(feature = "unified_contexts"))]
@@ -171,6 +422,31 @@ impl<L: Locale, S: Scope<L>> IntoDirective<(leptos::tachys::renderer::types::Ele
     }
 }
 
+/// Directive prefetching the translations of a locale when the element is hovered or focused,
+/// so switching to it is instant. Use it on the links of a locale switcher:
+///
+/// ```rust, ignore
+/// use leptos_i18n::context::prefetch_locale;
+///
+/// view! {
+///     <button
+///         use:prefetch_locale=Locale::fr
+///         on:click=move |_| i18n.set_locale_when_loaded(Locale::fr)
+///     >
+///         "Français"
+///     </button>
+/// }
+/// ```
+pub fn prefetch_locale<L: Locale>(el: leptos::tachys::renderer::types::Element, locale: L) {
+    let Some(i18n) = I18nContext::<L>::from_context() else {
+        return;
+    };
+    let _ = leptos_use::use_event_listener(el.clone(), leptos::ev::pointerenter, move |_| {
+        i18n.prefetch(locale)
+    });
+    let _ = leptos_use::use_event_listener(el, leptos::ev::focusin, move |_| i18n.prefetch(locale));
+}
+
 /// Cookies options for functions initializing or providing a `I18nContext`
 pub type CookieOptions<L> = UseCookieOptions<
     L,
@@ -182,9 +458,37 @@ pub(crate) const ENABLE_COOKIE: bool = cfg!(feature = "cookie");
 
 const COOKIE_PREFERED_LANG: &str = "i18n_pref_locale";
 
+/// Read a signal of the context, subscribing to it only inside a reactive context.
+///
+/// `t_string!` can be called outside of a reactive context, where tracking would log a warning.
+#[cfg_attr(
+    not(any(
+        feature = "format_currency",
+        feature = "key_inspector",
+        feature = "display_mode"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn get_maybe_tracked<S>(signal: &S) -> Option<<S as Get>::Value>
+where
+    S: Get + GetUntracked<Value = <S as Get>::Value>,
+{
+    if leptos::reactive::graph::Observer::get().is_some() {
+        signal.try_get()
+    } else {
+        signal.try_get_untracked()
+    }
+}
+
+/// A context without any locale detection or persistence, for the `testing` module.
+#[cfg(feature = "testing")]
+pub(crate) fn init_mock_context<L: Locale>(locale: L) -> I18nContext<L> {
+    init_context_inner(Arc::new(|_| {}), Memo::new(move |_| locale))
+}
+
 #[track_caller]
 fn init_context_inner<L: Locale>(
-    set_lang_cookie: WriteSignal<Option<L>>,
+    save_locale: Arc<dyn Fn(L) + Send + Sync>,
     initial_locale: Memo<L>,
 ) -> I18nContext<L> {
     #[cfg(feature = "unified_contexts")]
@@ -203,22 +507,111 @@ fn init_context_inner<L: Locale>(
         locale_signal.set(l);
     });
 
-    Effect::new_isomorphic(move |_| {
-        #[cfg(feature = "unified_contexts")]
-        let new_lang = {
-            let new_lang_any = locale_signal.get();
-            L::from_str(new_lang_any.0).unwrap_or_default()
-        };
-        #[cfg(not(feature = "unified_contexts"))]
-        let new_lang = locale_signal.get();
-        set_lang_cookie.set(Some(new_lang));
+    // effects only run in the browser, the server pushes the reloaded translations when polled.
+    #[cfg(all(
+        feature = "hot_reload",
+        not(feature = "dynamic_load"),
+        not(feature = "ssr"),
+        debug_assertions
+    ))]
+    Effect::new(|_| crate::hot_reload::start_polling());
+
+    // set when switching to the default locale because the translations failed to load, the choice of the user should be kept.
+    let skip_save = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    Effect::new_isomorphic({
+        let skip_save = skip_save.clone();
+        move |_| {
+            #[cfg(feature = "unified_contexts")]
+            let new_lang = {
+                let new_lang_any = locale_signal.get();
+                L::from_str(new_lang_any.0).unwrap_or_default()
+            };
+            #[cfg(not(feature = "unified_contexts"))]
+            let new_lang = locale_signal.get();
+            if !skip_save.swap(false, std::sync::atomic::Ordering::Relaxed) {
+                save_locale(new_lang);
+            }
+        }
     });
 
-    I18nContext::<L> {
+    // subcontexts share the preferences of their parent, they are about the user rather than the locale.
+    let preferences = use_context::<PreferencesContext>()
+        .map(|PreferencesContext(preferences)| preferences)
+        .unwrap_or_else(|| RwSignal::new(UserPreferences::default()));
+    // provided right away for the contexts provided with `provide_context` rather than `I18nContext::provide`.
+    provide_context(PreferencesContext(preferences));
+
+    // the inspector is enabled for the whole page, subcontexts included.
+    #[cfg(feature = "key_inspector")]
+    let inspect_keys = use_context::<crate::inspector::InspectorContext>()
+        .map(|crate::inspector::InspectorContext(enabled)| enabled)
+        .unwrap_or_else(|| RwSignal::new(false));
+    #[cfg(feature = "key_inspector")]
+    provide_context(crate::inspector::InspectorContext(inspect_keys));
+
+    #[cfg(feature = "display_mode")]
+    let display_mode = use_context::<crate::display_mode::DisplayModeContext>()
+        .map(|crate::display_mode::DisplayModeContext(mode)| mode)
+        .unwrap_or_else(|| RwSignal::new(crate::display_mode::DisplayMode::Translations));
+    #[cfg(feature = "display_mode")]
+    provide_context(crate::display_mode::DisplayModeContext(display_mode));
+
+    // subcontexts also report to the hooks of their parent.
+    #[cfg(feature = "track_fallbacks")]
+    let fallback_hooks = use_context().unwrap_or_else(crate::fallbacks::FallbackHooks::new);
+    #[cfg(feature = "track_fallbacks")]
+    provide_context(fallback_hooks);
+
+    // subcontexts render the overrides of their parent, a new root context starts without any.
+    #[cfg(feature = "translation_overrides")]
+    let overrides = use_context().unwrap_or_else(crate::overrides::TranslationOverrides::new);
+    #[cfg(feature = "translation_overrides")]
+    provide_context(overrides);
+
+    // unlike the options, each context has its own error so they don't clear each other's.
+    #[cfg(feature = "dynamic_load")]
+    let translations_error = RwSignal::new(None);
+    #[cfg(feature = "dynamic_load")]
+    provide_context(crate::loading::TranslationsErrorContext(translations_error));
+
+    let ctx = I18nContext::<L> {
         locale_signal,
+        preferences,
+        #[cfg(feature = "key_inspector")]
+        inspect_keys,
+        #[cfg(feature = "display_mode")]
+        display_mode,
+        #[cfg(feature = "track_fallbacks")]
+        fallback_hooks,
+        #[cfg(feature = "translation_overrides")]
+        overrides,
+        #[cfg(feature = "dynamic_load")]
+        translations_error,
         scope_marker: PhantomData,
         locale_marker: PhantomData,
-    }
+    };
+
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    let fallback = crate::loading::use_loading_options().fallback;
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    Effect::new(move |_| {
+        let Some(err) = ctx.translations_error() else {
+            return;
+        };
+        let locale = ctx.get_locale_untracked();
+        if fallback == crate::loading::LoadFallback::DefaultLocale
+            && err.locale() == locale.as_str()
+            && locale != L::default()
+        {
+            skip_save.store(true, std::sync::atomic::Ordering::Relaxed);
+            ctx.set_locale(L::default());
+        }
+    });
+    #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+    let _ = skip_save;
+
+    ctx
 }
 
 // *********************************************
@@ -237,9 +630,20 @@ where
     #[builder(into)]
     pub cookie_name: Cow<'a, str>,
     /// Options for the cookie, the value is of type `leptos_use::UseCookieOptions<Locale>` (default to `Default::default`)
+    #[builder(keep_type)]
     pub cookie_options: CookieOptions<L>,
     /// Options to pass to `leptos_use::use_locales`.
     pub ssr_lang_header_getter: UseLocalesOptions,
+    /// Ordered list of detectors used to find the initial locale, the first one returning a locale wins.
+    /// (default to `leptos_i18n::detectors::default_detectors()`)
+    #[builder(keep_type)]
+    pub detectors: LocaleDetectors<L>,
+    /// Where to save the locale, see `leptos_i18n::storage`. (default to `LocaleStorage::Cookie`)
+    #[builder(keep_type)]
+    pub storage: LocaleStorage<L>,
+    /// How the translations are loaded, see `leptos_i18n::loading`. Shared with the subcontexts.
+    #[cfg(feature = "dynamic_load")]
+    pub loading_options: crate::loading::LoadingOptions,
 }
 
 impl<L: Locale> Default for I18nContextOptions<'_, L> {
@@ -249,6 +653,10 @@ impl<L: Locale> Default for I18nContextOptions<'_, L> {
             cookie_name: Cow::Borrowed(COOKIE_PREFERED_LANG),
             cookie_options: Default::default(),
             ssr_lang_header_getter: Default::default(),
+            detectors: detectors::default_detectors(),
+            storage: LocaleStorage::default(),
+            #[cfg(feature = "dynamic_load")]
+            loading_options: Default::default(),
         }
     }
 }
@@ -261,18 +669,30 @@ pub fn init_i18n_context_with_options<L: Locale>(options: I18nContextOptions<L>)
         cookie_name,
         cookie_options,
         ssr_lang_header_getter,
+        detectors,
+        storage,
+        #[cfg(feature = "dynamic_load")]
+        loading_options,
     } = options;
-    let (lang_cookie, set_lang_cookie) = if ENABLE_COOKIE && enable_cookie {
-        leptos_use::use_cookie_with_options::<L, FromToStringCodec>(&cookie_name, cookie_options)
-    } else {
-        let (lang_cookie, set_lang_cookie) = signal(None);
-        (lang_cookie.into(), set_lang_cookie)
-    };
+    // provided before the context is created, its effects and the translations requests read them.
+    #[cfg(feature = "dynamic_load")]
+    provide_context(loading_options);
+    let storage = StorageHandle::new(storage, enable_cookie, &cookie_name, cookie_options);
 
     let initial_locale =
-        fetch_locale::fetch_locale(lang_cookie.get_untracked(), ssr_lang_header_getter);
+        fetch_locale::fetch_locale(&detectors, storage.stored, ssr_lang_header_getter);
+
+    let ctx = init_context_inner::<L>(storage.save, initial_locale);
 
-    init_context_inner::<L>(set_lang_cookie, initial_locale)
+    // the server can't read client only storages, switch to the stored locale after hydration to avoid a mismatch.
+    if cfg!(feature = "hydrate")
+        && storage.client_only
+        && let Some(stored) = storage.stored
+    {
+        Effect::new(move |_| ctx.set_locale(stored));
+    }
+
+    ctx
 }
 
 /// Initialize a `I18nContext` without providing it.
@@ -333,19 +753,17 @@ fn init_subcontext_with_options<L: Locale>(
     cookie_options: CookieOptions<L>,
     ssr_lang_header_getter: Option<UseLocalesOptions>,
 ) -> I18nContext<L> {
-    let (lang_cookie, set_lang_cookie) = match cookie_name {
-        Some(cookie_name) if ENABLE_COOKIE => leptos_use::use_cookie_with_options::<
-            L,
-            FromToStringCodec,
-        >(&cookie_name, cookie_options),
-        _ => {
-            let (lang_cookie, set_lang_cookie) = signal(None);
-            (lang_cookie.into(), set_lang_cookie)
-        }
+    let storage = match cookie_name {
+        Some(cookie_name) => StorageHandle::cookie(&cookie_name, cookie_options),
+        None => StorageHandle::none(),
     };
+    let cookie = storage.stored;
 
-    let fetch_locale_memo =
-        fetch_locale::fetch_locale(None, ssr_lang_header_getter.unwrap_or_default());
+    let fetch_locale_memo = fetch_locale::fetch_locale(
+        &detectors::default_detectors(),
+        None,
+        ssr_lang_header_getter.unwrap_or_default(),
+    );
 
     let parent_locale = I18nContext::<L>::from_context().map(|ctx| ctx.get_locale_untracked());
 
@@ -353,7 +771,6 @@ fn init_subcontext_with_options<L: Locale>(
 
     let initial_locale_listener = Memo::new(move |prev_locale| {
         let initial_locale = initial_locale.get();
-        let cookie = lang_cookie.get_untracked();
         let parent_locale = parent_locale.get();
         // first execution, cookie takes precedence
         if prev_locale.is_none() {
@@ -364,7 +781,7 @@ fn init_subcontext_with_options<L: Locale>(
         }
     });
 
-    init_context_inner::<L>(set_lang_cookie, initial_locale_listener)
+    init_context_inner::<L>(storage.save, initial_locale_listener)
 }
 
 #[track_caller]
@@ -528,13 +945,19 @@ macro_rules! fill_options {
 }
 
 #[track_caller]
+#[allow(clippy::too_many_arguments)]
 fn provide_i18n_context_component_inner<L: Locale, Chil: IntoView>(
     set_lang_attr_on_html: Option<bool>,
     set_dir_attr_on_html: Option<bool>,
+    set_content_language_meta: Option<bool>,
+    set_og_locale_meta: Option<bool>,
     enable_cookie: Option<bool>,
     cookie_name: Option<Cow<str>>,
     cookie_options: Option<CookieOptions<L>>,
     ssr_lang_header_getter: Option<UseLocalesOptions>,
+    detectors: Option<LocaleDetectors<L>>,
+    storage: Option<LocaleStorage<L>>,
+    #[cfg(feature = "dynamic_load")] loading_options: Option<crate::loading::LoadingOptions>,
     children: impl FnOnce() -> Chil,
 ) -> impl IntoView {
     #[cfg(all(feature = "dynamic_load", feature = "hydrate", not(feature = "ssr")))]
@@ -546,8 +969,12 @@ fn provide_i18n_context_component_inner<L: Locale, Chil: IntoView>(
         enable_cookie,
         cookie_name,
         cookie_options,
-        ssr_lang_header_getter
+        ssr_lang_header_getter,
+        detectors,
+        storage
     );
+    #[cfg(feature = "dynamic_load")]
+    let options = fill_options!(options, loading_options);
     let i18n = provide_i18n_context_with_options_inner(options);
     let children = children();
     #[cfg(all(feature = "dynamic_load", feature = "ssr"))]
@@ -561,32 +988,84 @@ fn provide_i18n_context_component_inner<L: Locale, Chil: IntoView>(
         .unwrap_or(true)
         .then_some(move || i18n.get_locale().direction().as_str());
 
+    let meta_tags = locale_meta_tags(
+        i18n,
+        set_content_language_meta.unwrap_or(false),
+        set_og_locale_meta.unwrap_or(false),
+    );
+
     view! {
         // Render children first, for 2 reasons: register the used translations and if it change the locale <Html> will have the correct one.
         {children}
         {embed_translations}
         <Html attr:lang=lang attr:dir=dir />
+        {meta_tags}
     }
 }
 
+/// Format a locale for the Open Graph protocol, which uses `language_TERRITORY`.
+fn og_locale(locale: &str) -> String {
+    locale.replace('-', "_")
+}
+
+/// `<meta http-equiv="content-language">` and `og:locale`/`og:locale:alternate` tags following the locale of the context.
+fn locale_meta_tags<L: Locale>(
+    i18n: I18nContext<L>,
+    content_language: bool,
+    og_locale_tags: bool,
+) -> impl IntoView {
+    let content_language = content_language.then(|| {
+        view! { <Meta http_equiv="content-language" content=move || i18n.get_locale().as_str() /> }
+    });
+    let og_locale_tags = og_locale_tags.then(|| {
+        let alternates = move || {
+            let current = i18n.get_locale();
+            L::get_all()
+                .iter()
+                .filter(|locale| **locale != current)
+                .map(|locale| {
+                    view! { <Meta property="og:locale:alternate" content=og_locale(locale.as_str()) /> }
+                })
+                .collect_view()
+        };
+        view! {
+            <Meta property="og:locale" content=move || og_locale(i18n.get_locale().as_str()) />
+            {alternates}
+        }
+    });
+    (content_language, og_locale_tags)
+}
+
 #[doc(hidden)]
 #[track_caller]
+#[allow(clippy::too_many_arguments)]
 pub fn provide_i18n_context_component<L: Locale, Chil: IntoView>(
     set_lang_attr_on_html: Option<bool>,
     set_dir_attr_on_html: Option<bool>,
+    set_content_language_meta: Option<bool>,
+    set_og_locale_meta: Option<bool>,
     enable_cookie: Option<bool>,
     cookie_name: Option<Cow<str>>,
     cookie_options: Option<CookieOptions<L>>,
     ssr_lang_header_getter: Option<UseLocalesOptions>,
+    detectors: Option<LocaleDetectors<L>>,
+    storage: Option<LocaleStorage<L>>,
+    #[cfg(feature = "dynamic_load")] loading_options: Option<crate::loading::LoadingOptions>,
     children: TypedChildren<Chil>,
 ) -> impl IntoView {
     provide_i18n_context_component_inner(
         set_lang_attr_on_html,
         set_dir_attr_on_html,
+        set_content_language_meta,
+        set_og_locale_meta,
         enable_cookie,
         cookie_name,
         cookie_options,
         ssr_lang_header_getter,
+        detectors,
+        storage,
+        #[cfg(feature = "dynamic_load")]
+        loading_options,
         children.into_inner(),
     )
 }
@@ -596,6 +1075,8 @@ pub fn provide_i18n_context_component<L: Locale, Chil: IntoView>(
 pub fn provide_i18n_context_component_island<L: Locale>(
     set_lang_attr_on_html: Option<bool>,
     set_dir_attr_on_html: Option<bool>,
+    set_content_language_meta: Option<bool>,
+    set_og_locale_meta: Option<bool>,
     enable_cookie: Option<bool>,
     cookie_name: Option<Cow<str>>,
     children: children::Children,
@@ -603,10 +1084,16 @@ pub fn provide_i18n_context_component_island<L: Locale>(
     provide_i18n_context_component_inner::<L, AnyView>(
         set_lang_attr_on_html,
         set_dir_attr_on_html,
+        set_content_language_meta,
+        set_og_locale_meta,
         enable_cookie,
         cookie_name,
         None,
         None,
+        None,
+        None,
+        #[cfg(feature = "dynamic_load")]
+        None,
         children,
     )
 }
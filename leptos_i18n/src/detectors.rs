@@ -0,0 +1,336 @@
+//! Locale detection.
+//!
+//! When a context is initialized, the detectors of `I18nContextOptions::detectors` are run in order
+//! and the first one returning a locale wins. If none does, the default locale is used.
+//!
+//! The default chain is `[HtmlLang, Storage, AcceptLanguage]`:
+//! 1. The "lang" attribute is set on the `<html>` element in hydrate
+//! 1. A previously detected locale is saved in the storage backend, a cookie by default (see `leptos_i18n::storage`)
+//! 1. A locale can be matched based on the `Accept-Language` header in SSR or `navigator.languages` in CSR
+//!
+//! Any closure `Fn() -> Option<Locale>` is also a detector, for exemple to read a locale saved in the user profile.
+//!
+//! ```rust, no_run
+//! #   leptos_i18n::declare_locales! {
+//! #       path: leptos_i18n,
+//! #       default: "en",
+//! #       locales: ["en", "fr"],
+//! #       en: {},
+//! #       fr: {},
+//! #   };
+//! # use i18n::*;
+//! # fn user_locale_from_session() -> Option<Locale> { None }
+//! use leptos_i18n::context::I18nContextOptions;
+//! use leptos_i18n::detectors::{AcceptLanguage, HtmlLang, LocalStorage, QueryParam, Storage};
+//!
+//! let options = I18nContextOptions::<Locale>::default().detectors(vec![
+//!     Box::new(HtmlLang),
+//!     Box::new(QueryParam::new("lang")),
+//!     Box::new(user_locale_from_session),
+//!     Box::new(Storage),
+//!     Box::new(LocalStorage::new("lang")),
+//!     Box::new(AcceptLanguage),
+//! ]);
+//! ```
+//!
+//! # Hydration
+//!
+//! The locale choosen by the server is forwarded to the client with the "lang" attribute of the `<html>` element,
+//! keep `HtmlLang` first in the chain, otherwise a detector only available on the client (such as `LocalStorage`)
+//! could pick a different locale than the server and cause a hydration mismatch.
+
+use std::{borrow::Cow, sync::Arc};
+
+use leptos::prelude::*;
+
+use crate::Locale;
+
+/// Values already gathered by the context that detectors can use.
+#[derive(Debug, Clone)]
+pub struct DetectorContext<L: Locale> {
+    pub(crate) stored: Option<L>,
+    pub(crate) accepted_languages: Signal<Vec<String>>,
+}
+
+impl<L: Locale> DetectorContext<L> {
+    /// The locale saved by the storage backend, if any.
+    pub fn stored(&self) -> Option<L> {
+        self.stored
+    }
+
+    /// The accepted languages, from the `Accept-Language` header in SSR or `navigator.languages` in CSR, sorted in preferred order.
+    pub fn accepted_languages(&self) -> Vec<String> {
+        self.accepted_languages.get_untracked()
+    }
+}
+
+/// A step in the locale detection chain.
+pub trait LocaleDetector<L: Locale>: Send + Sync + 'static {
+    /// Try to detect the locale, return `None` to let the next detector try.
+    fn detect(&self, cx: &DetectorContext<L>) -> Option<L>;
+}
+
+impl<L: Locale, F> LocaleDetector<L> for F
+where
+    F: Fn() -> Option<L> + Send + Sync + 'static,
+{
+    fn detect(&self, _cx: &DetectorContext<L>) -> Option<L> {
+        self()
+    }
+}
+
+/// An ordered list of detectors.
+pub type LocaleDetectors<L> = Vec<Box<dyn LocaleDetector<L>>>;
+
+/// Return the default detection chain: `[HtmlLang, Storage, AcceptLanguage]`.
+pub fn default_detectors<L: Locale>() -> LocaleDetectors<L> {
+    vec![
+        Box::new(HtmlLang),
+        Box::new(Storage),
+        Box::new(AcceptLanguage),
+    ]
+}
+
+pub(crate) fn detect_locale<L: Locale>(
+    detectors: &[Box<dyn LocaleDetector<L>>],
+    cx: &DetectorContext<L>,
+) -> Option<L> {
+    detectors.iter().find_map(|detector| detector.detect(cx))
+}
+
+type SsrGetter = Arc<dyn Fn() -> Option<String> + Send + Sync>;
+
+/// Read the "lang" attribute of the `<html>` element, only in hydrate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlLang;
+
+impl<L: Locale> LocaleDetector<L> for HtmlLang {
+    fn detect(&self, _cx: &DetectorContext<L>) -> Option<L> {
+        if cfg!(all(feature = "hydrate", not(feature = "ssr"))) {
+            crate::fetch_locale::get_locale_from_html()
+        } else {
+            None
+        }
+    }
+}
+
+/// Use the locale saved by the storage backend, see `I18nContextOptions::storage`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Storage;
+
+impl<L: Locale> LocaleDetector<L> for Storage {
+    fn detect(&self, cx: &DetectorContext<L>) -> Option<L> {
+        cx.stored()
+    }
+}
+
+/// Match the locale against the `Accept-Language` header in SSR or `navigator.languages` in CSR.
+///
+/// This detector always return a locale, falling back to the default one if no language matches,
+/// so detectors placed after it are never reached.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AcceptLanguage;
+
+impl<L: Locale> LocaleDetector<L> for AcceptLanguage {
+    fn detect(&self, cx: &DetectorContext<L>) -> Option<L> {
+        let locale = cx
+            .accepted_languages
+            .with_untracked(|accepted| L::find_locale(accepted));
+        Some(locale)
+    }
+}
+
+/// Read the locale from a query parameter of the URL, for exemple `?lang=fr`.
+///
+/// The browser URL is used on the client. On the server, the request URL (or only its query) must be given with `ssr_url_getter`,
+/// with axum it can be done with `use_context::<http::request::Parts>().map(|parts| parts.uri.to_string())`.
+#[derive(Clone)]
+pub struct QueryParam {
+    name: Cow<'static, str>,
+    ssr_url_getter: Option<SsrGetter>,
+}
+
+impl QueryParam {
+    /// Create a detector reading the query parameter of the given name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        QueryParam {
+            name: name.into(),
+            ssr_url_getter: None,
+        }
+    }
+
+    /// Give a way to get the request URL on the server.
+    pub fn ssr_url_getter(
+        self,
+        getter: impl Fn() -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        QueryParam {
+            ssr_url_getter: Some(Arc::new(getter)),
+            ..self
+        }
+    }
+
+    fn get_url(&self) -> Option<String> {
+        if cfg!(feature = "ssr") {
+            self.ssr_url_getter.as_ref().and_then(|getter| getter())
+        } else if cfg!(any(feature = "csr", feature = "hydrate")) {
+            window().location().search().ok()
+        } else {
+            None
+        }
+    }
+}
+
+fn find_query_param<'a>(url: &'a str, name: &str) -> Option<&'a str> {
+    let query = url.split_once('?').map_or(url, |(_, query)| query);
+    let query = query.split_once('#').map_or(query, |(query, _)| query);
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find_map(|(key, value)| (key == name).then_some(value))
+}
+
+impl<L: Locale> LocaleDetector<L> for QueryParam {
+    fn detect(&self, _cx: &DetectorContext<L>) -> Option<L> {
+        let url = self.get_url()?;
+        let value = find_query_param(&url, &self.name)?;
+        L::from_str(value).ok()
+    }
+}
+
+/// Read the locale saved in `localStorage` under the given key.
+///
+/// Only available on the client, on the server this detector never return a locale.
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    key: Cow<'static, str>,
+}
+
+impl LocalStorage {
+    /// Create a detector reading the given `localStorage` key.
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        LocalStorage { key: key.into() }
+    }
+}
+
+impl<L: Locale> LocaleDetector<L> for LocalStorage {
+    fn detect(&self, _cx: &DetectorContext<L>) -> Option<L> {
+        if cfg!(feature = "ssr") || !cfg!(any(feature = "csr", feature = "hydrate")) {
+            return None;
+        }
+        let value = window().local_storage().ok()??.get_item(&self.key).ok()??;
+        L::from_str(&value).ok()
+    }
+}
+
+/// Read the locale from the first label of the host, for exemple `fr.example.com`.
+///
+/// The browser location is used on the client. On the server, the host must be given with `ssr_host_getter`,
+/// for exemple from the `Host` header of the request.
+#[derive(Clone, Default)]
+pub struct Subdomain {
+    ssr_host_getter: Option<SsrGetter>,
+}
+
+impl Subdomain {
+    /// Create a detector reading the subdomain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give a way to get the request host on the server.
+    pub fn ssr_host_getter(
+        self,
+        getter: impl Fn() -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        Subdomain {
+            ssr_host_getter: Some(Arc::new(getter)),
+        }
+    }
+
+    fn get_host(&self) -> Option<String> {
+        if cfg!(feature = "ssr") {
+            self.ssr_host_getter.as_ref().and_then(|getter| getter())
+        } else if cfg!(any(feature = "csr", feature = "hydrate")) {
+            window().location().hostname().ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl<L: Locale> LocaleDetector<L> for Subdomain {
+    fn detect(&self, _cx: &DetectorContext<L>) -> Option<L> {
+        let host = self.get_host()?;
+        let (subdomain, _) = host.split_once('.')?;
+        L::from_str(subdomain).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        default: "en",
+        locales: ["en", "fr", "de"],
+        en: {},
+        fr: {},
+        de: {},
+    }
+
+    use i18n::Locale;
+
+    #[test]
+    fn test_detect_locale() {
+        let cx = DetectorContext {
+            stored: Some(Locale::de),
+            accepted_languages: Signal::stored(vec!["fr-FR".to_string()]),
+        };
+        let detectors: LocaleDetectors<Locale> = vec![
+            Box::new(|| None),
+            Box::new(Storage),
+            Box::new(AcceptLanguage),
+        ];
+        assert_eq!(detect_locale(&detectors, &cx), Some(Locale::de));
+        let detectors: LocaleDetectors<Locale> = vec![
+            Box::new(|| None),
+            Box::new(AcceptLanguage),
+            Box::new(Storage),
+        ];
+        assert_eq!(detect_locale(&detectors, &cx), Some(Locale::fr));
+        let detectors: LocaleDetectors<Locale> = vec![Box::new(|| Some(Locale::en))];
+        assert_eq!(detect_locale(&detectors, &cx), Some(Locale::en));
+        let detectors: LocaleDetectors<Locale> = vec![Box::new(HtmlLang)];
+        assert_eq!(detect_locale(&detectors, &cx), None);
+    }
+
+    #[test]
+    fn test_find_query_param() {
+        assert_eq!(find_query_param("?lang=fr", "lang"), Some("fr"));
+        assert_eq!(
+            find_query_param("/about?a=b&lang=fr#top", "lang"),
+            Some("fr")
+        );
+        assert_eq!(
+            find_query_param("https://example.com/?langs=fr", "lang"),
+            None
+        );
+        assert_eq!(find_query_param("", "lang"), None);
+    }
+    #[test]
+    #[cfg(not(any(feature = "ssr", feature = "csr", feature = "hydrate")))]
+    fn test_browser_detectors_without_window() {
+        let cx = DetectorContext {
+            stored: None,
+            accepted_languages: Signal::stored(vec![]),
+        };
+        let detectors: LocaleDetectors<Locale> = vec![
+            Box::new(QueryParam::new("lang")),
+            Box::new(LocalStorage::new("lang")),
+            Box::new(Subdomain::new()),
+        ];
+        assert_eq!(detect_locale(&detectors, &cx), None);
+    }
+}
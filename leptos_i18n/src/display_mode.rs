@@ -0,0 +1,286 @@
+//! Runtime display modes of the translations with the "display_mode" feature.
+//!
+//! The `show_keys_only` parse option replaces every translation by its key but requires a rebuild.
+//! With this feature the same can be done at runtime, along with a pseudo-localization of the translations,
+//! for example from a hidden settings panel of a staging build:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! view! {
+//!     <select on:change=move |ev| {
+//!         let mode = match event_target_value(&ev).as_str() {
+//!             "keys" => DisplayMode::Keys,
+//!             "pseudo" => DisplayMode::Pseudo,
+//!             _ => DisplayMode::Translations,
+//!         };
+//!         i18n.set_display_mode(mode);
+//!     }>
+//!         <option value="translations">"Translations"</option>
+//!         <option value="keys">"Keys"</option>
+//!         <option value="pseudo">"Pseudo"</option>
+//!     </select>
+//! }
+//! ```
+//!
+//! The mode is set on the context and shared with its subcontexts, on the server each request has its own mode.
+//! The views are updated when it changes.
+//! Only keys with strings are affected: keys with a number or a boolean value keep their value in every mode.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{OnceLock, RwLock},
+};
+
+use leptos::{either::EitherOf3, prelude::*};
+
+/// How the translations are displayed, see `I18nContext::set_display_mode`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DisplayMode {
+    /// Display the translations.
+    #[default]
+    Translations,
+    /// Display the path of the keys instead of their translation, such as `form.email` or `common::form.email`.
+    Keys,
+    /// Display the translations pseudo-localized: letters are accented, vowels are doubled to simulate the expansion
+    /// of longer languages, and each translation is wrapped in brackets to find truncated or concatenated strings.
+    /// Variables and components are left untouched.
+    Pseudo,
+}
+
+/// Display mode of the closest `I18nContext`, provided next to it so the generated code can find it without knowing the locale type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisplayModeContext(pub RwSignal<DisplayMode>);
+
+/// Return the display mode of the closest context, subscribing to changes inside a reactive context.
+///
+/// `DisplayMode::Translations` without a context.
+fn get() -> DisplayMode {
+    use_context::<DisplayModeContext>()
+        .and_then(|DisplayModeContext(mode)| crate::context::get_maybe_tracked(&mode))
+        .unwrap_or_default()
+}
+
+fn pseudo_char(c: char, out: &mut String) {
+    let accented = match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'đ',
+        'e' => 'é',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'í',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'ü',
+        'w' => 'ŵ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Á',
+        'C' => 'Ç',
+        'D' => 'Đ',
+        'E' => 'É',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Í',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Ü',
+        'W' => 'Ŵ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        c => c,
+    };
+    out.push(accented);
+    if matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u') {
+        out.push(accented);
+    }
+}
+
+/// Accent the letters of `s` and double its vowels, without the brackets of `pseudo_localize`.
+fn accent(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() * 2);
+    for c in s.chars() {
+        pseudo_char(c, &mut out);
+    }
+    out
+}
+
+/// Pseudo-localize a string the same way the translations are in the `DisplayMode::Pseudo` mode.
+///
+/// ```
+/// # use leptos_i18n::display_mode::pseudo_localize;
+/// assert_eq!(pseudo_localize("Hello"), "[Ĥééļļöö]");
+/// ```
+pub fn pseudo_localize(s: &str) -> String {
+    format!("[{}]", accent(s))
+}
+
+type Cache = RwLock<HashMap<&'static str, &'static str>>;
+
+/// Return the value of `s` in `cache`, leaking the output of `f` the first time.
+fn cached(cache: &'static OnceLock<Cache>, s: &'static str, f: fn(&str) -> String) -> &'static str {
+    let cache = cache.get_or_init(Default::default);
+    if let Some(cached) = cache.read().unwrap().get(s) {
+        return cached;
+    }
+    let value: &'static str = Box::leak(f(s).into_boxed_str());
+    cache.write().unwrap().insert(s, value);
+    value
+}
+
+/// Return the key path in the `Keys` mode.
+#[doc(hidden)]
+pub fn key(path: &'static str) -> Option<&'static str> {
+    (get() == DisplayMode::Keys).then_some(path)
+}
+
+/// Accent a string displayed as text in the `Pseudo` mode.
+#[doc(hidden)]
+pub fn text(s: &'static str) -> &'static str {
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+    match get() {
+        DisplayMode::Pseudo => cached(&CACHE, s, accent),
+        _ => s,
+    }
+}
+
+/// Wrap the value of a string key in brackets in the `Pseudo` mode.
+#[doc(hidden)]
+pub fn message(s: &'static str) -> &'static str {
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+    match get() {
+        DisplayMode::Pseudo => cached(&CACHE, s, |s| format!("[{s}]")),
+        _ => s,
+    }
+}
+
+#[doc(hidden)]
+pub fn view<V: IntoView + Clone + 'static>(
+    path: &'static str,
+    view: impl FnOnce() -> V,
+) -> impl IntoView + Clone + 'static {
+    match get() {
+        DisplayMode::Keys => EitherOf3::A(path),
+        DisplayMode::Pseudo => EitherOf3::B(("[", view(), "]")),
+        DisplayMode::Translations => EitherOf3::C(view()),
+    }
+}
+
+#[doc(hidden)]
+pub async fn view_fut<V: IntoView + Clone + 'static>(
+    path: &'static str,
+    view: impl Future<Output = V>,
+) -> impl IntoView + Clone + 'static {
+    match get() {
+        DisplayMode::Keys => EitherOf3::A(path),
+        DisplayMode::Pseudo => EitherOf3::B(("[", view.await, "]")),
+        DisplayMode::Translations => EitherOf3::C(view.await),
+    }
+}
+
+#[doc(hidden)]
+pub fn fmt_message(
+    f: &mut fmt::Formatter<'_>,
+    path: &'static str,
+    fmt: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    match get() {
+        DisplayMode::Keys => f.write_str(path),
+        DisplayMode::Pseudo => {
+            f.write_str("[")?;
+            fmt(f)?;
+            f.write_str("]")
+        }
+        DisplayMode::Translations => fmt(f),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_mode<T>(mode: DisplayMode, f: impl FnOnce() -> T) -> T {
+        let owner = Owner::new();
+        owner.with(|| {
+            provide_context(DisplayModeContext(RwSignal::new(mode)));
+            f()
+        })
+    }
+
+    struct Message(&'static str);
+
+    impl fmt::Display for Message {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt_message(f, "form.email", |f| f.write_str(self.0))
+        }
+    }
+
+    #[test]
+    fn test_accent() {
+        assert_eq!(accent("Hello World"), "Ĥééļļöö Ŵööŕļđ");
+        // only letters are changed
+        assert_eq!(accent("{{ count }} 42 !"), "{{ çööüüñţ }} 42 !");
+        assert_eq!(pseudo_localize(""), "[]");
+    }
+
+    #[test]
+    fn test_message() {
+        assert_eq!(
+            with_mode(DisplayMode::Translations, || message("Hello")),
+            "Hello"
+        );
+        assert_eq!(with_mode(DisplayMode::Keys, || message("Hello")), "Hello");
+        assert_eq!(
+            with_mode(DisplayMode::Pseudo, || message("Hello")),
+            "[Hello]"
+        );
+        assert_eq!(with_mode(DisplayMode::Pseudo, || text("Hello")), "Ĥééļļöö");
+        assert_eq!(
+            with_mode(DisplayMode::Keys, || key("form.email")),
+            Some("form.email")
+        );
+        assert_eq!(with_mode(DisplayMode::Pseudo, || key("form.email")), None);
+    }
+
+    #[test]
+    fn test_fmt_message() {
+        let render = |mode| with_mode(mode, || Message("Email").to_string());
+        assert_eq!(render(DisplayMode::Translations), "Email");
+        assert_eq!(render(DisplayMode::Keys), "form.email");
+        assert_eq!(render(DisplayMode::Pseudo), "[Email]");
+    }
+
+    #[test]
+    fn test_view() {
+        // the markers separate the text nodes for the hydration.
+        let render =
+            |mode| with_mode(mode, || view("form.email", || "Email").to_html()).replace("<!>", "");
+        assert_eq!(render(DisplayMode::Translations), "Email");
+        assert_eq!(render(DisplayMode::Keys), "form.email");
+        assert_eq!(render(DisplayMode::Pseudo), "[Email]");
+    }
+
+    #[test]
+    fn test_without_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(message("Hello"), "Hello");
+            assert_eq!(key("form.email"), None);
+        });
+    }
+}
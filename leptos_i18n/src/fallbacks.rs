@@ -0,0 +1,186 @@
+//! Reporting of the keys displayed with the value of another locale, with the "track_fallbacks" feature.
+//!
+//! A key missing from the files of a locale, explicitly defaulted with `null` or inherited from another locale
+//! is displayed with the value of the locale it falls back to. The keys in that case are listed by `Locale::defaulted_keys`,
+//! and the hooks registered with `I18nContext::on_fallback` are called each time one of them is rendered,
+//! to find out which untranslated strings users actually see:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! i18n.on_fallback(|locale, key_path| {
+//!     send_metric("untranslated", locale.as_str(), key_path);
+//! });
+//! ```
+//!
+//! The hooks are called from the rendering of the views, so they should return quickly,
+//! for example by pushing the report to a queue flushed later.
+//!
+//! The hooks are stored in the reactive context next to the `I18nContext` they are registered on,
+//! and subcontexts share the hooks of their parent. On the server each request has its own hooks,
+//! and keys rendered outside of the reactive tree of the context, for example by `td_string!` without an owner, are not reported.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use leptos::prelude::{StoredValue, UpdateValue, WithValue, use_context};
+
+type FallbackHook<L> = Arc<dyn Fn(L, &'static str) + Send + Sync>;
+
+/// Hooks of an `I18nContext`, provided next to it so the generated accessors can find them.
+pub(crate) struct FallbackHooks<L: 'static>(StoredValue<Vec<(usize, FallbackHook<L>)>>);
+
+impl<L: 'static> Clone for FallbackHooks<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: 'static> Copy for FallbackHooks<L> {}
+
+impl<L: 'static> core::fmt::Debug for FallbackHooks<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallbackHooks").finish_non_exhaustive()
+    }
+}
+
+impl<L: Copy + Send + Sync + 'static> FallbackHooks<L> {
+    pub fn new() -> Self {
+        FallbackHooks(StoredValue::new(Vec::new()))
+    }
+
+    pub fn add(self, hook: impl Fn(L, &'static str) + Send + Sync + 'static) -> usize {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        self.0
+            .update_value(|hooks| hooks.push((id, Arc::new(hook))));
+        id
+    }
+
+    pub fn remove(self, id: usize) {
+        // the hooks may already be disposed with the owner of the context.
+        self.0
+            .try_update_value(|hooks| hooks.retain(|(hook_id, _)| *hook_id != id));
+    }
+
+    fn call(self, locale: L, path: &'static str) {
+        // clone the hooks so a hook can register or remove hooks.
+        let Some(hooks) = self.0.try_with_value(|hooks| {
+            hooks
+                .iter()
+                .map(|(_, hook)| hook.clone())
+                .collect::<Vec<_>>()
+        }) else {
+            return;
+        };
+        for hook in hooks {
+            hook(locale, path);
+        }
+    }
+}
+
+/// Report the rendering of a key to the hooks of the closest context if `locale` is one of the locales using the value of another locale.
+#[doc(hidden)]
+pub fn report<L: PartialEq + Copy + Send + Sync + 'static>(
+    locale: L,
+    path: &'static str,
+    defaulted: &[L],
+) {
+    if !defaulted.contains(&locale) {
+        return;
+    }
+    if let Some(hooks) = use_context::<FallbackHooks<L>>() {
+        hooks.call(locale, path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::{Owner, provide_context};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Loc {
+        En,
+        Fr,
+    }
+
+    type Reports = Arc<Mutex<Vec<(Loc, &'static str)>>>;
+
+    fn recorder() -> (Reports, impl Fn(Loc, &'static str) + Send + Sync + 'static) {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let hook = {
+            let reports = reports.clone();
+            move |locale, path| reports.lock().unwrap().push((locale, path))
+        };
+        (reports, hook)
+    }
+
+    #[test]
+    fn report_defaulted_locales_only() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let hooks = FallbackHooks::<Loc>::new();
+            provide_context(hooks);
+            let (reports, hook) = recorder();
+            hooks.add(hook);
+            report(Loc::En, "a", &[Loc::Fr]);
+            report(Loc::Fr, "b", &[Loc::Fr]);
+            assert_eq!(*reports.lock().unwrap(), [(Loc::Fr, "b")]);
+        });
+    }
+
+    #[test]
+    fn report_without_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let hooks = FallbackHooks::<Loc>::new();
+            let (reports, hook) = recorder();
+            hooks.add(hook);
+            report(Loc::Fr, "a", &[Loc::Fr]);
+            assert!(reports.lock().unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn add_and_remove_hook() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let hooks = FallbackHooks::<Loc>::new();
+            provide_context(hooks);
+            let (first_reports, first) = recorder();
+            let (second_reports, second) = recorder();
+            let first_id = hooks.add(first);
+            hooks.add(second);
+            report(Loc::Fr, "a", &[Loc::Fr]);
+            hooks.remove(first_id);
+            report(Loc::Fr, "b", &[Loc::Fr]);
+            assert_eq!(*first_reports.lock().unwrap(), [(Loc::Fr, "a")]);
+            assert_eq!(
+                *second_reports.lock().unwrap(),
+                [(Loc::Fr, "a"), (Loc::Fr, "b")]
+            );
+        });
+    }
+
+    #[test]
+    fn hooks_are_not_shared_between_owners() {
+        let (reports, hook) = recorder();
+        let first = Owner::new();
+        first.with(|| {
+            let hooks = FallbackHooks::<Loc>::new();
+            provide_context(hooks);
+            hooks.add(hook);
+        });
+        let second = Owner::new();
+        second.with(|| {
+            provide_context(FallbackHooks::<Loc>::new());
+            report(Loc::Fr, "a", &[Loc::Fr]);
+        });
+        assert!(reports.lock().unwrap().is_empty());
+        first.with(|| report(Loc::Fr, "b", &[Loc::Fr]));
+        assert_eq!(*reports.lock().unwrap(), [(Loc::Fr, "b")]);
+    }
+}
@@ -1,33 +1,39 @@
 use leptos::prelude::*;
 use leptos_use::UseLocalesOptions;
 
-use crate::Locale;
+use crate::{
+    Locale,
+    detectors::{DetectorContext, LocaleDetector, detect_locale},
+};
 
-pub fn fetch_locale<L: Locale>(current_cookie: Option<L>, options: UseLocalesOptions) -> Memo<L> {
-    let accepted_locales = leptos_use::use_locales_with_options(options);
+pub fn fetch_locale<L: Locale>(
+    detectors: &[Box<dyn LocaleDetector<L>>],
+    stored_locale: Option<L>,
+    options: UseLocalesOptions,
+) -> Memo<L> {
+    let accepted_languages = leptos_use::use_locales_with_options(options);
     let accepted_locale =
-        Memo::new(move |_| accepted_locales.with(|accepted| L::find_locale(accepted)));
+        Memo::new(move |_| accepted_languages.with(|accepted| L::find_locale(accepted)));
 
-    if cfg!(feature = "ssr") {
-        fetch_locale_ssr(current_cookie, accepted_locale)
-    } else if cfg!(feature = "hydrate") {
-        fetch_locale_hydrate(current_cookie, accepted_locale)
-    } else {
-        fetch_locale_csr(current_cookie, accepted_locale)
-    }
-}
+    let cx = DetectorContext {
+        stored: stored_locale,
+        accepted_languages,
+    };
+    let detected_locale = detect_locale(detectors, &cx).unwrap_or_default();
 
-pub fn get_accepted_locale<L: Locale>(options: UseLocalesOptions) -> L {
-    leptos_use::use_locales_with_options(options)
-        .with_untracked(|accepted| L::find_locale(accepted))
+    signal_once_then(detected_locale, accepted_locale)
 }
 
-pub fn resolve_locale<L: Locale>(current_cookie: Option<L>, options: UseLocalesOptions) -> L {
-    cfg!(feature = "hydrate")
-        .then(get_locale_from_html)
-        .flatten()
-        .or(current_cookie)
-        .unwrap_or_else(move || get_accepted_locale(options))
+pub fn resolve_locale<L: Locale>(
+    detectors: &[Box<dyn LocaleDetector<L>>],
+    stored_locale: Option<L>,
+    options: UseLocalesOptions,
+) -> L {
+    let cx = DetectorContext {
+        stored: stored_locale,
+        accepted_languages: leptos_use::use_locales_with_options(options),
+    };
+    detect_locale(detectors, &cx).unwrap_or_default()
 }
 
 pub fn signal_once_then<T: Clone + PartialEq + Send + Sync + 'static>(
@@ -50,12 +56,7 @@ pub fn signal_maybe_once_then<T: Clone + PartialEq + Send + Sync + 'static>(
     }
 }
 
-// ssr fetch
-fn fetch_locale_ssr<L: Locale>(current_cookie: Option<L>, accepted_locale: Memo<L>) -> Memo<L> {
-    signal_maybe_once_then(current_cookie, accepted_locale)
-}
-
-fn get_locale_from_html<L: Locale>() -> Option<L> {
+pub(crate) fn get_locale_from_html<L: Locale>() -> Option<L> {
     leptos::prelude::document()
         .document_element()
         .and_then(|el| match el.get_attribute("lang") {
@@ -67,15 +68,3 @@ fn get_locale_from_html<L: Locale>() -> Option<L> {
         })
         .and_then(|lang| L::from_str(&lang).ok())
 }
-
-// hydrate fetch
-fn fetch_locale_hydrate<L: Locale>(current_cookie: Option<L>, accepted_locale: Memo<L>) -> Memo<L> {
-    let base_locale = get_locale_from_html().or(current_cookie);
-
-    signal_maybe_once_then(base_locale, accepted_locale)
-}
-
-// csr fetch
-fn fetch_locale_csr<L: Locale>(current_cookie: Option<L>, accepted_locale: Memo<L>) -> Memo<L> {
-    signal_maybe_once_then(current_cookie, accepted_locale)
-}
@@ -6,6 +6,8 @@ use crate::Locale;
 
 #[cfg(feature = "dynamic_load")]
 pub use async_once_cell::OnceCell;
+#[cfg(feature = "dynamic_load")]
+pub use futures::join;
 
 pub trait TranslationUnit: Sized {
     type Locale: Locale;
@@ -15,20 +17,51 @@ pub trait TranslationUnit: Sized {
     #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
     const STRINGS: &'static Self::Strings;
 
+    /// Hash of the strings, changing when the translations change.
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    const BUILD_HASH: &'static str;
+
     #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
     fn get_strings_lock() -> &'static OnceCell<Box<Self::Strings>>;
 
+    /// Return the strings, or `None` if they failed to load, the keys then display their path.
     #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
     fn request_strings()
-    -> impl std::future::Future<Output = &'static Self::Strings> + Send + Sync + 'static {
+    -> impl std::future::Future<Output = Option<&'static Self::Strings>> + Send + Sync + 'static
+    {
+        use crate::locale_traits::TranslationUnitId;
+        let options = crate::loading::use_loading_options();
+        let error = crate::loading::use_translations_error();
         let string_lock = Self::get_strings_lock();
-        let fut = string_lock.get_or_init(async {
-            let translations = Locale::request_translations(Self::LOCALE, Self::ID)
-                .await
-                .unwrap();
-            StringArray::cast(translations.0)
+        let fut = string_lock.get_or_try_init(async move {
+            #[cfg(feature = "translations_cache")]
+            if let Some(strings) = crate::translations_cache::get(
+                Self::LOCALE.as_str(),
+                Self::ID.to_str(),
+                Self::BUILD_HASH,
+            )
+            .await
+            {
+                return Ok(StringArray::cast(strings));
+            }
+            let translations = crate::loading::request_with_retries(
+                options,
+                error,
+                Self::LOCALE,
+                Self::ID.to_str(),
+                || Locale::request_translations(Self::LOCALE, Self::ID),
+            )
+            .await?;
+            #[cfg(feature = "translations_cache")]
+            crate::translations_cache::put(
+                Self::LOCALE.as_str(),
+                Self::ID.to_str(),
+                Self::BUILD_HASH,
+                translations.0.clone(),
+            );
+            Ok::<_, ()>(StringArray::cast(translations.0))
         });
-        async move { core::ops::Deref::deref(fut.await) }
+        async move { fut.await.ok().map(core::ops::Deref::deref) }
     }
 
     #[cfg(all(feature = "dynamic_load", feature = "hydrate", not(feature = "ssr")))]
@@ -127,6 +160,84 @@ impl<'de> serde::Deserialize<'de> for LocaleServerFnOutputClient {
     }
 }
 
+/// Codec for the translations files written with the binary encoding of `leptos_i18n_build`,
+/// see `leptos_i18n_parser::binary`.
+#[cfg(feature = "dynamic_load")]
+pub type Binary = leptos::server_fn::codec::Post<BinaryEncoding>;
+
+#[cfg(feature = "dynamic_load")]
+pub struct BinaryEncoding;
+
+#[cfg(feature = "dynamic_load")]
+mod binary {
+    use super::{BinaryEncoding, LocaleServerFnOutputClient, LocaleServerFnOutputServer};
+    use leptos::server_fn::{Bytes, ContentType, Decodes, Encodes, Format, FormatType};
+    use leptos_i18n_parser::binary;
+
+    impl ContentType for BinaryEncoding {
+        const CONTENT_TYPE: &'static str = "application/octet-stream";
+    }
+
+    impl FormatType for BinaryEncoding {
+        const FORMAT_TYPE: Format = Format::Binary;
+    }
+
+    impl Encodes<LocaleServerFnOutputServer> for BinaryEncoding {
+        type Error = std::convert::Infallible;
+
+        fn encode(output: &LocaleServerFnOutputServer) -> Result<Bytes, Self::Error> {
+            Ok(Bytes::from(binary::encode_strings(output.0)))
+        }
+    }
+
+    impl Encodes<LocaleServerFnOutputClient> for BinaryEncoding {
+        type Error = std::convert::Infallible;
+
+        fn encode(_: &LocaleServerFnOutputClient) -> Result<Bytes, Self::Error> {
+            unreachable!("This function should not have been called on the client !")
+        }
+    }
+
+    impl Decodes<LocaleServerFnOutputServer> for BinaryEncoding {
+        type Error = std::convert::Infallible;
+
+        fn decode(_: Bytes) -> Result<LocaleServerFnOutputServer, Self::Error> {
+            unreachable!("This function should not have been called on the server !")
+        }
+    }
+
+    impl Decodes<LocaleServerFnOutputClient> for BinaryEncoding {
+        type Error = &'static str;
+
+        fn decode(bytes: Bytes) -> Result<LocaleServerFnOutputClient, Self::Error> {
+            binary::decode_strings(&bytes).map(LocaleServerFnOutputClient)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn binary_round_trip() {
+            let strings: &'static [&'static str] = &["", "hello", "héllo wörld"];
+            let bytes = BinaryEncoding::encode(&LocaleServerFnOutputServer::new(strings)).unwrap();
+            let decoded: LocaleServerFnOutputClient = BinaryEncoding::decode(bytes).unwrap();
+            assert_eq!(
+                decoded.0,
+                strings.iter().map(|s| Box::from(*s)).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn binary_truncated() {
+            let bytes = Bytes::from_static(&[2, 0, 0, 0, 5, 0, 0, 0, b'h', b'e']);
+            let decoded: Result<LocaleServerFnOutputClient, _> = BinaryEncoding::decode(bytes);
+            assert!(decoded.is_err());
+        }
+    }
+}
+
 #[cfg(all(feature = "dynamic_load", any(feature = "hydrate", feature = "ssr")))]
 const JS_PREFIX: &str = "window.__LEPTOS_I18N_TRANSLATIONS=";
 
@@ -0,0 +1,503 @@
+//! Dev-mode hot reload of the translations with the "hot_reload" feature.
+//!
+//! The server watches the locales files, parses them again when they change and sends the new strings to the browser,
+//! which polls it through a server function. The views are updated without recompiling the application.
+//!
+//! Only the values can be hot reloaded: adding or removing keys, variables or components still requires a rebuild,
+//! the keys changed that way are reported in the server logs and keep their previous value.
+//!
+//! This is meant for development only: the strings are not deduplicated and every reloaded string is leaked.
+//! In release builds the server doesn't watch the files nor serve the reloaded strings, and the client doesn't poll it.
+
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Where the locales files are and how to parse them, generated by the codegen.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct HotReloadConfig {
+    /// `None` if the translations are not declared in files.
+    pub locales_dir: Option<&'static str>,
+    /// `None` if the files use a custom parser.
+    pub file_format: Option<&'static str>,
+    pub default_locale: &'static str,
+    pub locales: &'static [&'static str],
+    pub namespaces: &'static [&'static str],
+    pub extensions: &'static [(&'static str, &'static str)],
+    pub runtime_fallback: bool,
+    pub units: &'static [HotReloadUnit],
+}
+
+/// Indices of the strings used by each key of a translation unit, generated by the codegen.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct HotReloadUnit {
+    pub locale: &'static str,
+    pub namespace: Option<&'static str>,
+    pub strings_count: usize,
+    pub keys: &'static [(&'static str, &'static [usize])],
+}
+
+/// New strings of a translation unit, `None` for the strings that can't be replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitPatch {
+    locale: String,
+    namespace: Option<String>,
+    strings: Vec<Option<String>>,
+}
+
+/// Strings replaced since the application was built, sent to the client when they change.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotReloadUpdate {
+    version: u64,
+    patches: Vec<UnitPatch>,
+}
+
+type UnitId = (&'static str, Option<&'static str>);
+
+#[derive(Default)]
+struct State {
+    update: HotReloadUpdate,
+    merged: HashMap<UnitId, (u64, &'static [&'static str])>,
+}
+
+fn state() -> &'static RwLock<State> {
+    static STATE: OnceLock<RwLock<State>> = OnceLock::new();
+    STATE.get_or_init(Default::default)
+}
+
+fn trigger() -> &'static ArcTrigger {
+    static TRIGGER: OnceLock<ArcTrigger> = OnceLock::new();
+    TRIGGER.get_or_init(ArcTrigger::new)
+}
+
+#[cfg(debug_assertions)]
+fn apply_update(update: HotReloadUpdate) {
+    let mut state = state().write().unwrap();
+    if state.update.version != update.version {
+        state.update = update;
+        drop(state);
+        trigger().notify();
+    }
+}
+
+fn merge<const N: usize>(
+    patch: Option<&UnitPatch>,
+    baked: &'static [&'static str; N],
+) -> &'static [&'static str] {
+    match patch {
+        Some(patch) if patch.strings.len() == N => {
+            let merged = baked
+                .iter()
+                .zip(&patch.strings)
+                .map(|(baked, new)| match new {
+                    Some(new) if new != baked => &*Box::leak(new.clone().into_boxed_str()),
+                    _ => *baked,
+                })
+                .collect::<Box<[&'static str]>>();
+            Box::leak(merged)
+        }
+        _ => baked,
+    }
+}
+
+/// Return the strings of a translation unit, with the strings reloaded since the build.
+#[doc(hidden)]
+pub fn strings<const N: usize>(
+    config: &'static HotReloadConfig,
+    locale: &'static str,
+    namespace: Option<&'static str>,
+    baked: &'static [&'static str; N],
+) -> &'static [&'static str; N] {
+    #[cfg(all(feature = "ssr", debug_assertions))]
+    server::watch(config);
+    #[cfg(not(all(feature = "ssr", debug_assertions)))]
+    let _ = config;
+
+    trigger().track();
+    let id = (locale, namespace);
+    {
+        let state = state().read().unwrap();
+        if state.update.version == 0 {
+            return baked;
+        }
+        if let Some((version, merged)) = state.merged.get(&id)
+            && *version == state.update.version
+        {
+            return (*merged).try_into().unwrap_or(baked);
+        }
+    }
+    let mut state = state().write().unwrap();
+    let patch = state
+        .update
+        .patches
+        .iter()
+        .find(|patch| patch.locale == locale && patch.namespace.as_deref() == namespace);
+    let merged = merge(patch, baked);
+    let version = state.update.version;
+    state.merged.insert(id, (version, merged));
+    merged.try_into().unwrap_or(baked)
+}
+
+// private as the server function is only called by the polling loop.
+#[cfg(debug_assertions)]
+mod endpoint {
+    use super::HotReloadUpdate;
+    use leptos::prelude::*;
+
+    /// Return the strings reloaded by the server if they changed since `version`.
+    #[server(prefix = "/__leptos_i18n", endpoint = "hot_reload")]
+    pub async fn i18n_hot_reload(version: u64) -> Result<Option<HotReloadUpdate>, ServerFnError> {
+        let state = super::state().read().unwrap();
+        Ok((state.update.version != version).then(|| state.update.clone()))
+    }
+}
+
+/// Start polling the server for new translations.
+#[cfg(all(not(feature = "ssr"), debug_assertions))]
+pub(crate) fn start_polling() {
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+    static STARTED: OnceLock<()> = OnceLock::new();
+    if STARTED.set(()).is_err() {
+        return;
+    }
+    leptos::task::spawn_local(async move {
+        let mut failing = false;
+        loop {
+            let (tx, rx) = futures::channel::oneshot::channel::<()>();
+            set_timeout(
+                move || {
+                    let _ = tx.send(());
+                },
+                POLL_INTERVAL,
+            );
+            let _ = rx.await;
+            let version = state().read().unwrap().update.version;
+            match endpoint::i18n_hot_reload(version).await {
+                Ok(update) => {
+                    failing = false;
+                    if let Some(update) = update {
+                        apply_update(update);
+                    }
+                }
+                // the server is usually restarting, only report the first failure.
+                Err(err) if !failing => {
+                    failing = true;
+                    leptos::logging::warn!("failed to poll the translations hot reload: {}", err);
+                }
+                Err(_) => {}
+            }
+        }
+    });
+}
+
+#[cfg(all(feature = "ssr", debug_assertions))]
+mod server {
+    use super::{HotReloadConfig, HotReloadUpdate, UnitPatch, apply_update, state};
+    use leptos_i18n_parser::parse_locales::{
+        ParsedLocales,
+        locale::{BuildersKeys, Locale},
+        options::{Config, FileFormat, ParseOptions},
+        parse_locales,
+    };
+    use std::{
+        path::{Path, PathBuf},
+        sync::OnceLock,
+        time::{Duration, SystemTime},
+    };
+
+    const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+    type Fingerprint = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+    fn fingerprint(dir: &Path, acc: &mut Fingerprint) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                fingerprint(&path, acc);
+            } else {
+                acc.push((path, metadata.modified().ok(), metadata.len()));
+            }
+        }
+    }
+
+    fn dir_fingerprint(dir: &Path) -> Fingerprint {
+        let mut acc = Vec::new();
+        fingerprint(dir, &mut acc);
+        acc.sort();
+        acc
+    }
+
+    pub(super) fn parse(
+        config: &HotReloadConfig,
+        locales_dir: &'static str,
+    ) -> Result<ParsedLocales, String> {
+        let file_format = match config.file_format {
+            Some("json") => FileFormat::Json,
+            Some("json5") => FileFormat::Json5,
+            Some("yaml") => FileFormat::Yaml,
+            Some("toml") => FileFormat::Toml,
+            _ => return Err("custom file formats can't be hot reloaded".to_owned()),
+        };
+        let locales = config
+            .locales
+            .iter()
+            .filter(|locale| **locale != config.default_locale);
+        let mut cfg = Config::new(config.default_locale)
+            .and_then(|cfg| cfg.add_locales(locales))
+            .and_then(|cfg| cfg.add_namespaces(config.namespaces))
+            .map_err(|err| err.to_string())?;
+        for (locale, inherit_from) in config.extensions {
+            cfg = cfg
+                .extend_locale(locale, inherit_from)
+                .map_err(|err| err.to_string())?;
+        }
+        let options = ParseOptions::new()
+            .file_format(file_format)
+            .runtime_fallback(config.runtime_fallback)
+            .suppress_key_warnings(true);
+        let cfg = cfg.locales_path(locales_dir).parse_options(options);
+        parse_locales(Some(PathBuf::new()), cfg).map_err(|err| err.to_string())
+    }
+
+    /// Map the new strings to the indices of the compiled tables, returning the patches and the keys that changed structurally.
+    pub(super) fn make_patches(
+        config: &HotReloadConfig,
+        parsed: &ParsedLocales,
+    ) -> (Vec<UnitPatch>, Vec<String>) {
+        let units: Vec<(Option<&str>, &Locale)> = match &parsed.builder_keys {
+            BuildersKeys::Locales { locales, .. } => {
+                locales.iter().map(|locale| (None, locale)).collect()
+            }
+            BuildersKeys::NameSpaces { namespaces, .. } => namespaces
+                .iter()
+                .flat_map(|namespace| {
+                    namespace
+                        .locales
+                        .iter()
+                        .map(|locale| (Some(&*namespace.key.name), locale))
+                })
+                .collect(),
+        };
+        let mut patches = Vec::with_capacity(config.units.len());
+        let mut changed = Vec::new();
+        for unit in config.units {
+            let Some((_, locale)) = units.iter().find(|(namespace, locale)| {
+                *namespace == unit.namespace && &*locale.top_locale_name.name == unit.locale
+            }) else {
+                continue;
+            };
+            let mut strings = vec![None; unit.strings_count];
+            for (path, indices) in unit.keys {
+                match locale.key_strings.get(*path) {
+                    Some(new_indices) if new_indices.len() == indices.len() => {
+                        for (index, new_index) in indices.iter().zip(new_indices) {
+                            strings[*index] = Some(locale.strings[*new_index].to_string());
+                        }
+                    }
+                    _ => changed.push(format!("{}: {}", unit.locale, path)),
+                }
+            }
+            for path in locale.key_strings.keys() {
+                if !unit.keys.iter().any(|(key, _)| **key == **path) {
+                    changed.push(format!("{}: {}", unit.locale, path));
+                }
+            }
+            patches.push(UnitPatch {
+                locale: unit.locale.to_owned(),
+                namespace: unit.namespace.map(str::to_owned),
+                strings,
+            });
+        }
+        (patches, changed)
+    }
+
+    fn reload(config: &HotReloadConfig, locales_dir: &'static str) {
+        let parsed = match parse(config, locales_dir) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                leptos::logging::warn!("failed to hot reload the translations: {}", err);
+                return;
+            }
+        };
+        let (patches, changed) = make_patches(config, &parsed);
+        if !changed.is_empty() {
+            leptos::logging::warn!(
+                "the structure of these translations changed, rebuild the application to apply them: {}",
+                changed.join(", ")
+            );
+        }
+        let version = state().read().unwrap().update.version + 1;
+        apply_update(HotReloadUpdate { version, patches });
+        leptos::logging::log!("translations reloaded");
+    }
+
+    /// Start watching the locales files, once.
+    pub(super) fn watch(config: &'static HotReloadConfig) {
+        static STARTED: OnceLock<()> = OnceLock::new();
+        if STARTED.set(()).is_err() {
+            return;
+        }
+        let Some(locales_dir) = config.locales_dir else {
+            return;
+        };
+        std::thread::spawn(move || {
+            let mut last = dir_fingerprint(Path::new(locales_dir));
+            loop {
+                std::thread::sleep(WATCH_INTERVAL);
+                let current = dir_fingerprint(Path::new(locales_dir));
+                if current != last {
+                    last = current;
+                    reload(config, locales_dir);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnitPatch, merge};
+
+    static BAKED: [&str; 3] = ["a", "b", "c"];
+
+    fn patch(strings: &[Option<&str>]) -> UnitPatch {
+        UnitPatch {
+            locale: "en".to_owned(),
+            namespace: None,
+            strings: strings.iter().map(|s| s.map(str::to_owned)).collect(),
+        }
+    }
+
+    #[test]
+    fn merge_without_patch() {
+        assert!(std::ptr::eq(merge(None, &BAKED), &BAKED[..]));
+    }
+
+    #[test]
+    fn merge_patch() {
+        let merged = merge(Some(&patch(&[None, Some("B"), Some("c")])), &BAKED);
+        assert_eq!(merged, ["a", "B", "c"]);
+        // the strings that did not change keep the compiled ones
+        assert!(std::ptr::eq(merged[0], BAKED[0]));
+        assert!(std::ptr::eq(merged[2], BAKED[2]));
+    }
+
+    #[test]
+    fn merge_patch_with_other_strings_count() {
+        let merged = merge(Some(&patch(&[Some("A")])), &BAKED);
+        assert!(std::ptr::eq(merged, &BAKED[..]));
+    }
+
+    #[cfg(all(feature = "ssr", debug_assertions))]
+    mod server {
+        use super::super::{HotReloadConfig, HotReloadUnit, server::*};
+        use leptos_i18n_parser::parse_locales::{ParsedLocales, locale::BuildersKeys};
+        use std::path::Path;
+
+        fn leak<T: ?Sized>(value: Box<T>) -> &'static T {
+            Box::leak(value)
+        }
+
+        /// Units as the codegen would generate them for the parsed translations.
+        fn units(parsed: &ParsedLocales) -> &'static [HotReloadUnit] {
+            let BuildersKeys::Locales { locales, .. } = &parsed.builder_keys else {
+                unreachable!("no namespaces are declared");
+            };
+            let units = locales
+                .iter()
+                .map(|locale| HotReloadUnit {
+                    locale: leak(locale.top_locale_name.name.to_string().into_boxed_str()),
+                    namespace: None,
+                    strings_count: locale.top_locale_string_count,
+                    keys: leak(
+                        locale
+                            .key_strings
+                            .iter()
+                            .map(|(path, indices)| {
+                                (
+                                    leak(path.to_string().into_boxed_str()),
+                                    leak(indices.clone().into_boxed_slice()),
+                                )
+                            })
+                            .collect(),
+                    ),
+                })
+                .collect();
+            leak(units)
+        }
+
+        fn write_locales(dir: &Path, en: &str, fr: &str) {
+            std::fs::write(dir.join("en.json"), en).unwrap();
+            std::fs::write(dir.join("fr.json"), fr).unwrap();
+        }
+
+        #[test]
+        fn patches_and_structural_changes() {
+            let dir = std::env::temp_dir().join(format!(
+                "leptos_i18n_hot_reload_test_{}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let locales_dir: &'static str = leak(dir.to_str().unwrap().into());
+            write_locales(
+                &dir,
+                r#"{ "hello": "Hello", "bye": "Bye {{ name }}" }"#,
+                r#"{ "hello": "Bonjour", "bye": "Au revoir {{ name }}" }"#,
+            );
+
+            let mut config = HotReloadConfig {
+                locales_dir: Some(locales_dir),
+                file_format: Some("json"),
+                default_locale: "en",
+                locales: &["en", "fr"],
+                namespaces: &[],
+                extensions: &[],
+                runtime_fallback: false,
+                units: &[],
+            };
+            config.units = units(&parse(&config, locales_dir).unwrap());
+
+            // "bye" now uses two strings in english, it can't be patched.
+            write_locales(
+                &dir,
+                r#"{ "hello": "Hi", "bye": "Bye {{ name }}, see you" }"#,
+                r#"{ "hello": "Salut", "bye": "Au revoir {{ name }}" }"#,
+            );
+            let parsed = parse(&config, locales_dir).unwrap();
+            let (patches, changed) = make_patches(&config, &parsed);
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert_eq!(changed, ["en: bye"]);
+            assert_eq!(patches.len(), 2);
+            let strings_of = |locale: &str, key: &str| {
+                let unit = config
+                    .units
+                    .iter()
+                    .find(|unit| unit.locale == locale)
+                    .unwrap();
+                let patch = patches.iter().find(|patch| patch.locale == locale).unwrap();
+                let (_, indices) = unit.keys.iter().find(|(path, _)| *path == key).unwrap();
+                indices
+                    .iter()
+                    .map(|index| patch.strings[*index].as_deref())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(strings_of("en", "hello"), [Some("Hi")]);
+            assert_eq!(strings_of("en", "bye"), [None]);
+            assert_eq!(strings_of("fr", "hello"), [Some("Salut")]);
+            assert_eq!(strings_of("fr", "bye"), [Some("Au revoir ")]);
+        }
+    }
+}
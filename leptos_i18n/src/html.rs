@@ -0,0 +1,472 @@
+//! Rendering of translations to HTML strings with the `t_html!` macros.
+//!
+//! `t_display!` writes the components of a translation with the `DisplayComponent` given for each of them, and writes
+//! the text and the variables as is. `t_html!` outputs HTML safe to put in an email or in `inner_html`:
+//! the text and the variables are escaped, and the components are rendered from the templates of a `HtmlComponents` registry.
+//!
+//! ```rust, ignore
+//! // "terms": "Read the <link>terms</link>, {{ name }}"
+//! let components = HtmlComponents::default().add(
+//!     "link",
+//!     HtmlTemplate::tag("a").attr("href", "https://example.com/terms"),
+//! );
+//! let html = td_html!(Locale::en, terms, name = "<Bob>").with_components(components);
+//! assert_eq!(html.to_string(), r#"Read the <a href="https://example.com/terms">terms</a>, &lt;Bob&gt;"#);
+//! ```
+//!
+//! The components of the translations are filled by the macro, they can't be given as arguments.
+//! A component without a template in the registry only renders its children.
+
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt::{self, Display, Write},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::display::{Attributes, DisplayComponent};
+
+/// Attributes whose value is an URL, for which values using a script scheme are dropped.
+const URL_ATTRIBUTES: &[&str] = &["href", "src", "action", "formaction", "xlink:href"];
+
+/// Elements without a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Formatting tags registered by `HtmlComponents::default`, rendered as the same tag without attributes.
+const DEFAULT_TAGS: &[&str] = &[
+    "b",
+    "strong",
+    "i",
+    "em",
+    "u",
+    "s",
+    "small",
+    "mark",
+    "sub",
+    "sup",
+    "code",
+    "br",
+    "p",
+    "span",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "ul",
+    "li",
+    "blockquote",
+    "pre",
+    "hr",
+];
+
+/// How a component of a translation is rendered to HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlTemplate {
+    tag: Option<Cow<'static, str>>,
+    attrs: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    allowed_attrs: Vec<Cow<'static, str>>,
+}
+
+impl HtmlTemplate {
+    /// Render the component as the `tag` element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` is not a valid tag name.
+    pub fn tag(tag: impl Into<Cow<'static, str>>) -> Self {
+        let tag = tag.into();
+        assert!(is_valid_name(&tag), "invalid HTML tag name: {tag:?}");
+        HtmlTemplate {
+            tag: Some(tag),
+            attrs: Vec::new(),
+            allowed_attrs: Vec::new(),
+        }
+    }
+
+    /// Only render the children of the component.
+    pub fn children_only() -> Self {
+        HtmlTemplate {
+            tag: None,
+            attrs: Vec::new(),
+            allowed_attrs: Vec::new(),
+        }
+    }
+
+    /// Add an attribute to the element, its value is escaped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid attribute name.
+    pub fn attr(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let name = name.into();
+        assert!(
+            is_valid_name(&name),
+            "invalid HTML attribute name: {name:?}"
+        );
+        self.attrs.push((name, value.into()));
+        self
+    }
+
+    /// Keep the attribute `name` when it is set on the component in the translation, such as `<link title={{ title }}>`.
+    ///
+    /// Attributes of the translations are dropped unless allowed, their value is escaped,
+    /// and URLs using the `javascript:`, `vbscript:` or `data:` schemes are dropped.
+    pub fn allow_attr(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.allowed_attrs.push(name.into());
+        self
+    }
+
+    fn fmt_open(&self, f: &mut fmt::Formatter<'_>, tag: &str, attrs: Attributes) -> fmt::Result {
+        write!(f, "<{tag}")?;
+        for (name, value) in &self.attrs {
+            write!(f, " {name}=\"")?;
+            escape(f, value)?;
+            f.write_char('"')?;
+        }
+        for attr in attrs.0 {
+            let rendered = DisplayFn(*attr).to_string();
+            if let Some((name, value)) = parse_attribute(&rendered)
+                && self.allowed_attrs.iter().any(|allowed| allowed == name)
+                && !value.is_some_and(|value| is_script_url(name, value))
+            {
+                write!(f, " {name}")?;
+                if let Some(value) = value {
+                    f.write_str("=\"")?;
+                    escape(f, value)?;
+                    f.write_char('"')?;
+                }
+            }
+        }
+        f.write_char('>')
+    }
+}
+
+/// A registry of the templates used to render the components of the translations to HTML, by component name.
+///
+/// Cloning the registry is cheap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlComponents(Arc<HashMap<Cow<'static, str>, HtmlTemplate>>);
+
+impl HtmlComponents {
+    /// Create an empty registry, where every component only renders its children.
+    pub fn new() -> Self {
+        HtmlComponents(Arc::default())
+    }
+
+    /// Add the template for the components named `name`, such as `"link"` for `<link>`.
+    pub fn add(mut self, name: impl Into<Cow<'static, str>>, template: HtmlTemplate) -> Self {
+        Arc::make_mut(&mut self.0).insert(name.into(), template);
+        self
+    }
+
+    /// Return the template for the components named `name`.
+    pub fn get(&self, name: &str) -> Option<&HtmlTemplate> {
+        self.0.get(name)
+    }
+
+    /// Set the registry used by `t_html!` when none is given with `Html::with_components`.
+    pub fn set_global(self) {
+        *global().write().unwrap() = self;
+    }
+
+    /// Return the registry used by `t_html!` when none is given with `Html::with_components`,
+    /// the default registry until `set_global` is called.
+    pub fn global() -> Self {
+        global().read().unwrap().clone()
+    }
+}
+
+/// The registry of common formatting tags, such as `<b>`, `<em>` or `<br/>`, rendered as themselves without attributes,
+/// and of the other elements of the Markdown keys, `<a>`, `<img>` and `<ol>`, which keep their attributes.
+impl Default for HtmlComponents {
+    fn default() -> Self {
+        DEFAULT_TAGS
+            .iter()
+            .fold(HtmlComponents::new(), |components, tag| {
+                components.add(*tag, HtmlTemplate::tag(*tag))
+            })
+            .add(
+                "a",
+                HtmlTemplate::tag("a")
+                    .allow_attr("href")
+                    .allow_attr("title"),
+            )
+            .add(
+                "img",
+                HtmlTemplate::tag("img")
+                    .allow_attr("src")
+                    .allow_attr("alt")
+                    .allow_attr("title"),
+            )
+            .add("ol", HtmlTemplate::tag("ol").allow_attr("start"))
+    }
+}
+
+fn global() -> &'static RwLock<HtmlComponents> {
+    static GLOBAL: OnceLock<RwLock<HtmlComponents>> = OnceLock::new();
+    GLOBAL.get_or_init(Default::default)
+}
+
+thread_local! {
+    /// Set while a component writes its tags, the rest of the output is escaped.
+    static RAW: Cell<bool> = const { Cell::new(false) };
+    /// The registry of the `Html` being formatted.
+    static CURRENT: RefCell<Option<HtmlComponents>> = const { RefCell::new(None) };
+}
+
+fn write_raw(
+    f: &mut fmt::Formatter<'_>,
+    write: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let previous = RAW.replace(true);
+    let result = write(f);
+    RAW.set(previous);
+    result
+}
+
+/// The output of the `t_html!` macros, formatted as HTML.
+#[derive(Debug, Clone)]
+pub struct Html<T> {
+    inner: T,
+    components: Option<HtmlComponents>,
+}
+
+impl<T: Display> Html<T> {
+    #[doc(hidden)]
+    pub fn new(inner: T) -> Self {
+        Html {
+            inner,
+            components: None,
+        }
+    }
+
+    /// Render the components with `components` instead of the global registry.
+    pub fn with_components(self, components: HtmlComponents) -> Self {
+        Html {
+            components: Some(components),
+            ..self
+        }
+    }
+}
+
+impl<T: Display> Display for Html<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self
+            .components
+            .clone()
+            .unwrap_or_else(HtmlComponents::global);
+        // restored afterward in case `inner` contains another `Html`.
+        let previous_components = CURRENT.replace(Some(components));
+        let previous_raw = RAW.replace(false);
+        let result = write!(Escaper(f), "{}", self.inner);
+        RAW.set(previous_raw);
+        CURRENT.set(previous_components);
+        result
+    }
+}
+
+/// Escape everything written to it, except the tags written by the components.
+struct Escaper<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl Write for Escaper<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if RAW.get() {
+            self.0.write_str(s)
+        } else {
+            escape(self.0, s)
+        }
+    }
+}
+
+/// A component of a translation rendered with the template of the same name.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct HtmlComp(&'static str);
+
+impl HtmlComp {
+    pub const fn new(name: &'static str) -> Self {
+        HtmlComp(name)
+    }
+
+    fn template(self) -> Option<HtmlTemplate> {
+        CURRENT
+            .with_borrow(|current| {
+                current
+                    .as_ref()
+                    .map(|components| components.get(self.0).cloned())
+            })
+            .unwrap_or_else(|| HtmlComponents::global().get(self.0).cloned())
+    }
+}
+
+impl DisplayComponent<()> for HtmlComp {
+    fn fmt<T>(&self, f: &mut fmt::Formatter<'_>, children: T, attrs: Attributes) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        let template = self.template();
+        let Some((template, tag)) = template.as_ref().and_then(|t| Some((t, t.tag.as_deref()?)))
+        else {
+            return children(f);
+        };
+        write_raw(f, |f| template.fmt_open(f, tag, attrs))?;
+        children(f)?;
+        if VOID_ELEMENTS.contains(&tag) {
+            return Ok(());
+        }
+        write_raw(f, |f| write!(f, "</{tag}>"))
+    }
+
+    fn fmt_self_closing(&self, f: &mut fmt::Formatter<'_>, attrs: Attributes) -> fmt::Result {
+        self.fmt(f, |_| Ok(()), attrs)
+    }
+}
+
+struct DisplayFn<'a>(crate::display::DynDisplayFn<'a>);
+
+impl Display for DisplayFn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Parse an attribute formatted by `AttributeValue`, ` name="value"` or ` name`.
+fn parse_attribute(s: &str) -> Option<(&str, Option<&str>)> {
+    let s = s.strip_prefix(' ')?;
+    let (name, value) = match s.split_once('=') {
+        Some((name, value)) => (name, Some(value.strip_prefix('"')?.strip_suffix('"')?)),
+        None => (s, None),
+    };
+    is_valid_name(name).then_some((name, value))
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':'))
+}
+
+fn is_script_url(name: &str, value: &str) -> bool {
+    if !URL_ATTRIBUTES.contains(&name) {
+        return false;
+    }
+    // browsers ignore whitespaces and control characters in the scheme.
+    let scheme = value
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .take_while(|c| *c != ':')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    value.contains(':') && matches!(scheme.as_str(), "javascript" | "vbscript" | "data")
+}
+
+fn escape(f: &mut impl Write, s: &str) -> fmt::Result {
+    let mut rest = s;
+    while let Some(i) = rest.find(['&', '<', '>', '"', '\'']) {
+        f.write_str(&rest[..i])?;
+        f.write_str(match rest.as_bytes()[i] {
+            b'&' => "&amp;",
+            b'<' => "&lt;",
+            b'>' => "&gt;",
+            b'"' => "&quot;",
+            _ => "&#x27;",
+        })?;
+        rest = &rest[i + 1..];
+    }
+    f.write_str(rest)
+}
+
+/// Escape `s` to be used as HTML text or attribute value.
+///
+/// ```
+/// # use leptos_i18n::html::escape_html;
+/// assert_eq!(escape_html("<b>\"Tom\" & Jerry</b>"), "&lt;b&gt;&quot;Tom&quot; &amp; Jerry&lt;/b&gt;");
+/// ```
+pub fn escape_html(s: &str) -> Cow<'_, str> {
+    if !s.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len() + 8);
+    let _ = escape(&mut out, s);
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Text around a component, as formatted by the builders of `t_html!`.
+    struct Translation {
+        comp: HtmlComp,
+        attrs: &'static [(&'static str, &'static str)],
+    }
+
+    impl Display for Translation {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let attrs = self
+                .attrs
+                .iter()
+                .map(|(name, value)| {
+                    move |f: &mut fmt::Formatter<'_>| write!(f, " {name}=\"{value}\"")
+                })
+                .collect::<Vec<_>>();
+            let attrs = attrs
+                .iter()
+                .map(|attr| attr as crate::display::DynDisplayFn)
+                .collect::<Vec<_>>();
+            f.write_str("1 < 2 ")?;
+            self.comp
+                .fmt(f, |f| f.write_str("\"a\" & b"), Attributes(&attrs))
+        }
+    }
+
+    fn render(name: &'static str, attrs: &'static [(&'static str, &'static str)]) -> String {
+        let components = HtmlComponents::default().add(
+            "link",
+            HtmlTemplate::tag("a")
+                .attr("class", "x\"y")
+                .allow_attr("href")
+                .allow_attr("title"),
+        );
+        let translation = Translation {
+            comp: HtmlComp::new(name),
+            attrs,
+        };
+        Html::new(translation)
+            .with_components(components)
+            .to_string()
+    }
+
+    #[test]
+    fn escape_text() {
+        assert_eq!(
+            render("b", &[("title", "t")]),
+            "1 &lt; 2 <b>&quot;a&quot; &amp; b</b>"
+        );
+        assert_eq!(render("unknown", &[]), "1 &lt; 2 &quot;a&quot; &amp; b");
+        assert_eq!(render("br", &[]), "1 &lt; 2 <br>&quot;a&quot; &amp; b");
+    }
+
+    #[test]
+    fn template_attributes() {
+        assert_eq!(
+            render("link", &[("href", "/a?b=1&c=2"), ("onclick", "alert(1)")]),
+            "1 &lt; 2 <a class=\"x&quot;y\" href=\"/a?b=1&amp;c=2\">&quot;a&quot; &amp; b</a>"
+        );
+        assert_eq!(
+            render("link", &[("href", " JavaScript:alert(1)"), ("title", "t")]),
+            "1 &lt; 2 <a class=\"x&quot;y\" title=\"t\">&quot;a&quot; &amp; b</a>"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! In-context key inspector with the "key_inspector" feature.
+//!
+//! When the inspector is enabled with `I18nContext::set_inspect_keys`, the output of `t!` is wrapped in a
+//! `<span data-i18n-key=".." data-i18n-locale="..">` marker, and the output of `t_string!` and `t_display!` ends
+//! with an invisible tag made of unicode tag characters (U+E0000 block) holding the key and the locale.
+//! The `KeyInspector` component shows the key of the text under the cursor, alt-click pins it:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! view! {
+//!     <KeyInspector />
+//!     <button on:click=move |_| i18n.set_inspect_keys(!i18n.inspect_keys())>"Inspect keys"</button>
+//!     <p>{t!(i18n, hello_world)}</p>
+//! }
+//! ```
+//!
+//! Keys are shown as they are written in the macro, such as `form.email`.
+//! The inspector is enabled for the context it is set on and its subcontexts, on the server each request has its own flag.
+//!
+//! Everything is stripped in release builds: without `debug_assertions` nothing is ever marked and the component renders nothing.
+
+use leptos::prelude::*;
+use std::fmt::Display;
+
+#[cfg(debug_assertions)]
+mod imp {
+    use std::{
+        collections::HashSet,
+        sync::{OnceLock, RwLock},
+    };
+
+    pub fn intern(value: String) -> &'static str {
+        static INTERNED: OnceLock<RwLock<HashSet<&'static str>>> = OnceLock::new();
+        let interned = INTERNED.get_or_init(Default::default);
+        if let Some(value) = interned.read().unwrap().get(&*value) {
+            return value;
+        }
+        let value: &'static str = Box::leak(value.into_boxed_str());
+        interned.write().unwrap().insert(value);
+        value
+    }
+}
+
+/// Inspector flag of the closest `I18nContext`, provided next to it so the macros can find it without knowing the locale type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InspectorContext(pub RwSignal<bool>);
+
+/// Start of an inspector tag (LANGUAGE TAG).
+const TAG_START: char = '\u{E0001}';
+/// End of an inspector tag (CANCEL TAG).
+const TAG_END: char = '\u{E007F}';
+const TAG_OFFSET: u32 = 0xE0000;
+
+/// Encode `path@locale` with invisible unicode tag characters, non-ASCII characters are skipped.
+fn encode_tag(path: &str, locale: &str) -> String {
+    let mut tag = String::with_capacity((path.len() + locale.len() + 3) * 4);
+    tag.push(TAG_START);
+    let chars = path.chars().chain(Some('@')).chain(locale.chars());
+    tag.extend(
+        chars
+            .filter(|c| c.is_ascii_graphic())
+            .filter_map(|c| char::from_u32(c as u32 + TAG_OFFSET)),
+    );
+    tag.push(TAG_END);
+    tag
+}
+
+/// Return the key path and the locale of every inspector tag found in `s`.
+pub fn find_tags(s: &str) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find(TAG_START) {
+        rest = &rest[start + TAG_START.len_utf8()..];
+        let Some(end) = rest.find(TAG_END) else {
+            break;
+        };
+        let decoded = rest[..end]
+            .chars()
+            .filter_map(|c| char::from_u32((c as u32).checked_sub(TAG_OFFSET)?))
+            .collect::<String>();
+        if let Some((path, locale)) = decoded.rsplit_once('@') {
+            found.push((path.to_owned(), locale.to_owned()));
+        }
+        rest = &rest[end..];
+    }
+    found
+}
+
+/// Remove the inspector tags from `s`, for example before sending a string built with `t_string!` to a server.
+pub fn strip_tags(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            TAG_START => in_tag = true,
+            TAG_END if in_tag => in_tag = false,
+            _ if in_tag => {}
+            c => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+/// Return `true` if the inspector of the closest context is enabled, subscribing to changes inside a reactive context.
+///
+/// Always `false` in release builds.
+pub(crate) fn is_enabled() -> bool {
+    cfg!(debug_assertions)
+        && use_context::<InspectorContext>()
+            .and_then(|InspectorContext(enabled)| crate::context::get_maybe_tracked(&enabled))
+            .unwrap_or(false)
+}
+
+/// Strings returned by `t_string!` that can carry an inspector tag.
+#[doc(hidden)]
+pub trait TagString {
+    fn tagged(self, tag: &str) -> Self;
+}
+
+impl TagString for &'static str {
+    fn tagged(self, tag: &str) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            imp::intern(format!("{self}{tag}"))
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = tag;
+            self
+        }
+    }
+}
+
+impl TagString for String {
+    fn tagged(mut self, tag: &str) -> Self {
+        self.push_str(tag);
+        self
+    }
+}
+
+#[doc(hidden)]
+pub fn tag_string<S: TagString>(path: &'static str, locale: &'static str, s: S) -> S {
+    if is_enabled() {
+        s.tagged(&encode_tag(path, locale))
+    } else {
+        s
+    }
+}
+
+#[doc(hidden)]
+pub fn tag_display<D: Display>(path: &'static str, locale: &'static str, d: D) -> impl Display {
+    struct Tagged<D>(D, Option<String>);
+
+    impl<D: Display> Display for Tagged<D> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Display::fmt(&self.0, f)?;
+            match &self.1 {
+                Some(tag) => f.write_str(tag),
+                None => Ok(()),
+            }
+        }
+    }
+
+    let tag = is_enabled().then(|| encode_tag(path, locale));
+    Tagged(d, tag)
+}
+
+#[doc(hidden)]
+pub fn mark_view<V: IntoView + 'static>(
+    path: &'static str,
+    locale: impl Fn() -> &'static str + 'static,
+    view: impl Fn() -> V + 'static,
+) -> impl IntoView {
+    #[cfg(debug_assertions)]
+    {
+        use leptos::either::Either;
+        let locale = StoredValue::new_local(locale);
+        let view = StoredValue::new_local(view);
+        move || {
+            if is_enabled() {
+                Either::Left(view! {
+                    <span
+                        data-i18n-key=path
+                        data-i18n-locale=move || locale.with_value(|locale| locale())
+                    >
+                        {view.with_value(|view| view())}
+                    </span>
+                })
+            } else {
+                Either::Right(view.with_value(|view| view()))
+            }
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = (path, locale);
+        view()
+    }
+}
+
+#[cfg(all(debug_assertions, not(feature = "ssr")))]
+fn find_key(target: Option<web_sys::EventTarget>) -> Option<(String, String)> {
+    use wasm_bindgen::JsCast;
+
+    let element = target?.dyn_into::<web_sys::Element>().ok()?;
+    if let Ok(Some(marker)) = element.closest("[data-i18n-key]") {
+        let key = marker.get_attribute("data-i18n-key")?;
+        let locale = marker.get_attribute("data-i18n-locale").unwrap_or_default();
+        return Some((key, locale));
+    }
+    // strings from `t_string!` can be in the text or in the attributes of the element.
+    let texts = ["title", "placeholder", "alt", "aria-label", "value"]
+        .into_iter()
+        .filter_map(|attr| element.get_attribute(attr))
+        .chain(element.text_content());
+    texts.flat_map(|text| find_tags(&text)).next()
+}
+
+/// Overlay showing the key of the translation under the cursor while the inspector is enabled.
+///
+/// Alt-click on a translation pins its key, alt-click again to unpin it.
+/// Renders nothing in release builds.
+#[component]
+pub fn KeyInspector() -> impl IntoView {
+    #[cfg(debug_assertions)]
+    {
+        let hovered = RwSignal::new(None::<(String, String)>);
+        let pinned = RwSignal::new(false);
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let enabled =
+                use_context::<InspectorContext>().map(|InspectorContext(enabled)| enabled);
+            let hover_handle = window_event_listener(leptos::ev::mouseover, move |ev| {
+                if !pinned.get_untracked() {
+                    hovered.set(find_key(ev.target()));
+                }
+            });
+            let click_handle = window_event_listener(leptos::ev::click, move |ev| {
+                let enabled = enabled.is_some_and(|enabled| enabled.get_untracked());
+                if !ev.alt_key() || !enabled {
+                    return;
+                }
+                ev.prevent_default();
+                ev.stop_propagation();
+                if pinned.get_untracked() {
+                    pinned.set(false);
+                } else {
+                    hovered.set(find_key(ev.target()));
+                    pinned.set(true);
+                }
+            });
+            on_cleanup(move || {
+                hover_handle.remove();
+                click_handle.remove();
+            });
+        }
+
+        move || {
+            let (key, locale) = hovered.get().filter(|_| is_enabled())?;
+            let pin = if pinned.get() { " (pinned)" } else { "" };
+            Some(view! {
+                <div style="position: fixed; bottom: 8px; right: 8px; z-index: 2147483647; padding: 4px 8px; border-radius: 4px; background: #222; color: #fff; font: 12px monospace; pointer-events: none;">
+                    {key} " [" {locale} "]" {pin}
+                </div>
+            })
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InspectorContext, encode_tag, find_tags, is_enabled, strip_tags};
+    use leptos::prelude::*;
+
+    #[test]
+    fn tags_roundtrip() {
+        let tagged = format!(
+            "Hello{} and bye{}",
+            encode_tag("hello_world", "en"),
+            encode_tag("common::form.email", "fr-FR")
+        );
+        assert_eq!(tagged.chars().filter(char::is_ascii).count(), 13);
+        assert_eq!(
+            find_tags(&tagged),
+            [
+                ("hello_world".to_owned(), "en".to_owned()),
+                ("common::form.email".to_owned(), "fr-FR".to_owned())
+            ]
+        );
+        assert_eq!(strip_tags(&tagged), "Hello and bye");
+        assert!(find_tags("no tags").is_empty());
+    }
+
+    #[test]
+    fn enabled_per_context() {
+        let enabled = Owner::new();
+        enabled.with(|| {
+            provide_context(InspectorContext(RwSignal::new(true)));
+            assert_eq!(is_enabled(), cfg!(debug_assertions));
+        });
+        let disabled = Owner::new();
+        disabled.with(|| {
+            assert!(!is_enabled());
+            provide_context(InspectorContext(RwSignal::new(false)));
+            assert!(!is_enabled());
+        });
+    }
+}
@@ -117,26 +117,49 @@
 //! ```
 
 pub mod context;
+pub mod detectors;
 pub mod display;
+#[cfg(feature = "display_mode")]
+pub mod display_mode;
+#[cfg(feature = "track_fallbacks")]
+pub mod fallbacks;
 mod fetch_locale;
 mod fetch_translations;
+#[cfg(all(feature = "hot_reload", not(feature = "dynamic_load")))]
+pub mod hot_reload;
+pub mod html;
+#[cfg(feature = "key_inspector")]
+pub mod inspector;
 mod langid;
+#[cfg(feature = "dynamic_load")]
+pub mod loading;
 pub mod locale;
 mod locale_traits;
 mod macro_helpers;
 mod macros;
+#[cfg(feature = "translation_overrides")]
+pub mod overrides;
+pub mod preferences;
 mod scopes;
+pub mod server_error;
+pub mod storage;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(all(feature = "translations_cache", not(feature = "ssr")))]
+mod translations_cache;
 
 pub use macro_helpers::formatting;
 
-pub use locale_traits::{Direction, Locale, LocaleKeys};
+#[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+pub use locale_traits::TranslateDisplay;
+pub use locale_traits::{Direction, KeyHandle, Locale, LocaleKeys, Translate};
 
 pub use context::{I18nContext, use_i18n_context, use_i18n_with_scope};
 
 #[allow(deprecated)]
 pub use context::provide_i18n_context;
 
-pub use leptos_i18n_macro::IcuDataProvider;
+pub use leptos_i18n_macro::{IcuDataProvider, Translate};
 pub use scopes::{Scope, ScopedLocale};
 
 /// This module contain utilities for plurals.
@@ -150,13 +173,33 @@ pub mod plurals {
 #[doc(hidden)]
 pub mod __private {
     pub use crate::locale_traits::TranslationUnitId;
+    #[cfg(feature = "display_mode")]
+    pub mod display_mode {
+        pub use crate::display_mode::{fmt_message, key, message, text, view, view_fut};
+    }
+    #[cfg(feature = "track_fallbacks")]
+    pub mod fallbacks {
+        pub use crate::fallbacks::report;
+    }
     pub mod fetch_translations {
         pub use crate::fetch_translations::*;
     }
+    #[cfg(feature = "key_inspector")]
+    pub mod inspector {
+        pub use crate::inspector::{TagString, mark_view, tag_display, tag_string};
+    }
     #[cfg(feature = "plurals")]
     pub use crate::formatting::get_plural_rules;
     pub use crate::macro_helpers::*;
     pub use leptos_i18n_macro as macros_reexport;
+    #[cfg(feature = "translation_overrides")]
+    pub mod overrides {
+        pub use crate::overrides::{any_view, lookup, lookup_template};
+    }
+    #[cfg(all(feature = "hot_reload", not(feature = "dynamic_load")))]
+    pub mod hot_reload {
+        pub use crate::hot_reload::{HotReloadConfig, HotReloadUnit, strings};
+    }
 
     /// Helper trait to make some bounds for dummy code
     pub trait AnyBound {}
@@ -0,0 +1,277 @@
+//! Loading of the translations with the "dynamic_load" feature.
+//!
+//! On the client, translations are requested from the server the first time they are needed.
+//! A failed request is retried a few times with an exponential backoff, then the translations are replaced
+//! by a fallback (see `LoadFallback`) and the error is exposed with `I18nContext::translations_error` of the context the translations were requested under.
+//! The failed translations are requested again the next time they are accessed.
+//!
+//! The options are given to the context, with the `loading_options` prop of `I18nContextProvider`
+//! or `I18nContextOptions::loading_options`, and are shared with its subcontexts:
+//!
+//! ```rust, ignore
+//! use leptos_i18n::loading::{LoadFallback, LoadingOptions};
+//! use std::time::Duration;
+//!
+//! let loading_options = LoadingOptions::new()
+//!     .retries(5)
+//!     .backoff(Duration::from_millis(200))
+//!     .fallback(LoadFallback::Keys);
+//!
+//! view! {
+//!     <I18nContextProvider loading_options>
+//!         /* */
+//!     </I18nContextProvider>
+//! }
+//! ```
+//!
+//! The error can be shown in a banner, or given to an `ErrorBoundary` with `I18nContext::translations_result`:
+//!
+//! ```rust, ignore
+//! view! {
+//!     <ErrorBoundary fallback=|_| view! { <p>"Failed to load the translations"</p> }>
+//!         {move || i18n.translations_result()}
+//!         <Suspense>
+//!             <p>{t!(i18n, hello_world)}</p>
+//!         </Suspense>
+//!     </ErrorBoundary>
+//! }
+//! ```
+
+use std::{fmt::Display, time::Duration};
+
+use leptos::prelude::*;
+
+/// What to display when the translations failed to load.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadFallback {
+    /// Switch the context to the default locale, without saving it.
+    /// Until then, the keys are displayed like with `LoadFallback::Keys`.
+    #[default]
+    DefaultLocale,
+    /// Display the keys instead of the translations.
+    Keys,
+}
+
+/// Options for the loading of the translations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadingOptions {
+    pub(crate) retries: u32,
+    pub(crate) backoff: Duration,
+    pub(crate) fallback: LoadFallback,
+}
+
+impl Default for LoadingOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LoadingOptions {
+    /// Create the default options: 3 retries, starting with a 500ms backoff, falling back to the default locale.
+    pub const fn new() -> Self {
+        LoadingOptions {
+            retries: 3,
+            backoff: Duration::from_millis(500),
+            fallback: LoadFallback::DefaultLocale,
+        }
+    }
+
+    /// Number of retries after the first request failed.
+    pub const fn retries(self, retries: u32) -> Self {
+        LoadingOptions { retries, ..self }
+    }
+
+    /// Delay before the first retry, doubled after each retry.
+    pub const fn backoff(self, backoff: Duration) -> Self {
+        LoadingOptions { backoff, ..self }
+    }
+
+    /// What to display once every retry failed.
+    pub const fn fallback(self, fallback: LoadFallback) -> Self {
+        LoadingOptions { fallback, ..self }
+    }
+}
+
+/// Return the options provided by the closest context, or the default ones.
+#[cfg(not(feature = "ssr"))]
+pub(crate) fn use_loading_options() -> LoadingOptions {
+    use_context().unwrap_or_default()
+}
+
+/// Error returned when some translations could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationsLoadError {
+    locale: &'static str,
+    namespace: Option<&'static str>,
+    attempts: u32,
+    message: String,
+}
+
+impl TranslationsLoadError {
+    /// The locale of the translations.
+    pub fn locale(&self) -> &'static str {
+        self.locale
+    }
+
+    /// The namespace of the translations, if any.
+    pub fn namespace(&self) -> Option<&'static str> {
+        self.namespace
+    }
+
+    /// The number of requests made before giving up.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// The error of the last request.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for TranslationsLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to load the translations for locale {:?}",
+            self.locale
+        )?;
+        if let Some(namespace) = self.namespace {
+            write!(f, " and namespace {namespace:?}")?;
+        }
+        write!(f, " after {} attempts: {}", self.attempts, self.message)
+    }
+}
+
+impl std::error::Error for TranslationsLoadError {}
+
+/// Last error that happened while loading translations, one per `I18nContext`.
+// only read by the translations requests, on the client, and by the unified contexts.
+#[cfg_attr(
+    all(feature = "ssr", not(feature = "unified_contexts")),
+    allow(dead_code)
+)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TranslationsErrorContext(pub RwSignal<Option<TranslationsLoadError>>);
+
+/// Return the error signal of the closest context, if any.
+#[cfg(not(feature = "ssr"))]
+pub(crate) fn use_translations_error() -> Option<RwSignal<Option<TranslationsLoadError>>> {
+    use_context::<TranslationsErrorContext>().map(|TranslationsErrorContext(error)| error)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn sleep(duration: Duration) -> impl std::future::Future<Output = ()> + Send + Sync + 'static {
+    let (tx, rx) = futures::channel::oneshot::channel::<()>();
+    set_timeout(
+        move || {
+            let _ = tx.send(());
+        },
+        duration,
+    );
+    async move {
+        let _ = rx.await;
+    }
+}
+
+/// Request the translations, retrying on failure.
+#[cfg(not(feature = "ssr"))]
+pub(crate) async fn request_with_retries<L: crate::Locale, T, E: Display, F, Fut>(
+    options: LoadingOptions,
+    error: Option<RwSignal<Option<TranslationsLoadError>>>,
+    locale: L,
+    namespace: Option<&'static str>,
+    mut request: F,
+) -> Result<T, ()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+{
+    let mut backoff = options.backoff;
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let message = match request().await {
+            Ok(value) => {
+                if let Some(error) = error {
+                    let is_same_translations = error.with_untracked(|err| {
+                        err.as_ref().is_some_and(|err| {
+                            err.locale == locale.as_str() && err.namespace == namespace
+                        })
+                    });
+                    if is_same_translations {
+                        error.set(None);
+                    }
+                }
+                return Ok(value);
+            }
+            Err(err) => err.to_string(),
+        };
+        if attempts > options.retries {
+            let err = TranslationsLoadError {
+                locale: locale.as_str(),
+                namespace,
+                attempts,
+                message,
+            };
+            match error {
+                Some(error) => error.set(Some(err)),
+                None => leptos::logging::warn!("{err}"),
+            }
+            return Err(());
+        }
+        sleep(backoff).await;
+        backoff = backoff.saturating_mul(2);
+    }
+}
+
+#[cfg(all(test, not(feature = "ssr")))]
+mod tests {
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        default: "en",
+        locales: ["en", "fr"],
+        en: {},
+        fr: {},
+    }
+
+    use super::*;
+    use i18n::Locale;
+
+    fn request(
+        options: LoadingOptions,
+        error: RwSignal<Option<TranslationsLoadError>>,
+        result: Result<(), &'static str>,
+    ) -> Result<(), ()> {
+        futures::executor::block_on(request_with_retries(
+            options,
+            Some(error),
+            Locale::fr,
+            None,
+            || async { result },
+        ))
+    }
+
+    #[test]
+    fn errors_per_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let options = LoadingOptions::new().retries(0);
+            let first = RwSignal::new(None);
+            let second = RwSignal::new(None);
+
+            assert_eq!(request(options, first, Err("offline")), Err(()));
+            let err = first.get_untracked().unwrap();
+            assert_eq!(err.locale(), "fr");
+            assert_eq!(err.attempts(), 1);
+            assert_eq!(err.message(), "offline");
+            assert_eq!(second.get_untracked(), None);
+
+            // a successful request only clears the error of its own context.
+            second.set(Some(err.clone()));
+            assert_eq!(request(options, first, Ok(())), Ok(()));
+            assert_eq!(first.get_untracked(), None);
+            assert_eq!(second.get_untracked(), Some(err));
+        });
+    }
+}
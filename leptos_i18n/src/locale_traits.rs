@@ -38,7 +38,10 @@ pub trait Locale<L: Locale = Self>:
     type Keys: LocaleKeys<Locale = L>;
 
     /// Associated `#[server]` function type to request the translations
-    #[cfg(all(feature = "dynamic_load", not(feature = "csr")))]
+    #[cfg(all(
+        feature = "dynamic_load",
+        not(any(feature = "csr", feature = "static_translations"))
+    ))]
     type ServerFn: leptos::server_fn::ServerFn;
 
     /// Enum where each variants is an ID of a translation unit
@@ -116,6 +119,17 @@ pub trait Locale<L: Locale = Self>:
     /// Init the translation unit of the given ID with the given values
     #[cfg(all(feature = "dynamic_load", feature = "hydrate"))]
     fn init_translations(self, translations_id: Self::TranslationUnitId, values: Vec<Box<str>>);
+
+    /// Return every key that can be overridden, with the variables and components the overrides of interpolated keys can use.
+    ///
+    /// This is used to validate the translation overrides.
+    #[cfg(feature = "translation_overrides")]
+    fn translation_keys() -> &'static [crate::overrides::OverridableKey];
+
+    /// Return the path of every key displayed with the value of another locale for this locale,
+    /// either missing from its files, explicitly defaulted or inherited.
+    #[cfg(feature = "track_fallbacks")]
+    fn defaulted_keys(self) -> &'static [&'static str];
 }
 
 /// Trait implemented the struct representing the translation keys
@@ -127,6 +141,176 @@ pub trait LocaleKeys: 'static + Clone + Copy + Send + Sync {
 
     /// Return a static ref to Self containing the translations for the given locale
     fn from_locale(locale: Self::Locale) -> Self;
+
+    /// Load the translations of these keys for the given locale.
+    ///
+    /// Only the client with the "dynamic_load" feature has something to load, otherwise this resolves immediately.
+    fn preload(
+        locale: Self::Locale,
+    ) -> impl std::future::Future<Output = ()> + Send + Sync + 'static {
+        let _ = locale;
+        async {}
+    }
+
+    /// Return `true` if the translations of these keys are loaded for the given locale.
+    fn is_loaded(locale: Self::Locale) -> bool {
+        let _ = locale;
+        true
+    }
+
+    /// Load the translations of these keys for `locale`, only for the translation units already loaded for `loaded_locale`.
+    ///
+    /// This is used to switch locale without requesting translations that are not used.
+    fn preload_matching(
+        locale: Self::Locale,
+        loaded_locale: Self::Locale,
+    ) -> impl std::future::Future<Output = ()> + Send + Sync + 'static {
+        let is_loaded = Self::is_loaded(loaded_locale);
+        async move {
+            if is_loaded {
+                Self::preload(locale).await
+            }
+        }
+    }
+}
+
+/// Trait implemented by the zero-sized handles generated for each plain string key, found in the `i18n::keys` module.
+///
+/// Handles can be stored and passed around as regular values, for example as a component prop,
+/// and translated later with `t_key!` or `KeyHandle::translate`:
+///
+/// ```rust, ignore
+/// #[component]
+/// fn FormField<K: KeyHandle<Locale = Locale>>(label: K) -> impl IntoView {
+///     let i18n = use_i18n();
+///     view! { <label>{t_key!(i18n, label)}</label> }
+/// }
+///
+/// view! { <FormField label=keys::form::email /> }
+/// ```
+pub trait KeyHandle: 'static + Clone + Copy + Send + Sync {
+    /// The associated enum representing the supported locales
+    type Locale: Locale;
+
+    /// The full path of the key, such as `"form.email"`.
+    const PATH: &'static str;
+
+    /// Return the translation of the key for the given locale.
+    #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+    fn translate(self, locale: Self::Locale) -> &'static str;
+
+    /// Return the translation of the key for the given locale.
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    fn translate(self, locale: Self::Locale) -> impl Future<Output = &'static str>;
+}
+
+/// Trait mapping the values of a type to translation keys, usually implemented with `#[derive(Translate)]`.
+///
+/// The key of each variant can be given with `#[i18n(key = "...")]`, and defaults to the variant name in snake case,
+/// under the prefix given with `#[i18n(prefix = "...")]` on the type.
+/// Keys are checked at compile time against the generated `i18n::keys` module.
+///
+#[cfg_attr(feature = "dynamic_load", doc = "```rust, ignore")]
+#[cfg_attr(not(feature = "dynamic_load"), doc = "```rust")]
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       default: "en",
+/// #       locales: ["en", "fr"],
+/// #       en: {
+/// #           status: {
+/// #               pending: "Pending",
+/// #               in_review: "In review",
+/// #           },
+/// #       },
+/// #       fr: {
+/// #           status: {
+/// #               pending: "En attente",
+/// #               in_review: "En relecture",
+/// #           },
+/// #       },
+/// #   };
+/// # use i18n::*;
+/// use leptos_i18n::Translate;
+///
+/// #[derive(Translate)]
+/// #[i18n(path = i18n, prefix = "status")]
+/// enum Status {
+///     Pending, // -> "status.pending"
+///     #[i18n(key = "status.in_review")]
+///     Review { reviewer: String },
+/// }
+///
+/// assert_eq!(Status::Pending.translate(Locale::fr), "En attente");
+///
+/// let status = Status::Review { reviewer: "John".into() };
+/// assert_eq!(status.key_path(), "status.in_review");
+/// assert_eq!(status.translate(Locale::en), "In review");
+/// assert_eq!(format!("Status: {}", Status::Pending.display(Locale::en)), "Status: Pending");
+/// ```
+///
+/// The derive also implements `IntoView`, so the values can be used directly in `view!`,
+/// where they are translated in the locale of the `I18nContext`: `view! { <p>{Status::Pending}</p> }`.
+///
+/// Only keys without interpolations can be used, as no arguments are passed to the translation.
+pub trait Translate {
+    /// The associated enum representing the supported locales
+    type Locale: Locale;
+
+    /// Return the full path of the key used for this value.
+    fn key_path(&self) -> &'static str;
+
+    /// Return the translation of this value for the given locale.
+    #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+    fn translate(&self, locale: Self::Locale) -> &'static str;
+
+    /// Return the translation of this value for the given locale.
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    fn translate(&self, locale: Self::Locale) -> impl Future<Output = &'static str> + use<Self>;
+
+    /// Return a value displaying the translation of this value for the given locale.
+    #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+    fn display(&self, locale: Self::Locale) -> TranslateDisplay<'_, Self> {
+        TranslateDisplay {
+            value: self,
+            locale,
+        }
+    }
+
+    /// Return the translation of this value for the given locale once loaded, to be displayed.
+    #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+    fn display(&self, locale: Self::Locale) -> impl Future<Output = &'static str> + use<Self> {
+        self.translate(locale)
+    }
+
+    /// Return a view of the translation following the locale of the `I18nContext`.
+    fn into_i18n_view(self) -> impl leptos::IntoView
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        let i18n = crate::use_i18n_context::<Self::Locale>();
+        #[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+        {
+            move || self.translate(i18n.get_locale())
+        }
+        #[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+        {
+            crate::__private::future_renderer(move || self.translate(i18n.get_locale()))
+        }
+    }
+}
+
+/// Display the translation of a `Translate` value for a locale, returned by `Translate::display`.
+#[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+pub struct TranslateDisplay<'a, T: Translate + ?Sized> {
+    value: &'a T,
+    locale: T::Locale,
+}
+
+#[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+impl<T: Translate + ?Sized> Display for TranslateDisplay<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value.translate(self.locale))
+    }
 }
 
 /// Trait for the type giving an ID to each section of the translations
@@ -246,10 +430,43 @@ mod test {
     #[test]
     #[cfg(not(feature = "dynamic_load"))]
     fn test_scope() {
-        use crate::{self as leptos_i18n, __private::LitWrapper, scope_locale};
+        use crate::{self as leptos_i18n, scope_locale};
         let en_sk = scope_locale!(Locale::en, sk);
-        assert_eq!(en_sk.get_keys().ssk(), LitWrapper::new("test en"));
+        assert_eq!(en_sk.get_keys().ssk().build_string(), "test en");
         let fr_sk = en_sk.map_locale(Locale::fr);
-        assert_eq!(fr_sk.get_keys().ssk(), LitWrapper::new("test fr"));
+        assert_eq!(fr_sk.get_keys().ssk().build_string(), "test fr");
+    }
+
+    #[test]
+    #[cfg(not(feature = "dynamic_load"))]
+    fn test_derive_translate() {
+        use crate::{self as leptos_i18n, Translate};
+
+        #[derive(Translate)]
+        #[i18n(path = i18n, prefix = "sk")]
+        enum Sk {
+            Ssk,
+            #[i18n(key = "sk.ssk")]
+            Other(#[allow(unused)] u8),
+        }
+
+        assert_eq!(Sk::Ssk.key_path(), "sk.ssk");
+        assert_eq!(Sk::Ssk.translate(Locale::en), "test en");
+        assert_eq!(Sk::Other(0).translate(Locale::fr), "test fr");
+        assert_eq!(Sk::Ssk.display(Locale::fr).to_string(), "test fr");
+        assert_eq!(format!("{}", Sk::Other(0).display(Locale::en)), "test en");
+    }
+
+    #[test]
+    #[cfg(all(feature = "testing", not(feature = "dynamic_load")))]
+    fn test_derive_translate_view() {
+        use crate::{self as leptos_i18n, Translate, testing::render_in_locale};
+
+        #[derive(Translate)]
+        #[i18n(path = i18n, key = "sk.ssk")]
+        struct Sk;
+
+        assert_eq!(render_in_locale(Locale::en, || Sk).html, "test en");
+        assert_eq!(render_in_locale(Locale::fr, || Sk).html, "test fr");
     }
 }
@@ -32,6 +32,27 @@ impl From<CurrencyWidth> for Width {
     }
 }
 
+/// Return the currency of the user preferences for `currency_code: $user`,
+/// `fallback`, the code written in the translation, if there is none or it is invalid.
+#[doc(hidden)]
+pub fn user_currency_code(fallback: CurrencyCode) -> CurrencyCode {
+    let Some(code) = crate::preferences::current().and_then(|preferences| preferences.currency)
+    else {
+        return fallback;
+    };
+    match tinystr::TinyAsciiStr::try_from_str(&code) {
+        Ok(code) if crate::preferences::is_currency_code(&code) => CurrencyCode(code),
+        _ => {
+            leptos::logging::warn!(
+                "{}, using {:?} instead",
+                crate::preferences::InvalidCurrencyCode(code.into_owned()),
+                fallback.0.as_str()
+            );
+            fallback
+        }
+    }
+}
+
 #[doc(hidden)]
 pub fn format_currency_to_view<L: Locale>(
     locale: L,
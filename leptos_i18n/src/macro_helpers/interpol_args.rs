@@ -14,6 +14,8 @@ pub type Attributes = Vec<leptos::attr::any_attribute::AnyAttribute>;
 pub struct WithoutAttributes<O>(PhantomData<O>);
 /// Marker for closure that take attributes as argument
 pub struct WithAttributes<O>(PhantomData<O>);
+/// Marker for closure that take the typed attributes struct of the component as argument
+pub struct WithTypedAttributes<O>(PhantomData<O>);
 
 /// Marker trait for differenciating closure based on their arguments
 pub trait AttributesArgMarker: 'static {
@@ -29,58 +31,111 @@ impl<O: IntoView + 'static> AttributesArgMarker for WithoutAttributes<O> {
     type IntoView = O;
 }
 
+impl<O: IntoView + 'static> AttributesArgMarker for WithTypedAttributes<O> {
+    type IntoView = O;
+}
+
 impl<O: IntoView + 'static> AttributesArgMarker for O {
     type IntoView = O;
 }
 
 /// Trait for a type that can be used as an interpolation component.
-pub trait InterpolateComp<O: AttributesArgMarker>: Clone + 'static + Send + Sync {
+///
+/// `A` is the struct generated for the attributes the translations give to the component.
+pub trait InterpolateComp<O: AttributesArgMarker, A>: Clone + 'static + Send + Sync {
     /// Create a view from self
-    fn to_view(&self, children: leptos::children::ChildrenFn, attrs: &Attributes) -> O::IntoView;
+    fn to_view(
+        &self,
+        children: leptos::children::ChildrenFn,
+        attrs: &Attributes,
+        typed_attrs: &A,
+    ) -> O::IntoView;
 }
 
 impl<
     O: IntoView + 'static,
+    A,
     T: Fn(leptos::children::ChildrenFn) -> O + Clone + 'static + Send + Sync,
-> InterpolateComp<WithoutAttributes<O>> for T
+> InterpolateComp<WithoutAttributes<O>, A> for T
 {
-    fn to_view(&self, children: leptos::children::ChildrenFn, _attrs: &Attributes) -> O {
+    fn to_view(&self, children: leptos::children::ChildrenFn, _attrs: &Attributes, _: &A) -> O {
         self(children)
     }
 }
 
 impl<
     O: IntoView + 'static,
+    A,
     T: Fn(leptos::children::ChildrenFn, Attributes) -> O + Clone + 'static + Send + Sync,
-> InterpolateComp<WithAttributes<O>> for T
+> InterpolateComp<WithAttributes<O>, A> for T
 {
-    fn to_view(&self, children: leptos::children::ChildrenFn, attrs: &Attributes) -> O {
+    fn to_view(&self, children: leptos::children::ChildrenFn, attrs: &Attributes, _: &A) -> O {
         self(children, attrs.clone())
     }
 }
 
+impl<
+    O: IntoView + 'static,
+    A: Clone,
+    F: Fn(A, leptos::children::ChildrenFn) -> O + Clone + 'static + Send + Sync,
+> InterpolateComp<WithTypedAttributes<O>, A> for TypedComp<F>
+{
+    fn to_view(&self, children: leptos::children::ChildrenFn, _: &Attributes, attrs: &A) -> O {
+        (self.0)(attrs.clone(), children)
+    }
+}
+
 /// Marker trait for a type that can be used as an interpolation self-closed component.
-pub trait InterpolateCompSelfClosed<O: AttributesArgMarker>: Clone + 'static + Send + Sync {
+pub trait InterpolateCompSelfClosed<O: AttributesArgMarker, A>:
+    Clone + 'static + Send + Sync
+{
     /// Create a view from self
-    fn to_view(&self, attrs: &Attributes) -> O::IntoView;
+    fn to_view(&self, attrs: &Attributes, typed_attrs: &A) -> O::IntoView;
 }
 
-impl<O: IntoView + 'static, T: Fn() -> O + Clone + 'static + Send + Sync>
-    InterpolateCompSelfClosed<WithoutAttributes<O>> for T
+impl<O: IntoView + 'static, A, T: Fn() -> O + Clone + 'static + Send + Sync>
+    InterpolateCompSelfClosed<WithoutAttributes<O>, A> for T
 {
-    fn to_view(&self, _attrs: &Attributes) -> O {
+    fn to_view(&self, _attrs: &Attributes, _: &A) -> O {
         self()
     }
 }
 
-impl<O: IntoView + 'static, T: Fn(Attributes) -> O + Clone + 'static + Send + Sync>
-    InterpolateCompSelfClosed<WithAttributes<O>> for T
+impl<O: IntoView + 'static, A, T: Fn(Attributes) -> O + Clone + 'static + Send + Sync>
+    InterpolateCompSelfClosed<WithAttributes<O>, A> for T
 {
-    fn to_view(&self, attrs: &Attributes) -> O {
+    fn to_view(&self, attrs: &Attributes, _: &A) -> O {
         self(attrs.clone())
     }
 }
 
+impl<
+    O: IntoView + 'static,
+    A: Clone,
+    F: Fn(A, leptos::children::ChildrenFn) -> O + Clone + 'static + Send + Sync,
+> InterpolateCompSelfClosed<WithTypedAttributes<O>, A> for TypedComp<F>
+{
+    fn to_view(&self, _: &Attributes, attrs: &A) -> O {
+        let no_children: leptos::children::ChildrenFn =
+            std::sync::Arc::new(|| leptos::prelude::IntoAny::into_any(()));
+        (self.0)(attrs.clone(), no_children)
+    }
+}
+
+/// A component taking the typed attributes struct of the component and its children.
+///
+/// Created by the `t!` macro for closures of the form `|attrs, children| ...`,
+/// self-closed components are given empty children.
+#[derive(Clone)]
+pub struct TypedComp<F>(F);
+
+impl<F> TypedComp<F> {
+    /// Wrap the closure
+    pub fn new(f: F) -> Self {
+        TypedComp(f)
+    }
+}
+
 /// Marker trait for a type that can be used to produce a count for a range key.
 pub trait InterpolateRangeCount<T>: Fn() -> T + Clone + 'static + Send + Sync {}
 
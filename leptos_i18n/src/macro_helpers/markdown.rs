@@ -0,0 +1,71 @@
+use leptos::{
+    children::ChildrenFn,
+    html,
+    prelude::{AddAnyAttr, AnyView, ElementChild, IntoAny},
+};
+
+use super::{Attributes, InterpolateComp, InterpolateCompSelfClosed};
+
+/// Default component of the keys written in Markdown, rendered as the HTML element it is named after.
+///
+/// `t!` uses it for the Markdown components it is not given, so `<p>` is rendered as `<p>{children}</p>`,
+/// with the attributes of the Markdown such as the `href` of the links.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownComp(&'static str);
+
+impl MarkdownComp {
+    /// Create the component for the given tag.
+    pub const fn new(tag: &'static str) -> Self {
+        MarkdownComp(tag)
+    }
+
+    fn render(self, children: Option<ChildrenFn>, attrs: &Attributes) -> AnyView {
+        let attrs = attrs.clone();
+        macro_rules! render {
+            ($($tag:ident),*) => {
+                match self.0 {
+                    $(
+                        stringify!($tag) => html::$tag()
+                            .child(children.map(|children| move || children()))
+                            .add_any_attr(attrs)
+                            .into_any(),
+                    )*
+                    // void elements can't have children.
+                    "br" => html::br().add_any_attr(attrs).into_any(),
+                    "hr" => html::hr().add_any_attr(attrs).into_any(),
+                    "img" => html::img().add_any_attr(attrs).into_any(),
+                    tag => html::custom(tag)
+                        .child(children.map(|children| move || children()))
+                        .add_any_attr(attrs)
+                        .into_any(),
+                }
+            };
+        }
+        render!(
+            p, h1, h2, h3, h4, h5, h6, blockquote, pre, ul, ol, li, em, strong, code, a
+        )
+    }
+}
+
+impl<A> InterpolateComp<AnyView, A> for MarkdownComp {
+    fn to_view(&self, children: ChildrenFn, attrs: &Attributes, _: &A) -> AnyView {
+        self.render(Some(children), attrs)
+    }
+}
+
+impl<A> InterpolateCompSelfClosed<AnyView, A> for MarkdownComp {
+    fn to_view(&self, attrs: &Attributes, _: &A) -> AnyView {
+        self.render(None, attrs)
+    }
+}
+
+/// Set the Markdown component of the `I`th field of a builder to a `MarkdownComp` if it is not already set.
+///
+/// Implemented by the builders of the keys written in Markdown,
+/// their `fill_markdown` method is called by `t!` before building the value.
+#[doc(hidden)]
+pub trait FillMarkdownComp<const I: usize> {
+    type Output;
+
+    fn fill(self) -> Self::Output;
+}
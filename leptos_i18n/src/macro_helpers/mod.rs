@@ -1,14 +1,18 @@
 use core::fmt;
-use std::{fmt::Display, future::Future, marker::PhantomData};
+use std::{fmt::Display, future::Future, marker::PhantomData, pin::Pin};
 
 pub mod formatting;
 mod interpol_args;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod scope;
 
 use crate::Locale;
 pub use formatting::*;
 pub use interpol_args::*;
 use leptos::IntoView;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
 pub use scope::*;
 
 #[doc(hidden)]
@@ -82,6 +86,15 @@ impl<T: Literal> LitWrapper<T> {
         self
     }
 
+    pub const fn html_builder(self) -> Self {
+        self
+    }
+
+    #[cfg(feature = "markdown")]
+    pub const fn fill_markdown(self) -> Self {
+        self
+    }
+
     pub const fn build(self) -> Self {
         self
     }
@@ -103,6 +116,70 @@ impl<T: Literal> LitWrapper<T> {
     }
 }
 
+/// Value of a literal key that can change at runtime,
+/// with the "translation_overrides", "hot_reload", "track_fallbacks" or "display_mode" features.
+///
+/// The accessors returning it stay `const`: `inner` gives the value from the locale files at compile time,
+/// the other methods make the runtime lookups when the value is used.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct LitResolver<T, L> {
+    lit: T,
+    locale: L,
+    resolve: fn(L) -> T,
+}
+
+impl<T: Literal, L: Copy> LitResolver<T, L> {
+    pub const fn new(lit: T, locale: L, resolve: fn(L) -> T) -> Self {
+        LitResolver {
+            lit,
+            locale,
+            resolve,
+        }
+    }
+
+    pub const fn builder(self) -> Self {
+        self
+    }
+
+    pub const fn display_builder(self) -> Self {
+        self
+    }
+
+    pub const fn html_builder(self) -> Self {
+        self
+    }
+
+    #[cfg(feature = "markdown")]
+    pub const fn fill_markdown(self) -> Self {
+        self
+    }
+
+    pub const fn build(self) -> Self {
+        self
+    }
+
+    fn resolve(self) -> LitWrapper<T> {
+        LitWrapper::new((self.resolve)(self.locale))
+    }
+
+    pub fn into_view(self) -> impl IntoView + Copy {
+        self.resolve().into_view()
+    }
+
+    pub fn build_string(self) -> T::AsStr {
+        self.resolve().build_string()
+    }
+
+    pub fn build_display(self) -> impl Display {
+        self.resolve().build_display()
+    }
+
+    pub const fn inner(self) -> T {
+        self.lit
+    }
+}
+
 #[doc(hidden)]
 #[repr(transparent)]
 pub struct LitWrapperFut<T>(T);
@@ -120,6 +197,15 @@ impl<T: Literal, F: Future<Output = LitWrapper<T>>> LitWrapperFut<F> {
         self
     }
 
+    pub const fn html_builder(self) -> Self {
+        self
+    }
+
+    #[cfg(feature = "markdown")]
+    pub const fn fill_markdown(self) -> Self {
+        self
+    }
+
     pub const fn build(self) -> Self {
         self
     }
@@ -135,8 +221,22 @@ impl<T: Literal, F: Future<Output = LitWrapper<T>>> LitWrapperFut<F> {
     pub async fn build_display(self) -> impl Display {
         self.0.await.build_display()
     }
+
+    pub async fn inner(self) -> T {
+        self.0.await.inner()
+    }
+
+    pub fn boxed(self) -> LitWrapperFut<BoxedLitFut<T>>
+    where
+        F: 'static,
+    {
+        LitWrapperFut(Box::pin(self.0))
+    }
 }
 
+#[doc(hidden)]
+pub type BoxedLitFut<T> = Pin<Box<dyn Future<Output = LitWrapper<T>>>>;
+
 impl<T: Literal> LitWrapperFut<LitWrapper<T>> {
     pub const fn new_not_fut(v: T) -> Self {
         LitWrapperFut(LitWrapper::new(v))
@@ -150,6 +250,15 @@ impl<T: Literal> LitWrapperFut<LitWrapper<T>> {
         self
     }
 
+    pub const fn html_builder(self) -> Self {
+        self
+    }
+
+    #[cfg(feature = "markdown")]
+    pub const fn fill_markdown(self) -> Self {
+        self
+    }
+
     pub const fn build(self) -> Self {
         self
     }
@@ -165,6 +274,10 @@ impl<T: Literal> LitWrapperFut<LitWrapper<T>> {
     pub async fn build_display(self) -> impl Display {
         self.0.build_display()
     }
+
+    pub const fn inner(self) -> T {
+        self.0.inner()
+    }
 }
 
 #[doc(hidden)]
@@ -270,13 +383,32 @@ pub const fn index_translations<const N: usize, const I: usize>(
     translations[I]
 }
 
+/// The strings of a translation unit used by a key, with the path of the key shown if they failed to load.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+#[cfg(not(any(not(feature = "dynamic_load"), feature = "ssr")))]
+pub struct KeyTranslations<const N: usize> {
+    strings: Option<&'static [Box<str>; N]>,
+    key_path: &'static str,
+}
+
+#[cfg(not(any(not(feature = "dynamic_load"), feature = "ssr")))]
+impl<const N: usize> KeyTranslations<N> {
+    pub const fn new(strings: Option<&'static [Box<str>; N]>, key_path: &'static str) -> Self {
+        KeyTranslations { strings, key_path }
+    }
+}
+
 #[doc(hidden)]
 #[track_caller]
 #[cfg(not(any(not(feature = "dynamic_load"), feature = "ssr")))]
 pub fn index_translations<const N: usize, const I: usize>(
-    translations: &'static [Box<str>; N],
+    translations: KeyTranslations<N>,
 ) -> &'static str {
-    &translations[I]
+    match translations.strings {
+        Some(strings) => &strings[I],
+        None => translations.key_path,
+    }
 }
 
 #[doc(hidden)]
@@ -289,6 +421,28 @@ pub fn future_renderer<IV: IntoView + 'static + Clone, F: Future<Output = IV> +
     move || fut.get()
 }
 
+/// View of a `#[derive(Translate)]` value, following the locale of the `I18nContext`.
+#[doc(hidden)]
+#[cfg(not(all(feature = "dynamic_load", not(feature = "ssr"))))]
+pub fn translate_view<L: Locale>(
+    translate: impl Fn(L) -> &'static str + Send + Sync + 'static,
+) -> leptos::prelude::AnyView {
+    use leptos::prelude::IntoAny;
+    let i18n = crate::use_i18n_context::<L>();
+    (move || translate(i18n.get_locale())).into_any()
+}
+
+/// View of a `#[derive(Translate)]` value, following the locale of the `I18nContext`.
+#[doc(hidden)]
+#[cfg(all(feature = "dynamic_load", not(feature = "ssr")))]
+pub fn translate_view<L: Locale, F: Future<Output = &'static str> + 'static>(
+    translate: impl Fn(L) -> F + 'static,
+) -> leptos::prelude::AnyView {
+    use leptos::prelude::IntoAny;
+    let i18n = crate::use_i18n_context::<L>();
+    future_renderer(move || translate(i18n.get_locale())).into_any()
+}
+
 #[doc(hidden)]
 #[cfg(feature = "plurals")]
 pub fn get_plural_category_for<L, F>(
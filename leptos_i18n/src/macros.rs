@@ -94,6 +94,58 @@ macro_rules! declare_locales {
 /// t!(i18n, key, variable, <component>)
 /// # ;
 /// ```
+///
+/// # Typed attributes
+///
+/// A component given as a closure of the form `|attrs, children| ...` receives the attributes the translations give it as a struct:
+///
+/// ```rust, no_run
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       default: "en",
+/// #       locales: ["en", "fr"],
+/// #       en: {
+/// #           home: "<a href=\"/en\" title=\"Home\">home</a>",
+/// #       },
+/// #       fr: {
+/// #           home: "<a href=\"/fr\">accueil</a>",
+/// #       },
+/// #   };
+/// #
+/// # use i18n::*;
+/// # use leptos::prelude::*;
+/// # let i18n = use_i18n();
+/// # let _ =
+/// t!(i18n, home, <a> = |attrs, children| view! {
+///     <a href=attrs.href title=attrs.title>{children()}</a>
+/// })
+/// # ;
+/// ```
+///
+/// The translations must agree on the type of each attribute, a string in one locale and a variable in another fails the build:
+///
+/// ```rust, compile_fail
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       default: "en",
+/// #       locales: ["en", "fr"],
+/// #       en: {
+/// #           home: "<a href=\"/en\" title=\"Home\">home</a>",
+/// #       },
+/// #       fr: {
+/// #           home: "<a href={{ url }}>accueil</a>",
+/// #       },
+/// #   };
+/// #
+/// # use i18n::*;
+/// # use leptos::prelude::*;
+/// # let i18n = use_i18n();
+/// # let _ =
+/// t!(i18n, home, url = "/fr", <a> = |attrs, children| view! {
+///     <a title=attrs.title>{children()}</a>
+/// })
+/// # ;
+/// ```
 #[macro_export]
 macro_rules! t {
     ($($tt:tt)*) => {
@@ -310,6 +362,143 @@ macro_rules! tu_display {
     };
 }
 
+/// Just like the `t_display!` macro but the output is formatted as HTML, see the `html` module.
+///
+/// The text and the variables are escaped, and the components are rendered with the templates of the global `HtmlComponents` registry,
+/// or of the one given with `with_components`. The components can't be given as arguments.
+///
+/// Usage:
+///
+#[cfg_attr(feature = "dynamic_load", doc = "```rust, ignore")]
+#[cfg_attr(not(feature = "dynamic_load"), doc = "```rust")]
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       interpolate_display,
+/// #       default: "en",
+/// #       locales: ["en"],
+/// #       en: {
+/// #           welcome: "Welcome <b>{{ name }}</b>, read the <link>rules</link>",
+/// #       },
+/// #   };
+/// # use i18n::*;
+/// use leptos_i18n::html::{HtmlComponents, HtmlTemplate};
+///
+/// // welcome = "Welcome <b>{{ name }}</b>, read the <link>rules</link>"
+/// let components = HtmlComponents::default()
+///     .add("link", HtmlTemplate::tag("a").attr("href", "/rules"));
+/// // `t_html!(i18n, welcome, name = "<Bob>")` with the context.
+/// let t = td_html!(Locale::en, welcome, name = "<Bob>").with_components(components);
+///
+/// assert_eq!(t.to_string(), "Welcome <b>&lt;Bob&gt;</b>, read the <a href=\"/rules\">rules</a>");
+/// ```
+#[macro_export]
+macro_rules! t_html {
+    ($($tt:tt)*) => {
+        $crate::__private::macros_reexport::t_html!{$($tt)*}
+    };
+}
+
+/// Just like the `t_html!` macro but takes the `Locale` as an argument instead of the context.
+#[macro_export]
+macro_rules! td_html {
+    ($($tt:tt)*) => {
+        $crate::__private::macros_reexport::td_html!{$($tt)*}
+    };
+}
+
+/// Same as the `t_html!` macro but untracked.
+#[macro_export]
+macro_rules! tu_html {
+    ($($tt:tt)*) => {
+        $crate::__private::macros_reexport::tu_html!{$($tt)*}
+    };
+}
+
+/// Return an iterator over the `(key, value)` pairs of the plain string entries under the given subkeys, for the given locale.
+///
+/// Keys are yielded in alphabetical order, interpolated values and nested subkeys are skipped.
+/// New keys added to the translations show up without touching the code.
+///
+/// Usage:
+///
+#[cfg_attr(feature = "dynamic_load", doc = "```rust, ignore")]
+#[cfg_attr(not(feature = "dynamic_load"), doc = "```rust")]
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       default: "en",
+/// #       locales: ["en", "fr"],
+/// #       en: {
+/// #           settings: {
+/// #               theme: "Theme",
+/// #               language: "Language",
+/// #           },
+/// #       },
+/// #       fr: {
+/// #           settings: {
+/// #               theme: "Thème",
+/// #               language: "Langue",
+/// #           },
+/// #       },
+/// #   };
+/// # use i18n::*;
+/// let entries: Vec<_> = td_entries!(Locale::fr, settings).collect();
+///
+/// assert_eq!(entries, [("language", "Langue"), ("theme", "Thème")]);
+/// ```
+#[macro_export]
+macro_rules! td_entries {
+    ($($tt:tt)*) => {
+        $crate::__private::macros_reexport::td_entries!{$($tt)*}
+    };
+}
+
+/// Just like the `t!` macro but takes a key handle from the generated `keys` module instead of a key path.
+///
+/// Handles are plain values, so components can take "which label to show" as a typed parameter
+/// and still react to locale changes.
+///
+/// Usage:
+///
+#[cfg_attr(feature = "dynamic_load", doc = "```rust, ignore")]
+#[cfg_attr(not(feature = "dynamic_load"), doc = "```rust, no_run")]
+/// #   leptos_i18n::declare_locales! {
+/// #       path: leptos_i18n,
+/// #       default: "en",
+/// #       locales: ["en", "fr"],
+/// #       en: {
+/// #           form: {
+/// #               email: "Email",
+/// #           },
+/// #       },
+/// #       fr: {
+/// #           form: {
+/// #               email: "Courriel",
+/// #           },
+/// #       },
+/// #   };
+/// # use i18n::*;
+/// # use leptos::prelude::*;
+/// use leptos_i18n::KeyHandle;
+///
+/// #[component]
+/// fn FormField<K: KeyHandle<Locale = Locale>>(label: K) -> impl IntoView {
+///     let i18n = use_i18n();
+///     view! { <label>{t_key!(i18n, label)}</label> }
+/// }
+///
+/// # let _ =
+/// view! { <FormField label=keys::form::email /> }
+/// # ;
+///
+/// assert_eq!(keys::form::email.translate(Locale::fr), "Courriel");
+/// ```
+#[macro_export]
+macro_rules! t_key {
+    ($($tt:tt)*) => {
+        $crate::__private::macros_reexport::t_key!{$($tt)*}
+    };
+}
+
 /// Like `use_i18n` but enable to scope the context:
 ///
 /// Instead of
@@ -0,0 +1,808 @@
+//! Runtime overrides of the translations with the "translation_overrides" feature.
+//!
+//! Overrides replace the value of keys without rebuilding the application,
+//! for exemple with wording fetched from a CMS at startup:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! let overrides = i18n.overrides();
+//! overrides.load_json(r#"{ "en": { "hello_world": "Hi there!", "form": { "email": "Email address" } } }"#)?;
+//! overrides.set(Locale::fr, "greeting", "Salut <b>{{ name }}</b> !");
+//! ```
+//!
+//! Keys are given by their path, like `KeyHandle::PATH`: subkeys are separated by a `.` and namespaces are prefixed with `namespace::`.
+//! The value of an interpolated key can use the variables (`{{ name }}`) and components (`<b>...</b>` or `<br />`) of the key,
+//! they are rendered like in the translations, formatters included. Components given attributes by every translation can't be used,
+//! as the override can't give them, and keys using plurals or ranges can't be overridden.
+//!
+//! An override is checked against the keys generated for the application and rejected with a warning in the console if
+//! the key does not exist or if the value uses a variable or a component the key doesn't have.
+//! Rejected overrides are never displayed, the translation from the locale files is used instead.
+//!
+//! The overrides are stored in the `I18nContext` and shared with its subcontexts: on the server each request has its own,
+//! and the views using an overridden key are updated when they change. Keys rendered outside of the reactive tree of the context,
+//! for example by `td_string!` without an owner, are not overridden.
+//!
+//! The values of plain string keys are leaked to be given out as `&'static str` like the other translations.
+//! The allocation is reused whenever a value that was already pushed is pushed again, for any key, locale or context,
+//! so refreshing the overrides from their source only allocates for the wordings that changed,
+//! and the leaked values are capped to `MAX_LEAKED_BYTES`, the values pushed past it are rejected.
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    marker::PhantomData,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use leptos::{children::ChildrenFn, prelude::*};
+
+use crate::Locale;
+
+/// Maximum size, in bytes, of the values of plain string keys leaked by the overrides for the life of the process.
+pub const MAX_LEAKED_BYTES: usize = 4 * 1024 * 1024;
+
+/// A key that can be overridden, generated for each locale type and returned by `Locale::translation_keys`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverridableKey {
+    /// Path of the key.
+    pub path: &'static str,
+    /// `false` for plain string keys.
+    pub interpolated: bool,
+    /// Variables the overrides can use.
+    pub variables: &'static [&'static str],
+    /// Components with children the overrides can use.
+    pub components: &'static [&'static str],
+    /// Self-closed components the overrides can use.
+    pub self_closed_components: &'static [&'static str],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(Box<str>),
+    Var(Box<str>),
+    Comp {
+        name: Box<str>,
+        children: Option<Arc<[Segment]>>,
+    },
+}
+
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    SelfClosed(&'a str),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Parse the tag at the start of `value`, returning it with its length. `None` if it's not a tag, like in `1 < 2`.
+fn parse_tag(value: &str) -> Option<(Tag<'_>, usize)> {
+    let inner = value.strip_prefix('<')?;
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let len = inner.find(|c| !is_name_char(c)).unwrap_or(inner.len());
+    let name = &inner[..len];
+    let after = inner[len..].trim_start();
+    let consumed = value.len() - after.len();
+    if name.is_empty() {
+        None
+    } else if after.starts_with('>') {
+        let tag = if closing {
+            Tag::Close(name)
+        } else {
+            Tag::Open(name)
+        };
+        Some((tag, consumed + 1))
+    } else if !closing && after.starts_with("/>") {
+        Some((Tag::SelfClosed(name), consumed + 2))
+    } else {
+        None
+    }
+}
+
+/// Parse an override value into text, variables (`{{ name }}`) and components (`<name>...</name>` or `<name />`).
+fn parse_value(value: &str) -> Result<Vec<Segment>, String> {
+    fn flush(text: &mut String, segments: &mut Vec<Segment>) {
+        if !text.is_empty() {
+            segments.push(Segment::Text(std::mem::take(text).into()));
+        }
+    }
+
+    let mut parents: Vec<(&str, Vec<Segment>)> = Vec::new();
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("{{") {
+            let end = after
+                .find("}}")
+                .ok_or_else(|| "unclosed \"{{\"".to_owned())?;
+            let name = after[..end].trim();
+            if name.is_empty() || !name.chars().all(is_name_char) {
+                return Err(format!(
+                    "invalid variable \"{{{{{}}}}}\", only the name of the variable can be given",
+                    &after[..end]
+                ));
+            }
+            flush(&mut text, &mut segments);
+            segments.push(Segment::Var(name.into()));
+            rest = &after[end + 2..];
+            continue;
+        }
+        if let Some((tag, len)) = parse_tag(rest) {
+            flush(&mut text, &mut segments);
+            match tag {
+                Tag::Open(name) => parents.push((name, std::mem::take(&mut segments))),
+                Tag::Close(name) => {
+                    let Some((open, parent)) = parents.pop() else {
+                        return Err(format!("closing tag </{name}> without an opening tag"));
+                    };
+                    if open != name {
+                        return Err(format!("tag <{open}> closed by </{name}>"));
+                    }
+                    let children = std::mem::replace(&mut segments, parent);
+                    segments.push(Segment::Comp {
+                        name: name.into(),
+                        children: Some(children.into()),
+                    });
+                }
+                Tag::SelfClosed(name) => segments.push(Segment::Comp {
+                    name: name.into(),
+                    children: None,
+                }),
+            }
+            rest = &rest[len..];
+            continue;
+        }
+        text.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if let Some((name, _)) = parents.pop() {
+        return Err(format!("unclosed tag <{name}>"));
+    }
+    flush(&mut text, &mut segments);
+    Ok(segments)
+}
+
+/// Return the first variable or component of `segments` the key doesn't have.
+fn find_unexpected<'a>(segments: &'a [Segment], key: &OverridableKey) -> Option<&'a str> {
+    segments.iter().find_map(|segment| match segment {
+        Segment::Text(_) => None,
+        Segment::Var(name) => (!key.variables.contains(&&**name)).then_some(&**name),
+        Segment::Comp {
+            name,
+            children: Some(children),
+        } => {
+            if key.components.contains(&&**name) {
+                find_unexpected(children, key)
+            } else {
+                Some(&**name)
+            }
+        }
+        Segment::Comp {
+            name,
+            children: None,
+        } => (!key.self_closed_components.contains(&&**name)).then_some(&**name),
+    })
+}
+
+/// The override of an interpolated key, parsed when it is pushed.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: Arc<str>,
+    segments: Arc<[Segment]>,
+}
+
+fn render_segments<V, C>(segments: &Arc<[Segment]>, var: &V, comp: &C) -> AnyView
+where
+    V: Fn(&str) -> AnyView + Clone + Send + Sync + 'static,
+    C: Fn(&str, Option<ChildrenFn>) -> AnyView + Clone + Send + Sync + 'static,
+{
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text.to_string().into_any(),
+            Segment::Var(name) => var(name),
+            Segment::Comp { name, children } => {
+                let children = children.clone().map(|children| {
+                    let (var, comp) = (var.clone(), comp.clone());
+                    let children: ChildrenFn =
+                        Arc::new(move || render_segments(&children, &var, &comp));
+                    children
+                });
+                comp(name, children)
+            }
+        })
+        .collect::<Vec<_>>()
+        .into_any()
+}
+
+type DynFmtFn<'a> = &'a dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+fn fmt_segments<V, C>(
+    segments: &[Segment],
+    f: &mut fmt::Formatter<'_>,
+    var: &V,
+    comp: &C,
+) -> fmt::Result
+where
+    V: Fn(&str, &mut fmt::Formatter<'_>) -> fmt::Result,
+    C: Fn(&str, &mut fmt::Formatter<'_>, Option<DynFmtFn<'_>>) -> fmt::Result,
+{
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => f.write_str(text)?,
+            Segment::Var(name) => var(name, f)?,
+            Segment::Comp {
+                name,
+                children: Some(children),
+            } => comp(
+                name,
+                f,
+                Some(&|f: &mut fmt::Formatter<'_>| fmt_segments(children, f, var, comp)),
+            )?,
+            Segment::Comp {
+                name,
+                children: None,
+            } => comp(name, f, None)?,
+        }
+    }
+    Ok(())
+}
+
+impl Template {
+    /// Render the override, with `var` rendering the variables and `comp` the components of the key.
+    pub fn into_view<V, C>(self, var: V, comp: C) -> impl IntoView + Clone + 'static
+    where
+        V: Fn(&str) -> AnyView + Clone + Send + Sync + 'static,
+        C: Fn(&str, Option<ChildrenFn>) -> AnyView + Clone + Send + Sync + 'static,
+    {
+        move || render_segments(&self.segments, &var, &comp)
+    }
+
+    /// Format the override, with `var` formatting the variables and `comp` the components of the key.
+    pub fn fmt<V, C>(&self, f: &mut fmt::Formatter<'_>, var: V, comp: C) -> fmt::Result
+    where
+        V: Fn(&str, &mut fmt::Formatter<'_>) -> fmt::Result,
+        C: Fn(&str, &mut fmt::Formatter<'_>, Option<DynFmtFn<'_>>) -> fmt::Result,
+    {
+        fmt_segments(&self.segments, f, &var, &comp)
+    }
+}
+
+#[doc(hidden)]
+pub fn any_view(view: impl IntoView) -> AnyView {
+    view.into_view().into_any()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Entry {
+    Literal(&'static str),
+    Template(Template),
+}
+
+#[derive(Default)]
+struct Interned {
+    values: HashSet<&'static str>,
+    leaked_bytes: usize,
+}
+
+/// Return the leaked allocation of `value`, leaking it only if no override had this value before.
+///
+/// `None` if leaking it would exceed `MAX_LEAKED_BYTES`.
+fn intern(value: &str) -> Option<&'static str> {
+    static INTERNED: OnceLock<Mutex<Interned>> = OnceLock::new();
+    let mut interned = INTERNED.get_or_init(Default::default).lock().unwrap();
+    if let Some(value) = interned.values.get(value) {
+        return Some(value);
+    }
+    if interned.leaked_bytes + value.len() > MAX_LEAKED_BYTES {
+        return None;
+    }
+    let value: &'static str = Box::leak(value.into());
+    interned.leaked_bytes += value.len();
+    interned.values.insert(value);
+    Some(value)
+}
+
+type OverridesMap = HashMap<&'static str, HashMap<Box<str>, Entry>>;
+
+/// Return the override of a plain string key, subscribing to the changes of the overrides.
+#[doc(hidden)]
+pub fn lookup<L: Locale>(locale: L, path: &str) -> Option<&'static str> {
+    let overrides = use_context::<TranslationOverrides<L>>()?;
+    overrides.with_entry(locale, path, |entry| match entry {
+        Entry::Literal(value) => Some(*value),
+        Entry::Template(_) => None,
+    })
+}
+
+/// Return the override of an interpolated key, subscribing to the changes of the overrides.
+#[doc(hidden)]
+pub fn lookup_template<L: Locale>(locale: L, path: &str) -> Option<Template> {
+    let overrides = use_context::<TranslationOverrides<L>>()?;
+    overrides.with_entry(locale, path, |entry| match entry {
+        Entry::Template(template) => Some(template.clone()),
+        Entry::Literal(_) => None,
+    })
+}
+
+/// Error returned when an override is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverrideError {
+    /// The locale is not one of the application locales.
+    UnknownLocale(String),
+    /// No key that can be overridden exists at this path.
+    UnknownKey(String),
+    /// The value uses a variable or a component the key doesn't have.
+    UnexpectedVariable {
+        /// Path of the key.
+        key: String,
+        /// Name of the variable or component.
+        variable: String,
+    },
+    /// The value can't be parsed, such as a component that is never closed.
+    InvalidTemplate {
+        /// Path of the key.
+        key: String,
+        /// What is wrong with the value.
+        reason: String,
+    },
+    /// The value of the key is not a string.
+    InvalidValue(String),
+    /// The values of the plain string keys pushed so far already use the `MAX_LEAKED_BYTES` kept for them.
+    LimitReached(String),
+}
+
+impl Display for OverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverrideError::UnknownLocale(locale) => {
+                write!(f, "invalid translation override: unknown locale {locale:?}")
+            }
+            OverrideError::UnknownKey(key) => {
+                write!(
+                    f,
+                    "invalid translation override: no key {key:?} that can be overridden"
+                )
+            }
+            OverrideError::UnexpectedVariable { key, variable } => write!(
+                f,
+                "invalid translation override: key {key:?} has no variable or component {variable:?} the override can use"
+            ),
+            OverrideError::InvalidTemplate { key, reason } => {
+                write!(f, "invalid translation override of key {key:?}: {reason}")
+            }
+            OverrideError::InvalidValue(key) => write!(
+                f,
+                "invalid translation override: value of key {key:?} is not a string"
+            ),
+            OverrideError::LimitReached(key) => write!(
+                f,
+                "invalid translation override of key {key:?}: the overrides already use the {MAX_LEAKED_BYTES} bytes kept for them"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OverrideError {}
+
+fn validate<L: Locale>(key: &str, value: &str) -> Result<Entry, OverrideError> {
+    let Some(overridable) = L::translation_keys().iter().find(|k| k.path == key) else {
+        return Err(OverrideError::UnknownKey(key.to_owned()));
+    };
+    let segments = parse_value(value).map_err(|reason| OverrideError::InvalidTemplate {
+        key: key.to_owned(),
+        reason,
+    })?;
+    if let Some(variable) = find_unexpected(&segments, overridable) {
+        return Err(OverrideError::UnexpectedVariable {
+            key: key.to_owned(),
+            variable: variable.to_owned(),
+        });
+    }
+    if overridable.interpolated {
+        Ok(Entry::Template(Template {
+            source: value.into(),
+            segments: segments.into(),
+        }))
+    } else {
+        intern(value)
+            .map(Entry::Literal)
+            .ok_or_else(|| OverrideError::LimitReached(key.to_owned()))
+    }
+}
+
+fn flatten_json<'a>(
+    prefix: &str,
+    value: &'a serde_json::Value,
+    entries: &mut Vec<(String, Option<&'a str>)>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_json(&path, value, entries);
+            }
+        }
+        serde_json::Value::String(s) => entries.push((prefix.to_owned(), Some(s))),
+        _ => entries.push((prefix.to_owned(), None)),
+    }
+}
+
+/// Handle to the translation overrides of an `I18nContext`, returned by `I18nContext::overrides`.
+pub struct TranslationOverrides<L: Locale> {
+    overrides: RwSignal<OverridesMap>,
+    marker: PhantomData<L>,
+}
+
+impl<L: Locale> Clone for TranslationOverrides<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: Locale> Copy for TranslationOverrides<L> {}
+
+impl<L: Locale> fmt::Debug for TranslationOverrides<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TranslationOverrides")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: Locale> TranslationOverrides<L> {
+    pub(crate) fn new() -> Self {
+        TranslationOverrides {
+            overrides: RwSignal::new(HashMap::new()),
+            marker: PhantomData,
+        }
+    }
+
+    fn with_entry<T>(
+        self,
+        locale: L,
+        path: &str,
+        f: impl FnOnce(&Entry) -> Option<T>,
+    ) -> Option<T> {
+        // the overrides may already be disposed with the owner of the context.
+        self.overrides
+            .try_with(|overrides| overrides.get(locale.as_str())?.get(path).and_then(f))
+            .flatten()
+    }
+
+    fn insert(
+        overrides: &mut OverridesMap,
+        locale: L,
+        key: &str,
+        value: &str,
+    ) -> Result<(), OverrideError> {
+        let entry =
+            validate::<L>(key, value).inspect_err(|err| leptos::logging::warn!("{}", err))?;
+        overrides
+            .entry(locale.as_str())
+            .or_default()
+            .insert(key.into(), entry);
+        Ok(())
+    }
+
+    /// Override the value of a key for the given locale.
+    ///
+    /// The override is rejected and a warning is logged if the key can't be overridden
+    /// or the value uses variables or components the key doesn't have.
+    pub fn set(self, locale: L, key: &str, value: &str) -> Result<(), OverrideError> {
+        self.overrides
+            .try_update(|overrides| Self::insert(overrides, locale, key, value))
+            .unwrap_or(Ok(()))
+    }
+
+    /// Load overrides from a JSON object mapping each locale to its overrides.
+    ///
+    /// Overrides can be given with their full path (`"form.email"`) or as nested objects (`{ "form": { "email": "..." } }`),
+    /// and are added to the existing ones. Invalid overrides are logged and skipped,
+    /// the number of overrides applied is returned.
+    pub fn load_json(self, json: &str) -> Result<usize, serde_json::Error> {
+        let locales: HashMap<String, serde_json::Value> = serde_json::from_str(json)?;
+        let mut entries = Vec::new();
+        for (locale, value) in &locales {
+            let Some(locale) = L::get_all().iter().find(|l| l.as_str() == locale) else {
+                leptos::logging::warn!("{}", OverrideError::UnknownLocale(locale.clone()));
+                continue;
+            };
+            let mut locale_entries = Vec::new();
+            flatten_json("", value, &mut locale_entries);
+            entries.extend(
+                locale_entries
+                    .into_iter()
+                    .map(|(key, value)| (*locale, key, value)),
+            );
+        }
+        let mut applied = 0;
+        let mut overrides = self.overrides.try_write_untracked();
+        for (locale, key, value) in entries {
+            let Some(value) = value else {
+                leptos::logging::warn!("{}", OverrideError::InvalidValue(key));
+                continue;
+            };
+            if let Some(overrides) = overrides.as_deref_mut()
+                && Self::insert(overrides, locale, &key, value).is_ok()
+            {
+                applied += 1;
+            }
+        }
+        drop(overrides);
+        if applied > 0 {
+            self.overrides.notify();
+        }
+        Ok(applied)
+    }
+
+    /// Return the override of a key for the given locale, if any.
+    pub fn get(self, locale: L, key: &str) -> Option<Cow<'static, str>> {
+        self.with_entry(locale, key, |entry| match entry {
+            Entry::Literal(value) => Some(Cow::Borrowed(*value)),
+            Entry::Template(template) => Some(Cow::Owned(template.source.to_string())),
+        })
+    }
+
+    /// Remove the override of a key for the given locale, returning `true` if there was one.
+    pub fn remove(self, locale: L, key: &str) -> bool {
+        let removed = self
+            .overrides
+            .try_update_untracked(|overrides| {
+                overrides
+                    .get_mut(locale.as_str())
+                    .and_then(|overrides| overrides.remove(key))
+                    .is_some()
+            })
+            .unwrap_or(false);
+        if removed {
+            self.overrides.notify();
+        }
+        removed
+    }
+
+    /// Remove every override.
+    pub fn clear(self) {
+        self.overrides.try_update(HashMap::clear);
+    }
+}
+
+#[cfg(all(test, not(feature = "dynamic_load")))]
+mod tests {
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        interpolate_display,
+        default: "en",
+        locales: ["en", "fr"],
+        en: {
+            hello: "Hello",
+            form: {
+                email: "Email",
+            },
+            greeting: "Hi <b>{{ name }}</b>!<br/>",
+            more: "<a href=\"/more\">more</a>",
+        },
+        fr: {
+            hello: "Bonjour",
+            form: {
+                email: "Courriel",
+            },
+            greeting: "Salut <b>{{ name }}</b> !<br/>",
+            more: "<a href=\"/more\">plus</a>",
+        },
+    }
+
+    use super::*;
+    use crate as leptos_i18n;
+    use i18n::{Locale, td_string};
+
+    fn with_overrides<T>(f: impl FnOnce(TranslationOverrides<Locale>) -> T) -> T {
+        let owner = Owner::new();
+        owner.with(|| {
+            let overrides = TranslationOverrides::new();
+            provide_context(overrides);
+            f(overrides)
+        })
+    }
+
+    #[test]
+    fn intern_reuses_allocations() {
+        let hello = intern("Hello").unwrap();
+        assert_eq!(hello, "Hello");
+        assert!(std::ptr::eq(hello, intern(&String::from("Hello")).unwrap()));
+        let hi = intern("Hi").unwrap();
+        assert!(!std::ptr::eq(hello, hi));
+        assert!(std::ptr::eq(hi, intern("Hi").unwrap()));
+    }
+
+    #[test]
+    fn parse_templates() {
+        assert_eq!(
+            parse_value("plain text, 1 < 2 and a > b").unwrap(),
+            [Segment::Text("plain text, 1 < 2 and a > b".into())]
+        );
+        assert_eq!(
+            parse_value("Hi <b>{{name}}</b><br />").unwrap(),
+            [
+                Segment::Text("Hi ".into()),
+                Segment::Comp {
+                    name: "b".into(),
+                    children: Some([Segment::Var("name".into())].into()),
+                },
+                Segment::Comp {
+                    name: "br".into(),
+                    children: None,
+                },
+            ]
+        );
+        assert!(parse_value("Hi {{ name").is_err());
+        assert!(parse_value("Hi {{ name | number }}").is_err());
+        assert!(parse_value("<b>bold").is_err());
+        assert!(parse_value("<b>bold</i>").is_err());
+        assert!(parse_value("bold</b>").is_err());
+    }
+
+    #[test]
+    fn validate_overrides() {
+        assert_eq!(
+            validate::<Locale>("form.email", "Email address"),
+            Ok(Entry::Literal(intern("Email address").unwrap()))
+        );
+        assert_eq!(
+            validate::<Locale>("form.phone", "Phone"),
+            Err(OverrideError::UnknownKey("form.phone".to_owned()))
+        );
+        assert!(matches!(
+            validate::<Locale>("greeting", "Hey <b>{{ name }}</b><br/>"),
+            Ok(Entry::Template(_))
+        ));
+        assert_eq!(
+            validate::<Locale>("greeting", "Hey {{ user }}"),
+            Err(OverrideError::UnexpectedVariable {
+                key: "greeting".to_owned(),
+                variable: "user".to_owned()
+            })
+        );
+        assert_eq!(
+            validate::<Locale>("greeting", "Hey <i>{{ name }}</i>"),
+            Err(OverrideError::UnexpectedVariable {
+                key: "greeting".to_owned(),
+                variable: "i".to_owned()
+            })
+        );
+        // a plain string key has no variable.
+        assert_eq!(
+            validate::<Locale>("hello", "Hello {{ name }}"),
+            Err(OverrideError::UnexpectedVariable {
+                key: "hello".to_owned(),
+                variable: "name".to_owned()
+            })
+        );
+        assert!(matches!(
+            validate::<Locale>("greeting", "Hey <b>{{ name }}"),
+            Err(OverrideError::InvalidTemplate { .. })
+        ));
+        // the override can't give the `href` attribute to `<a>`.
+        assert_eq!(
+            validate::<Locale>("more", "<a>see more</a>"),
+            Err(OverrideError::UnexpectedVariable {
+                key: "more".to_owned(),
+                variable: "a".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn load_json() {
+        with_overrides(|overrides| {
+            let count = overrides
+                .load_json(
+                    r#"{
+                        "en": { "hello": "Hi", "form": { "email": "Email address" }, "greeting": "Hey {{ name }}" },
+                        "fr": { "form.email": "Adresse", "form": { "phone": "Téléphone" }, "hello": 3 },
+                        "de": { "hello": "Hallo" }
+                    }"#,
+                )
+                .unwrap();
+            assert_eq!(count, 4);
+            assert_eq!(overrides.get(Locale::en, "hello").as_deref(), Some("Hi"));
+            assert_eq!(
+                overrides.get(Locale::en, "form.email").as_deref(),
+                Some("Email address")
+            );
+            assert_eq!(
+                overrides.get(Locale::en, "greeting").as_deref(),
+                Some("Hey {{ name }}")
+            );
+            assert_eq!(
+                overrides.get(Locale::fr, "form.email").as_deref(),
+                Some("Adresse")
+            );
+            assert_eq!(overrides.get(Locale::fr, "form.phone"), None);
+            assert_eq!(overrides.get(Locale::fr, "hello"), None);
+
+            assert!(overrides.load_json("[1, 2]").is_err());
+        });
+    }
+
+    #[test]
+    fn set_get_remove_clear() {
+        with_overrides(|overrides| {
+            assert_eq!(overrides.get(Locale::en, "hello"), None);
+            overrides.set(Locale::en, "hello", "Hi").unwrap();
+            overrides.set(Locale::fr, "hello", "Salut").unwrap();
+            assert!(overrides.set(Locale::fr, "bye", "Au revoir").is_err());
+            assert_eq!(overrides.get(Locale::en, "hello").as_deref(), Some("Hi"));
+            assert_eq!(td_string!(Locale::en, hello).to_string(), "Hi");
+            assert_eq!(td_string!(Locale::fr, hello).to_string(), "Salut");
+
+            // a rejected override keeps the previous one.
+            assert!(overrides.set(Locale::en, "hello", "Hi {{ name }}").is_err());
+            assert_eq!(overrides.get(Locale::en, "hello").as_deref(), Some("Hi"));
+
+            assert!(overrides.remove(Locale::en, "hello"));
+            assert!(!overrides.remove(Locale::en, "hello"));
+            assert_eq!(td_string!(Locale::en, hello).to_string(), "Hello");
+            assert_eq!(td_string!(Locale::fr, hello).to_string(), "Salut");
+
+            overrides.clear();
+            assert_eq!(overrides.get(Locale::fr, "hello"), None);
+            assert_eq!(td_string!(Locale::fr, hello).to_string(), "Bonjour");
+        });
+    }
+
+    #[test]
+    fn format_interpolated_override() {
+        with_overrides(|overrides| {
+            overrides
+                .set(Locale::fr, "greeting", "Coucou <b>{{ name }}</b><br/>")
+                .unwrap();
+            let b = |f: &mut fmt::Formatter, children: crate::display::Children| {
+                write!(f, "*{children}*")
+            };
+            let br = |f: &mut fmt::Formatter| write!(f, " / ");
+            assert_eq!(
+                td_string!(Locale::fr, greeting, name = "Ana", <b> = b, <br/> = br),
+                "Coucou *Ana* / "
+            );
+            assert_eq!(
+                td_string!(Locale::en, greeting, name = "Ana", <b> = b, <br/> = br),
+                "Hi *Ana*! / "
+            );
+        });
+        // outside of the context the translation is used.
+        let b =
+            |f: &mut fmt::Formatter, children: crate::display::Children| write!(f, "*{children}*");
+        let br = |f: &mut fmt::Formatter| write!(f, " / ");
+        assert_eq!(
+            td_string!(Locale::fr, greeting, name = "Ana", <b> = b, <br/> = br),
+            "Salut *Ana* ! / "
+        );
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn render_interpolated_override() {
+        use i18n::{td, use_i18n};
+
+        let rendered = crate::testing::render_in_locale(Locale::en, || {
+            use_i18n()
+                .overrides()
+                .set(Locale::en, "greeting", "Welcome, <b>{{ name }}</b>.")
+                .unwrap();
+            td!(Locale::en, greeting, name = "Ana", <b> = <strong />, <br/> = || view! { <br /> })
+        });
+        assert_eq!(rendered.html, "Welcome, <strong>Ana</strong>.");
+    }
+}
@@ -0,0 +1,329 @@
+//! Formatting preferences of the user, carried by the `I18nContext`.
+//!
+//! The preferences are set with `I18nContext::set_preferences`, for example from the profile of the user,
+//! and formatters in the translations files can refer to them instead of a hard-coded value:
+//!
+//! ```json
+//! {
+//!     "price": "Price: {{ price, currency(currency_code: $user) }}"
+//! }
+//! ```
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! i18n.set_preferences(UserPreferences::default().currency("EUR")?);
+//! view! { <p>{t!(i18n, price, price = move || 42)}</p> } // "Price: €42"
+//! ```
+//!
+//! The views are updated when the preferences change. Formatters only find the preferences when rendered under
+//! the context, with `t!` and its variants: `td!` and `tu!` used without a context use the defaults.
+//! Without a preferred currency `$user` uses `USD`, or the code it is given such as `$user(EUR)`.
+//!
+//! Only the currency can be referenced by the formatters for now, the time zone, measurement system and first day of the week
+//! are there for the application to read with `I18nContext::time_zone` and the like,
+//! such as formatting dates in the time zone of the user before passing them to a translation.
+
+use std::{borrow::Cow, fmt::Display};
+
+use leptos::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A system of units of measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MeasurementSystem {
+    /// The metric system.
+    Metric,
+    /// The United States customary system.
+    Us,
+    /// The mix of metric and imperial units used in the United Kingdom.
+    Uk,
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// Formatting preferences of a user, `None` when the user has no preference.
+///
+/// Deserializing preferences with an invalid currency code or time zone name is an error.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserPreferences {
+    /// ISO 4217 code of the preferred currency, such as `EUR`, used by `currency(currency_code: $user)`.
+    ///
+    /// Set it with `UserPreferences::currency` to validate it, an invalid code set directly falls back to
+    /// the code given in the translation.
+    #[serde(default, deserialize_with = "deserialize_currency")]
+    pub currency: Option<Cow<'static, str>>,
+    /// IANA name of the time zone of the user, such as `Europe/Paris`.
+    ///
+    /// Set it with `UserPreferences::time_zone` to validate it.
+    #[serde(default, deserialize_with = "deserialize_time_zone")]
+    pub time_zone: Option<Cow<'static, str>>,
+    /// The preferred units of measurement.
+    pub measurement_system: Option<MeasurementSystem>,
+    /// The day weeks start on.
+    pub first_day_of_week: Option<Weekday>,
+}
+
+impl UserPreferences {
+    /// Set the preferred currency, returning an error if `currency` is not made of three ASCII letters.
+    ///
+    /// The code is uppercased, `eur` is stored as `EUR`.
+    pub fn currency(
+        mut self,
+        currency: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, InvalidCurrencyCode> {
+        self.currency = Some(validate_currency(currency.into())?);
+        Ok(self)
+    }
+
+    /// Set the time zone, returning an error if `time_zone` does not follow the naming rules of the IANA time zone database,
+    /// such as `Europe/Paris`, `America/Argentina/Buenos_Aires` or `UTC`.
+    ///
+    /// Only the form of the name is checked, a well formed name that is not in the database is accepted.
+    pub fn time_zone(
+        mut self,
+        time_zone: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, InvalidTimeZone> {
+        self.time_zone = Some(validate_time_zone(time_zone.into())?);
+        Ok(self)
+    }
+
+    /// Set the preferred units of measurement.
+    pub fn measurement_system(self, measurement_system: MeasurementSystem) -> Self {
+        UserPreferences {
+            measurement_system: Some(measurement_system),
+            ..self
+        }
+    }
+
+    /// Set the first day of the week.
+    pub fn first_day_of_week(self, first_day_of_week: Weekday) -> Self {
+        UserPreferences {
+            first_day_of_week: Some(first_day_of_week),
+            ..self
+        }
+    }
+}
+
+fn validate_currency(
+    currency: Cow<'static, str>,
+) -> Result<Cow<'static, str>, InvalidCurrencyCode> {
+    if currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(InvalidCurrencyCode(currency.into_owned()));
+    }
+    if is_currency_code(&currency) {
+        Ok(currency)
+    } else {
+        Ok(Cow::Owned(currency.to_ascii_uppercase()))
+    }
+}
+
+/// Names are made of `/` separated parts of at most 14 ASCII letters, digits, `.`, `_`, `-` or `+`,
+/// starting with a letter, see the "Theory" file of the time zone database.
+fn validate_time_zone(time_zone: Cow<'static, str>) -> Result<Cow<'static, str>, InvalidTimeZone> {
+    let is_valid_part = |part: &str| {
+        part.len() <= 14
+            && part.starts_with(|c: char| c.is_ascii_alphabetic())
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-' | b'+'))
+    };
+    if time_zone.split('/').all(is_valid_part) {
+        Ok(time_zone)
+    } else {
+        Err(InvalidTimeZone(time_zone.into_owned()))
+    }
+}
+
+fn deserialize_currency<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'static, str>>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|currency| validate_currency(currency.into()).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn deserialize_time_zone<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'static, str>>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|time_zone| validate_time_zone(time_zone.into()).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Error returned by `UserPreferences::currency` for a code that is not an ISO 4217 code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCurrencyCode(pub String);
+
+impl Display for InvalidCurrencyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid currency code {:?}, expected three letters such as \"EUR\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidCurrencyCode {}
+
+/// Error returned by `UserPreferences::time_zone` for a name that is not an IANA time zone name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimeZone(pub String);
+
+impl Display for InvalidTimeZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid time zone {:?}, expected an IANA time zone name such as \"Europe/Paris\"",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidTimeZone {}
+
+/// Return `true` if `code` is made of three uppercase ASCII letters, the format of the ISO 4217 codes.
+pub(crate) fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Preferences of the closest `I18nContext`, provided next to it so the formatters can find them without knowing the locale type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PreferencesContext(pub RwSignal<UserPreferences>);
+
+/// Return the preferences of the closest context, subscribing to changes inside a reactive context.
+#[cfg_attr(not(feature = "format_currency"), allow(dead_code))]
+pub(crate) fn current() -> Option<UserPreferences> {
+    let PreferencesContext(preferences) = use_context()?;
+    crate::context::get_maybe_tracked(&preferences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn currency_validation() {
+        let preferences = UserPreferences::default().currency("EUR").unwrap();
+        assert_eq!(preferences.currency.as_deref(), Some("EUR"));
+        let preferences = UserPreferences::default().currency("jpy").unwrap();
+        assert_eq!(preferences.currency.as_deref(), Some("JPY"));
+        for invalid in ["", "EU", "EURO", "€UR", "E1R"] {
+            assert_eq!(
+                UserPreferences::default().currency(invalid),
+                Err(InvalidCurrencyCode(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn time_zone_validation() {
+        for valid in [
+            "Europe/Paris",
+            "America/Argentina/Buenos_Aires",
+            "UTC",
+            "Etc/GMT+5",
+            "America/Port-au-Prince",
+        ] {
+            let preferences = UserPreferences::default().time_zone(valid).unwrap();
+            assert_eq!(preferences.time_zone.as_deref(), Some(valid));
+        }
+        for invalid in [
+            "",
+            "Europe/",
+            "/Paris",
+            "Europe//Paris",
+            "Europe/Paris France",
+            "Europe/Zürich",
+            "../etc/passwd",
+            "Pacific/Averyveryverylongcity",
+        ] {
+            assert_eq!(
+                UserPreferences::default().time_zone(invalid),
+                Err(InvalidTimeZone(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_preferences() {
+        let preferences = UserPreferences::default()
+            .currency("EUR")
+            .unwrap()
+            .time_zone("Europe/Paris")
+            .unwrap()
+            .measurement_system(MeasurementSystem::Metric)
+            .first_day_of_week(Weekday::Monday);
+        let json = serde_json::to_string(&preferences).unwrap();
+        assert_eq!(
+            json,
+            r#"{"currency":"EUR","time_zone":"Europe/Paris","measurement_system":"metric","first_day_of_week":"monday"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<UserPreferences>(&json).unwrap(),
+            preferences
+        );
+        assert_eq!(
+            serde_json::from_str::<UserPreferences>(r#"{"currency":"usd"}"#).unwrap(),
+            UserPreferences::default().currency("USD").unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<UserPreferences>("{}").unwrap(),
+            UserPreferences::default()
+        );
+        assert!(serde_json::from_str::<UserPreferences>(r#"{"currency":"EURO"}"#).is_err());
+        assert!(
+            serde_json::from_str::<UserPreferences>(r#"{"time_zone":"Paris, France"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn current_preferences() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(current(), None);
+            let preferences = RwSignal::new(UserPreferences::default());
+            provide_context(PreferencesContext(preferences));
+            assert_eq!(current(), Some(UserPreferences::default()));
+            preferences.set(UserPreferences::default().currency("EUR").unwrap());
+            // subcontexts read the preferences of their parent.
+            let child = Owner::new();
+            child.with(|| assert_eq!(current().unwrap().currency.as_deref(), Some("EUR")));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "format_currency")]
+    fn user_currency_code() {
+        use crate::macro_helpers::user_currency_code;
+        use icu_experimental::dimension::currency::CurrencyCode;
+
+        let fallback = CurrencyCode(tinystr::tinystr!(3, "GBP"));
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(user_currency_code(fallback).0.as_str(), "GBP");
+            let preferences = RwSignal::new(UserPreferences::default().currency("EUR").unwrap());
+            provide_context(PreferencesContext(preferences));
+            assert_eq!(user_currency_code(fallback).0.as_str(), "EUR");
+            // set without validation, the code of the translation is used.
+            preferences.set(UserPreferences {
+                currency: Some("eu".into()),
+                ..Default::default()
+            });
+            assert_eq!(user_currency_code(fallback).0.as_str(), "GBP");
+        });
+    }
+}
@@ -121,7 +121,10 @@ impl<L: Locale, S: Scope<L>> FromStr for ScopedLocale<L, S> {
 impl<L: Locale, S: Scope<L>> Locale<L> for ScopedLocale<L, S> {
     type Keys = S::Keys;
     type TranslationUnitId = L::TranslationUnitId;
-    #[cfg(all(feature = "dynamic_load", not(feature = "csr")))]
+    #[cfg(all(
+        feature = "dynamic_load",
+        not(any(feature = "csr", feature = "static_translations"))
+    ))]
     type ServerFn = L::ServerFn;
 
     fn as_str(self) -> &'static str {
@@ -168,6 +171,16 @@ impl<L: Locale, S: Scope<L>> Locale<L> for ScopedLocale<L, S> {
     fn init_translations(self, translations_id: Self::TranslationUnitId, values: Vec<Box<str>>) {
         L::init_translations(self.locale, translations_id, values);
     }
+
+    #[cfg(feature = "translation_overrides")]
+    fn translation_keys() -> &'static [crate::overrides::OverridableKey] {
+        L::translation_keys()
+    }
+
+    #[cfg(feature = "track_fallbacks")]
+    fn defaulted_keys(self) -> &'static [&'static str] {
+        L::defaulted_keys(self.locale)
+    }
 }
 
 impl<L: Locale, Sc: Scope<L>> serde::Serialize for ScopedLocale<L, Sc> {
@@ -0,0 +1,315 @@
+//! Utilities to return localized errors from server functions.
+//!
+//! The error carries a serializable message (usually an enum, where each variant maps to a key and its fields are the arguments),
+//! and the message is translated where it is displayed: on the client with the locale of the `I18nContext`,
+//! or on the server with the locale resolved from the request.
+//!
+#![cfg_attr(feature = "dynamic_load", doc = "```rust, ignore")]
+#![cfg_attr(not(feature = "dynamic_load"), doc = "```rust, no_run")]
+//! #   leptos_i18n::declare_locales! {
+//! #       path: leptos_i18n,
+//! #       interpolate_display,
+//! #       default: "en",
+//! #       locales: ["en"],
+//! #       en: {
+//! #           errors: {
+//! #               not_found: "Not found",
+//! #               quota: "You can't have more than {{ limit }} projects",
+//! #           },
+//! #       },
+//! #   };
+//! # use i18n::*;
+//! use leptos::prelude::*;
+//! use leptos_i18n::server_error::{I18nServerError, LocalizedMessage};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Clone, Serialize, Deserialize)]
+//! enum AppError {
+//!     NotFound,
+//!     Quota { limit: u32 },
+//! }
+//!
+//! impl LocalizedMessage<Locale> for AppError {
+//!     fn render(&self, locale: Locale) -> String {
+//!         match self {
+//!             AppError::NotFound => td_string!(locale, errors.not_found).to_string(),
+//!             AppError::Quota { limit } => td_string!(locale, errors.quota, limit = *limit),
+//!         }
+//!     }
+//! }
+//!
+//! #[server]
+//! async fn create_project() -> Result<(), I18nServerError<AppError>> {
+//!     Err(AppError::Quota { limit: 3 }.into())
+//! }
+//!
+//! #[component]
+//! fn CreateProject() -> impl IntoView {
+//!     let i18n = use_i18n();
+//!     let action = ServerAction::<CreateProject>::new();
+//!     let error = move || match action.value().get() {
+//!         Some(Err(err)) => Some(err.into_view(i18n)),
+//!         _ => None,
+//!     };
+//!     view! { <p>{error}</p> }
+//! }
+//! ```
+
+use leptos::{
+    IntoView,
+    server_fn::{
+        codec::JsonEncoding,
+        error::{FromServerFnError, ServerFnErrorErr},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{I18nContext, Locale, Scope, context::I18nContextOptions};
+
+/// Trait for messages that can be translated in a given locale, such as error payloads.
+///
+/// It is implemented for every type implementing `Translate`.
+pub trait LocalizedMessage<L: Locale> {
+    /// Render the message in the given locale.
+    #[cfg(not(feature = "dynamic_load"))]
+    fn render(&self, locale: L) -> String;
+
+    /// Render the message in the given locale.
+    #[cfg(feature = "dynamic_load")]
+    fn render(&self, locale: L) -> impl Future<Output = String>;
+}
+
+impl<T: crate::Translate> LocalizedMessage<T::Locale> for T {
+    #[cfg(not(feature = "dynamic_load"))]
+    fn render(&self, locale: T::Locale) -> String {
+        self.translate(locale).to_string()
+    }
+
+    #[cfg(feature = "dynamic_load")]
+    fn render(&self, locale: T::Locale) -> impl Future<Output = String> {
+        let translation = self.translate(locale);
+        async move {
+            #[cfg(not(feature = "ssr"))]
+            let translation = translation.await;
+            translation.to_string()
+        }
+    }
+}
+
+/// Error type for server functions, carrying either a localized message or an error from the server function machinery.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum I18nServerError<M> {
+    /// A message to translate.
+    Message(M),
+    /// An error raised by the server function itself (network, serialization, ...), these are not localized.
+    ServerFn(ServerFnErrorErr),
+}
+
+impl<M> From<M> for I18nServerError<M> {
+    fn from(value: M) -> Self {
+        I18nServerError::Message(value)
+    }
+}
+
+impl<M> FromServerFnError for I18nServerError<M>
+where
+    M: std::fmt::Debug + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    type Encoder = JsonEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        I18nServerError::ServerFn(value)
+    }
+}
+
+impl<M> I18nServerError<M> {
+    /// Render the error in the given locale.
+    #[cfg(not(feature = "dynamic_load"))]
+    pub fn render<L: Locale>(&self, locale: L) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        match self {
+            I18nServerError::Message(msg) => msg.render(locale),
+            I18nServerError::ServerFn(err) => err.to_string(),
+        }
+    }
+
+    /// Render the error in the given locale.
+    #[cfg(feature = "dynamic_load")]
+    pub async fn render<L: Locale>(&self, locale: L) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        match self {
+            I18nServerError::Message(msg) => msg.render(locale).await,
+            I18nServerError::ServerFn(err) => err.to_string(),
+        }
+    }
+
+    /// Render the error with the locale resolved by `leptos_i18n::locale::resolve_locale`,
+    /// for when the message must be rendered on the server, for example in a server function.
+    #[cfg(not(feature = "dynamic_load"))]
+    pub fn render_with_resolved_locale<L: Locale>(&self) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        self.render(crate::locale::resolve_locale::<L>())
+    }
+
+    /// Same as `render_with_resolved_locale` but with some options, such as the cookie or the detection chain.
+    #[cfg(not(feature = "dynamic_load"))]
+    pub fn render_with_options<L: Locale>(&self, options: I18nContextOptions<L>) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        self.render(crate::locale::resolve_locale_with_options(options))
+    }
+
+    /// Render the error with the locale resolved by `leptos_i18n::locale::resolve_locale`,
+    /// for when the message must be rendered on the server, for example in a server function.
+    #[cfg(feature = "dynamic_load")]
+    pub async fn render_with_resolved_locale<L: Locale>(&self) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        self.render(crate::locale::resolve_locale::<L>()).await
+    }
+
+    /// Same as `render_with_resolved_locale` but with some options, such as the cookie or the detection chain.
+    #[cfg(feature = "dynamic_load")]
+    pub async fn render_with_options<L: Locale>(&self, options: I18nContextOptions<'_, L>) -> String
+    where
+        M: LocalizedMessage<L>,
+    {
+        self.render(crate::locale::resolve_locale_with_options(options))
+            .await
+    }
+
+    /// Render the error with the current locale of the given context, updating on locale change.
+    pub fn into_view<L: Locale, S: Scope<L>>(self, i18n: I18nContext<L, S>) -> impl IntoView
+    where
+        M: LocalizedMessage<L> + Send + Sync + 'static,
+    {
+        #[cfg(not(feature = "dynamic_load"))]
+        {
+            move || self.render(i18n.get_locale())
+        }
+        #[cfg(feature = "dynamic_load")]
+        {
+            let this = std::sync::Arc::new(self);
+            crate::__private::future_renderer(move || {
+                let this = this.clone();
+                let locale = i18n.get_locale();
+                async move { this.render(locale).await }
+            })
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "dynamic_load")))]
+mod tests {
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        interpolate_display,
+        default: "en",
+        locales: ["en", "fr"],
+        en: {
+            errors: {
+                not_found: "Not found",
+                quota: "You can't have more than {{ limit }} projects",
+            },
+        },
+        fr: {
+            errors: {
+                not_found: "Introuvable",
+                quota: "Vous ne pouvez pas avoir plus de {{ limit }} projets",
+            },
+        },
+    }
+
+    use super::*;
+    use crate as leptos_i18n;
+    use i18n::{Locale, td_string};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum AppError {
+        NotFound,
+        Quota { limit: u32 },
+    }
+
+    impl LocalizedMessage<Locale> for AppError {
+        fn render(&self, locale: Locale) -> String {
+            match self {
+                AppError::NotFound => td_string!(locale, errors.not_found).to_string(),
+                AppError::Quota { limit } => td_string!(locale, errors.quota, limit = *limit),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let err = I18nServerError::from(AppError::Quota { limit: 3 });
+        let de = I18nServerError::<AppError>::de(err.ser());
+        assert_eq!(de, err);
+
+        let err = I18nServerError::<AppError>::from_server_fn_error(ServerFnErrorErr::ServerError(
+            "boom".into(),
+        ));
+        let de = I18nServerError::<AppError>::de(err.ser());
+        assert_eq!(de, err);
+    }
+
+    #[test]
+    fn from_server_fn_error() {
+        let err = I18nServerError::<AppError>::from_server_fn_error(ServerFnErrorErr::Request(
+            "offline".into(),
+        ));
+        assert_eq!(
+            err,
+            I18nServerError::ServerFn(ServerFnErrorErr::Request("offline".into()))
+        );
+        assert_eq!(
+            err.render(Locale::fr),
+            ServerFnErrorErr::Request("offline".into()).to_string()
+        );
+    }
+
+    #[test]
+    fn render() {
+        let err = I18nServerError::from(AppError::Quota { limit: 3 });
+        assert_eq!(
+            err.render(Locale::fr),
+            "Vous ne pouvez pas avoir plus de 3 projets"
+        );
+        assert_eq!(
+            I18nServerError::from(AppError::NotFound).render(Locale::en),
+            "Not found"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ssr")]
+    fn render_with_resolved_locale() {
+        use crate::detectors::{DetectorContext, LocaleDetector};
+        use leptos::prelude::Owner;
+
+        struct Fixed(Locale);
+
+        impl LocaleDetector<Locale> for Fixed {
+            fn detect(&self, _cx: &DetectorContext<Locale>) -> Option<Locale> {
+                Some(self.0)
+            }
+        }
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let err = I18nServerError::from(AppError::NotFound);
+            let options =
+                I18nContextOptions::default().detectors(vec![Box::new(Fixed(Locale::fr))]);
+            assert_eq!(err.render_with_options(options), "Introuvable");
+            // nothing to detect the locale from outside of a request.
+            assert_eq!(err.render_with_resolved_locale::<Locale>(), "Not found");
+        });
+    }
+}
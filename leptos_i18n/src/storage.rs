@@ -0,0 +1,245 @@
+//! Persistence of the choosen locale.
+//!
+//! The context saves the current locale each time it changes, and the saved locale is read back when a new context is initialized
+//! (see the `Storage` detector). The backend is choosen with `I18nContextOptions::storage`, a cookie by default.
+//!
+//! # SSR and hydration
+//!
+//! `localStorage` and `sessionStorage` only exist in the browser, so the server can't read them and renders with the rest of the detection chain.
+//! To avoid a hydration mismatch the client first uses the same locale as the server (read from the "lang" attribute of `<html>`),
+//! then switches to the stored locale once hydrated, so the page can briefly show the server locale.
+//! If this is a problem, use the cookie backend which is readable by the server.
+
+use std::{borrow::Cow, sync::Arc};
+
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+
+use crate::{
+    Locale,
+    context::{CookieOptions, ENABLE_COOKIE},
+};
+
+/// Trait for custom storage backends.
+pub trait LocaleStore<L: Locale>: Send + Sync + 'static {
+    /// Read the saved locale.
+    fn load(&self) -> Option<L>;
+
+    /// Save the locale, called each time the locale of the context changes.
+    fn save(&self, locale: L);
+
+    /// If the store can only be read on the client, the client then switches to the stored locale after hydration
+    /// instead of creating a hydration mismatch.
+    fn client_only(&self) -> bool {
+        false
+    }
+}
+
+/// Where to save the choosen locale.
+#[derive(Default)]
+pub enum LocaleStorage<L: Locale> {
+    /// Save the locale in a cookie, using `I18nContextOptions::cookie_name` and `I18nContextOptions::cookie_options`.
+    /// Does nothing without the "cookie" feature or if `I18nContextOptions::enable_cookie` is false.
+    #[default]
+    Cookie,
+    /// Save the locale in `localStorage` under the given key.
+    LocalStorage(Cow<'static, str>),
+    /// Save the locale in `sessionStorage` under the given key.
+    SessionStorage(Cow<'static, str>),
+    /// Don't save the locale.
+    None,
+    /// Use a custom backend.
+    Custom(Box<dyn LocaleStore<L>>),
+}
+
+impl<L: Locale> LocaleStorage<L> {
+    /// Save the locale in `localStorage` under the given key.
+    pub fn local_storage(key: impl Into<Cow<'static, str>>) -> Self {
+        LocaleStorage::LocalStorage(key.into())
+    }
+
+    /// Save the locale in `sessionStorage` under the given key.
+    pub fn session_storage(key: impl Into<Cow<'static, str>>) -> Self {
+        LocaleStorage::SessionStorage(key.into())
+    }
+
+    /// Use a custom backend.
+    pub fn custom(store: impl LocaleStore<L>) -> Self {
+        LocaleStorage::Custom(Box::new(store))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum WebStorageKind {
+    Local,
+    Session,
+}
+
+struct WebStorage {
+    kind: WebStorageKind,
+    key: Cow<'static, str>,
+}
+
+impl WebStorage {
+    fn with_storage<T>(&self, f: impl FnOnce(leptos::web_sys::Storage) -> Option<T>) -> Option<T> {
+        if cfg!(feature = "ssr") || !cfg!(any(feature = "csr", feature = "hydrate")) {
+            return None;
+        }
+        let window = window();
+        let storage = match self.kind {
+            WebStorageKind::Local => window.local_storage(),
+            WebStorageKind::Session => window.session_storage(),
+        };
+        storage.ok().flatten().and_then(f)
+    }
+}
+
+impl<L: Locale> LocaleStore<L> for WebStorage {
+    fn load(&self) -> Option<L> {
+        let value = self.with_storage(|storage| storage.get_item(&self.key).ok().flatten())?;
+        L::from_str(&value).ok()
+    }
+
+    fn save(&self, locale: L) {
+        self.with_storage(|storage| storage.set_item(&self.key, locale.as_str()).ok());
+    }
+
+    fn client_only(&self) -> bool {
+        true
+    }
+}
+
+pub(crate) struct StorageHandle<L: Locale> {
+    pub stored: Option<L>,
+    pub save: Arc<dyn Fn(L) + Send + Sync>,
+    pub client_only: bool,
+}
+
+impl<L: Locale> StorageHandle<L> {
+    pub fn none() -> Self {
+        StorageHandle {
+            stored: None,
+            save: Arc::new(|_| {}),
+            client_only: false,
+        }
+    }
+
+    pub fn cookie(cookie_name: &str, cookie_options: CookieOptions<L>) -> Self {
+        if !ENABLE_COOKIE {
+            return Self::none();
+        }
+        let (lang_cookie, set_lang_cookie) = leptos_use::use_cookie_with_options::<
+            L,
+            FromToStringCodec,
+        >(cookie_name, cookie_options);
+        StorageHandle {
+            stored: lang_cookie.get_untracked(),
+            save: Arc::new(move |locale| set_lang_cookie.set(Some(locale))),
+            client_only: false,
+        }
+    }
+
+    fn store(store: impl LocaleStore<L>) -> Self {
+        let store = Arc::new(store);
+        StorageHandle {
+            stored: store.load(),
+            client_only: store.client_only(),
+            save: Arc::new(move |locale| store.save(locale)),
+        }
+    }
+
+    pub fn new(
+        storage: LocaleStorage<L>,
+        enable_cookie: bool,
+        cookie_name: &str,
+        cookie_options: CookieOptions<L>,
+    ) -> Self {
+        match storage {
+            LocaleStorage::Cookie if enable_cookie => Self::cookie(cookie_name, cookie_options),
+            LocaleStorage::Cookie | LocaleStorage::None => Self::none(),
+            LocaleStorage::LocalStorage(key) => Self::store(WebStorage {
+                kind: WebStorageKind::Local,
+                key,
+            }),
+            LocaleStorage::SessionStorage(key) => Self::store(WebStorage {
+                kind: WebStorageKind::Session,
+                key,
+            }),
+            LocaleStorage::Custom(store) => Self::store(store),
+        }
+    }
+}
+
+impl<L: Locale> LocaleStore<L> for Box<dyn LocaleStore<L>> {
+    fn load(&self) -> Option<L> {
+        (**self).load()
+    }
+
+    fn save(&self, locale: L) {
+        (**self).save(locale)
+    }
+
+    fn client_only(&self) -> bool {
+        (**self).client_only()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        default: "en",
+        locales: ["en", "fr"],
+        en: {},
+        fr: {},
+    }
+
+    use i18n::Locale;
+
+    #[derive(Default)]
+    struct MemoryStore(Arc<Mutex<Option<Locale>>>);
+
+    impl LocaleStore<Locale> for MemoryStore {
+        fn load(&self) -> Option<Locale> {
+            *self.0.lock().unwrap()
+        }
+
+        fn save(&self, locale: Locale) {
+            *self.0.lock().unwrap() = Some(locale);
+        }
+    }
+
+    #[test]
+    fn test_custom_store() {
+        let saved = Arc::new(Mutex::new(Some(Locale::fr)));
+        let storage = LocaleStorage::custom(MemoryStore(saved.clone()));
+        let handle = StorageHandle::new(storage, true, "", Default::default());
+        assert_eq!(handle.stored, Some(Locale::fr));
+        assert!(!handle.client_only);
+        (handle.save)(Locale::en);
+        assert_eq!(*saved.lock().unwrap(), Some(Locale::en));
+    }
+
+    #[test]
+    fn test_no_storage() {
+        let handle =
+            StorageHandle::<Locale>::new(LocaleStorage::None, true, "", Default::default());
+        assert_eq!(handle.stored, None);
+        (handle.save)(Locale::fr);
+    }
+    #[test]
+    #[cfg(not(any(feature = "ssr", feature = "csr", feature = "hydrate")))]
+    fn test_web_storage_without_window() {
+        for storage in [
+            LocaleStorage::<Locale>::local_storage("lang"),
+            LocaleStorage::session_storage("lang"),
+        ] {
+            let handle = StorageHandle::new(storage, true, "", Default::default());
+            assert_eq!(handle.stored, None);
+            (handle.save)(Locale::fr);
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Helpers to test the translated views in every locale with the "testing" feature.
+//!
+//! The views are rendered to a string on the current thread, so they run in a plain `cargo test` without a browser
+//! or a server integration:
+//!
+//! ```rust, ignore
+//! use leptos_i18n::testing::{render_all_locales, render_in_locale};
+//!
+//! #[test]
+//! fn greeting() {
+//!     let rendered = render_in_locale(Locale::fr, || view! { <Greeting /> });
+//!     assert_eq!(rendered.html, "<p>Bonjour</p>");
+//!
+//!     for rendered in render_all_locales::<Locale, _>(|| view! { <Greeting /> }) {
+//!         rendered.assert_snapshot("greeting");
+//!     }
+//! }
+//! ```
+//!
+//! Snapshots are stored in `tests/snapshots` of the tested crate as `<name>.<locale>.html`.
+//! They are created the first time a test runs, and updated when the `LEPTOS_I18N_UPDATE_SNAPSHOTS` environment variable is set.
+//!
+//! Rendering to a string needs the "ssr" feature, which this feature enables: add it to the `dev-dependencies`
+//! so it is only enabled for the tests, translations loaded with the "dynamic_load" feature are then rendered synchronously too.
+
+use std::{borrow::Cow, fmt::Write as _, path::PathBuf};
+
+use leptos::prelude::*;
+
+use crate::Locale;
+
+/// Name of the environment variable overwriting the stored snapshots with the rendered views.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "LEPTOS_I18N_UPDATE_SNAPSHOTS";
+
+/// A view rendered in a locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedLocale<L: Locale> {
+    /// The locale the view was rendered in.
+    pub locale: L,
+    /// The rendered HTML, without the hydration markers and comments.
+    pub html: String,
+    /// The keys rendered with the value of another locale, see the `fallbacks` module.
+    #[cfg(feature = "track_fallbacks")]
+    pub fallbacks: Vec<&'static str>,
+}
+
+/// Run `f` with an `I18nContext` set to `locale` provided, and return its output.
+///
+/// The context is a mock: it does not read or save the locale in a cookie and does not detect the locale of the user.
+/// The reactive owner is disposed when `f` returns, so views should be rendered inside `f`, as `render_in_locale` does.
+pub fn with_i18n<L: Locale, T>(locale: L, f: impl FnOnce() -> T) -> T {
+    // `Err` when an executor is already set, which is fine.
+    let _ = any_spawner::Executor::init_futures_executor();
+    let owner = Owner::new();
+    owner.with(|| {
+        let i18n = crate::context::init_mock_context(locale);
+        crate::context::I18nContext::provide(i18n);
+        f()
+    })
+}
+
+/// Render the view returned by `view` in `locale`.
+pub fn render_in_locale<L: Locale, V: IntoView>(
+    locale: L,
+    view: impl FnOnce() -> V,
+) -> RenderedLocale<L> {
+    with_i18n(locale, || {
+        #[cfg(feature = "track_fallbacks")]
+        let fallbacks = collect_fallbacks::<L>();
+
+        let html = clean_html(view().into_view().to_html());
+
+        RenderedLocale {
+            locale,
+            html,
+            #[cfg(feature = "track_fallbacks")]
+            fallbacks: fallbacks.finish(),
+        }
+    })
+}
+
+/// Return an iterator rendering the view returned by `view` in each locale of `Locale::get_all`.
+pub fn render_all_locales<L: Locale, V: IntoView>(
+    view: impl Fn() -> V,
+) -> impl Iterator<Item = RenderedLocale<L>> {
+    L::get_all()
+        .iter()
+        .map(move |locale| render_in_locale(*locale, &view))
+}
+
+impl<L: Locale> RenderedLocale<L> {
+    /// The text content of the rendered HTML, without the tags and with the entities decoded.
+    pub fn text(&self) -> String {
+        html_text(&self.html)
+    }
+
+    /// Compare the rendered HTML with the snapshot `<name>.<locale>.html` stored in `tests/snapshots`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot differs, or if it can't be read or written.
+    #[track_caller]
+    pub fn assert_snapshot(&self, name: &str) {
+        assert_snapshot(name, self.locale.as_str(), &self.html);
+    }
+
+    /// Assert that the text of this rendering is at most `max_ratio` times as long as the text of `reference`,
+    /// usually the rendering in the default locale, to find the translations likely to overflow a layout designed for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text is longer than allowed.
+    #[track_caller]
+    pub fn assert_max_expansion(&self, reference: &Self, max_ratio: f32) {
+        let len = self.text().chars().count();
+        let reference_len = reference.text().chars().count();
+        let max_len = (reference_len as f32 * max_ratio).floor() as usize;
+        assert!(
+            len <= max_len,
+            "the text rendered in {} is {} characters long, more than {} times the {} characters rendered in {}",
+            self.locale.as_str(),
+            len,
+            max_ratio,
+            reference_len,
+            reference.locale.as_str()
+        );
+    }
+
+    /// Assert that every key rendered has a value in this locale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if keys were rendered with the value of another locale.
+    #[cfg(feature = "track_fallbacks")]
+    #[track_caller]
+    pub fn assert_no_fallbacks(&self) {
+        assert!(
+            self.fallbacks.is_empty(),
+            "keys rendered without a value in {}: {}",
+            self.locale.as_str(),
+            self.fallbacks.join(", ")
+        );
+    }
+}
+
+/// Return the directory the snapshots are stored in, `tests/snapshots` in the crate being tested.
+pub fn snapshots_dir() -> PathBuf {
+    // set by cargo when running the tests, fallback on the working directory which is the crate directory too.
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    PathBuf::from(manifest_dir).join("tests").join("snapshots")
+}
+
+/// Compare `rendered` with the snapshot `<name>.<locale>.html` stored in `tests/snapshots`.
+///
+/// The snapshot is written instead if it does not exist yet or if the `LEPTOS_I18N_UPDATE_SNAPSHOTS` environment variable is set.
+///
+/// # Panics
+///
+/// Panics if the snapshot differs, or if it can't be read or written.
+#[track_caller]
+pub fn assert_snapshot(name: &str, locale: &str, rendered: &str) {
+    let dir = snapshots_dir();
+    let path = dir.join(format!("{name}.{locale}.html"));
+
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some_and(|var| var != "0");
+    if update || !path.exists() {
+        if let Err(err) =
+            std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, rendered))
+        {
+            panic!("failed to write snapshot {}: {}", path.display(), err);
+        }
+        return;
+    }
+
+    let stored = match std::fs::read_to_string(&path) {
+        Ok(stored) => stored,
+        Err(err) => panic!("failed to read snapshot {}: {}", path.display(), err),
+    };
+    if stored != rendered {
+        panic!(
+            "snapshot {} differs from the rendered view, set {} to update it\n{}",
+            path.display(),
+            UPDATE_SNAPSHOTS_VAR,
+            diff(&stored, rendered)
+        );
+    }
+}
+
+/// List the lines that differ, the views are usually rendered on a single line so the whole output is shown in that case.
+fn diff(stored: &str, rendered: &str) -> String {
+    let mut out = String::new();
+    let mut stored_lines = stored.lines();
+    let mut rendered_lines = rendered.lines();
+    let mut line = 1;
+    loop {
+        match (stored_lines.next(), rendered_lines.next()) {
+            (None, None) => break,
+            (stored, rendered) if stored == rendered => {}
+            (stored, rendered) => {
+                let _ = writeln!(out, "line {line}:");
+                let _ = writeln!(out, "- {}", stored.unwrap_or_default());
+                let _ = writeln!(out, "+ {}", rendered.unwrap_or_default());
+            }
+        }
+        line += 1;
+    }
+    out
+}
+
+#[cfg(feature = "track_fallbacks")]
+struct FallbacksCollector<L: 'static> {
+    id: usize,
+    hooks: crate::fallbacks::FallbackHooks<L>,
+    keys: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+}
+
+#[cfg(feature = "track_fallbacks")]
+fn collect_fallbacks<L: Locale>() -> FallbacksCollector<L> {
+    let keys = std::sync::Arc::<std::sync::Mutex<Vec<&'static str>>>::default();
+    // the hooks belong to the context provided by `with_i18n`, tests running concurrently have their own.
+    let hooks = use_context::<crate::fallbacks::FallbackHooks<L>>()
+        .expect("the context should be provided by `with_i18n`");
+    let id = hooks.add({
+        let keys = keys.clone();
+        move |_, path| {
+            let mut keys = keys.lock().unwrap();
+            if !keys.contains(&path) {
+                keys.push(path);
+            }
+        }
+    });
+    FallbacksCollector { id, hooks, keys }
+}
+
+#[cfg(feature = "track_fallbacks")]
+impl<L: Locale> FallbacksCollector<L> {
+    fn finish(self) -> Vec<&'static str> {
+        self.hooks.remove(self.id);
+        std::mem::take(&mut *self.keys.lock().unwrap())
+    }
+}
+
+/// Remove everything between `start` and `end`, both included.
+fn remove_between<'a>(s: &'a str, start: &str, end: &str) -> Cow<'a, str> {
+    if !s.contains(start) {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some((before, after)) = rest.split_once(start) {
+        out.push_str(before);
+        rest = after.split_once(end).map_or("", |(_, after)| after);
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn clean_html(html: String) -> String {
+    let html = html.replace("<!>", "");
+    let html = remove_between(&html, "<!--", "-->");
+    remove_between(&html, " data-hk=\"", "\"").into_owned()
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+fn html_text(html: &str) -> String {
+    let text = remove_between(html, "<", ">");
+    let mut out = String::with_capacity(text.len());
+    let mut rest = &*text;
+    while let Some((before, after)) = rest.split_once('&') {
+        out.push_str(before);
+        match after
+            .split_once(';')
+            .and_then(|(entity, after)| Some((decode_entity(entity)?, after)))
+        {
+            Some((c, after)) => {
+                out.push(c);
+                rest = after;
+            }
+            None => {
+                out.push('&');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    leptos_i18n_macro::declare_locales! {
+        path: crate,
+        default: "en",
+        locales: ["en", "fr"],
+        en: {
+            hello: "Hello",
+        },
+        fr: {
+            hello: "Bonjour",
+        },
+    }
+
+    use super::*;
+    use crate::Locale as _;
+    use i18n::Locale;
+
+    fn locale_view() -> impl IntoView {
+        let i18n = crate::use_i18n_context::<Locale>();
+        view! { <p>{move || i18n.get_locale().as_str()}" & b"</p> }
+    }
+
+    #[test]
+    fn render_each_locale() {
+        let rendered = render_all_locales(locale_view)
+            .map(|rendered| (rendered.locale, rendered.html))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            [
+                (Locale::en, "<p>en &amp; b</p>".to_owned()),
+                (Locale::fr, "<p>fr &amp; b</p>".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn text_content() {
+        let rendered = render_in_locale(Locale::fr, locale_view);
+        assert_eq!(rendered.text(), "fr & b");
+        assert_eq!(
+            html_text("<b>1 &lt; 2</b>&#x27;&unknown;"),
+            "1 < 2'&unknown;"
+        );
+    }
+
+    #[test]
+    fn clean_markers() {
+        assert_eq!(
+            clean_html(r#"<p data-hk="0">a<!>b<!-- c --></p>"#.to_owned()),
+            "<p>ab</p>"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Persistent cache of the loaded translations, stored in the browser Cache Storage with the "translations_cache" feature.
+//!
+//! Each translation unit is stored with the hash of its strings computed at build time,
+//! a cached unit with a different hash comes from a previous deploy and is requested again.
+
+use std::future::Future;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+const CACHE_NAME: &str = "leptos_i18n_translations";
+const HASH_HEADER: &str = "x-leptos-i18n-hash";
+
+fn cache_url(locale: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) => format!("/__leptos_i18n/{namespace}/{locale}"),
+        None => format!("/__leptos_i18n/{locale}"),
+    }
+}
+
+async fn open_cache() -> Result<web_sys::Cache, JsValue> {
+    let window = web_sys::window().ok_or(JsValue::NULL)?;
+    let cache = JsFuture::from(window.caches()?.open(CACHE_NAME)).await?;
+    Ok(cache.unchecked_into())
+}
+
+async fn get_inner(url: &str, hash: &str) -> Result<Option<Vec<Box<str>>>, JsValue> {
+    let cache = open_cache().await?;
+    let response = JsFuture::from(cache.match_with_str(url)).await?;
+    let Ok(response) = response.dyn_into::<web_sys::Response>() else {
+        return Ok(None);
+    };
+    if response.headers().get(HASH_HEADER)?.as_deref() != Some(hash) {
+        return Ok(None);
+    }
+    let text = JsFuture::from(response.text()?).await?;
+    Ok(text
+        .as_string()
+        .and_then(|text| serde_json::from_str(&text).ok()))
+}
+
+async fn put_inner(url: &str, hash: &str, strings: &[Box<str>]) -> Result<(), JsValue> {
+    let body = serde_json::to_string(strings).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    let headers = web_sys::Headers::new()?;
+    headers.set(HASH_HEADER, hash)?;
+    let init = web_sys::ResponseInit::new();
+    init.set_headers(&headers);
+    let response = web_sys::Response::new_with_opt_str_and_init(Some(&body), &init)?;
+    let cache = open_cache().await?;
+    JsFuture::from(cache.put_with_str(url, &response)).await?;
+    Ok(())
+}
+
+/// Return the cached strings of a translation unit, if they were cached with the same hash.
+pub(crate) fn get(
+    locale: &'static str,
+    namespace: Option<&'static str>,
+    hash: &'static str,
+) -> impl Future<Output = Option<Vec<Box<str>>>> + Send + Sync + 'static {
+    // JS futures are not `Send`, run them on the local executor.
+    let (tx, rx) = futures::channel::oneshot::channel();
+    leptos::task::spawn_local(async move {
+        let strings = get_inner(&cache_url(locale, namespace), hash).await;
+        let _ = tx.send(strings.ok().flatten());
+    });
+    async move { rx.await.ok().flatten() }
+}
+
+/// Store the strings of a translation unit in the background.
+pub(crate) fn put(
+    locale: &'static str,
+    namespace: Option<&'static str>,
+    hash: &'static str,
+    strings: Vec<Box<str>>,
+) {
+    leptos::task::spawn_local(async move {
+        let _ = put_inner(&cache_url(locale, namespace), hash, &strings).await;
+    });
+}
@@ -4,8 +4,12 @@
 //! This crate provide `build.rs` utilities for the `leptos_i18n` crate.
 
 pub use datamarker::FormatterOptions;
+pub use leptos_i18n_codegen::manifest::{
+    TranslationsEncoding, TranslationsFile, TranslationsManifest,
+};
 pub use leptos_i18n_parser::parse_locales::options::{Config, FileFormat, ParseOptions, parser};
 
+use flate2::{Compression, write::GzEncoder};
 use icu_locale::LocaleFallbacker;
 use icu_provider::{DataError, DataMarkerInfo};
 use icu_provider_export::{
@@ -19,19 +23,19 @@ use leptos_i18n_parser::parse_locales::{
     locale::{BuildersKeys, Locale},
     parse_locales,
 };
+use leptos_i18n_parser::{binary, utils::content_hash};
 use std::{
     collections::HashSet,
     fmt::{Display, Write},
     fs::{File, create_dir_all},
-    io::BufWriter,
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
 };
 
 mod datamarker;
 pub mod options;
 
-use crate::options::CodegenOptions;
+use crate::options::{CodegenOptions, WriteOptions};
 
 /// Module for custom formatters.
 pub mod formatter {
@@ -62,7 +66,8 @@ pub struct TranslationsInfos {
 }
 
 impl TranslationsInfos {
-    fn parse_inner(dir_path: Option<PathBuf>, cfg: Config) -> Result<Self> {
+    fn parse_inner(dir_path: Option<PathBuf>, mut cfg: Config) -> Result<Self> {
+        leptos_i18n_codegen::load_locales::hot_reload::configure(&mut cfg);
         // We don't really care for warnings, they will already be displayed by the macro
         let parsed_locales = parse_locales(dir_path, cfg)?;
 
@@ -258,13 +263,30 @@ impl TranslationsInfos {
         mut mod_directory: PathBuf,
         options: CodegenOptions,
     ) -> Result<()> {
-        let ts = leptos_i18n_codegen::gen_code(
-            &self.parsed_locales,
-            options.crate_path.as_ref(),
-            false,
-            options.top_level_attributes.as_ref(),
-            options.gen_docs,
-        )?;
+        let ts = if options.lite {
+            // the keys skipped by the lite module are reported while generating it,
+            // so after the parsing diagnostics have usually been emitted.
+            let emitted_warnings = self.parsed_locales.diag.warnings().len();
+            let ts = leptos_i18n_codegen::lite::gen_lite_code(
+                &self.parsed_locales,
+                options.top_level_attributes.as_ref(),
+                options.translate_dyn,
+            )?;
+            for warning in &self.parsed_locales.diag.warnings()[emitted_warnings..] {
+                println!("cargo::warning={warning}");
+            }
+            ts
+        } else {
+            leptos_i18n_codegen::gen_code(
+                &self.parsed_locales,
+                options.crate_path.as_ref(),
+                false,
+                options.top_level_attributes.as_ref(),
+                options.gen_docs,
+                options.translations_manifest,
+                options.translate_dyn,
+            )?
+        };
 
         #[cfg(feature = "pretty_print")]
         let ts = {
@@ -359,10 +381,14 @@ impl<N, L> TranslationsType<N, L> {
 fn write_locales_to_dir<'a>(
     locales: impl Iterator<Item = LocaleTranslations<'a>>,
     path: &mut PathBuf,
+    namespace: Option<&str>,
+    options: &WriteOptions,
+    manifest: &mut TranslationsManifest,
 ) -> std::io::Result<()> {
     create_dir_all(&*path)?;
     for locale in locales {
-        locale.write_to_dir(path)?;
+        let file = locale.write_to_dir(path, namespace, options)?;
+        manifest.insert(namespace, locale.name, file);
     }
     Ok(())
 }
@@ -376,16 +402,30 @@ impl<
 {
     /// Write the translations in the given directory
     pub fn write_to_dir<P: Into<PathBuf>>(self, path: P) -> std::io::Result<()> {
+        self.write_to_dir_with_options(path, &WriteOptions::default())
+            .map(drop)
+    }
+
+    /// Write the translations in the given directory with options,
+    /// return the manifest of the written files.
+    pub fn write_to_dir_with_options<P: Into<PathBuf>>(
+        self,
+        path: P,
+        options: &WriteOptions,
+    ) -> std::io::Result<TranslationsManifest> {
         let mut path: PathBuf = path.into();
+        let mut manifest = TranslationsManifest::new(options.encoding);
         match self {
             TranslationsType::Namespace(namespaces) => {
                 for namespace in namespaces {
-                    namespace.write_to_dir(&mut path)?;
+                    namespace.write_to_dir(&mut path, options, &mut manifest)?;
                 }
-                Ok(())
             }
-            TranslationsType::Locale(locales) => write_locales_to_dir(locales, &mut path),
+            TranslationsType::Locale(locales) => {
+                write_locales_to_dir(locales, &mut path, None, options, &mut manifest)?
+            }
         }
+        Ok(manifest)
     }
 }
 
@@ -402,9 +442,14 @@ impl<'a, L> NamespaceTranslations<'a, L> {
 }
 
 impl<'a, L: Iterator<Item = LocaleTranslations<'a>>> NamespaceTranslations<'a, L> {
-    fn write_to_dir(self, path: &mut PathBuf) -> std::io::Result<()> {
+    fn write_to_dir(
+        self,
+        path: &mut PathBuf,
+        options: &WriteOptions,
+        manifest: &mut TranslationsManifest,
+    ) -> std::io::Result<()> {
         path.push(self.name);
-        write_locales_to_dir(self.locales, path)?;
+        write_locales_to_dir(self.locales, path, Some(self.name), options, manifest)?;
         path.pop();
         Ok(())
     }
@@ -423,17 +468,87 @@ impl<'a> LocaleTranslations<'a> {
         }
     }
 
-    fn write_to_dir(self, path: &mut PathBuf) -> std::io::Result<()> {
-        use std::io::Write;
-        path.push(self.name);
-        path.set_extension("json");
-        let mut file = File::create(&*path)?;
+    /// Encode the parsed strings of that locale.
+    pub fn encode(&self, encoding: TranslationsEncoding) -> Vec<u8> {
+        match encoding {
+            TranslationsEncoding::Json => self.translations_formatter().to_string().into_bytes(),
+            TranslationsEncoding::Binary => binary::encode_strings(self.strings),
+        }
+    }
+
+    fn write_to_dir(
+        self,
+        path: &mut PathBuf,
+        namespace: Option<&str>,
+        options: &WriteOptions,
+    ) -> std::io::Result<TranslationsFile> {
+        let content = self.encode(options.encoding);
+        let hash = options.hash_file_names.then(|| content_hash(&content));
+        let file_name = match &hash {
+            Some(hash) => format!("{}.{}.{}", self.name, hash, options.encoding.extension()),
+            None => format!("{}.{}", self.name, options.encoding.extension()),
+        };
+
+        path.push(&file_name);
+        std::fs::write(&*path, &content)?;
+        if options.gzip {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+            std::io::Write::write_all(&mut encoder, &content)?;
+            write_sibling(path, "gz", &encoder.finish()?)?;
+        }
+        if options.brotli {
+            let mut compressed = Vec::new();
+            let params = brotli::enc::BrotliEncoderParams::default();
+            brotli::BrotliCompress(&mut &*content, &mut compressed, &params)?;
+            write_sibling(path, "br", &compressed)?;
+        }
+        for compressor in &options.compressors {
+            write_sibling(
+                path,
+                compressor.extension(),
+                &compressor.compress(&content)?,
+            )?;
+        }
         path.pop();
-        let mut f = BufWriter::new(&mut file);
-        write!(f, "{}", self.translations_formatter())
+
+        let path = match namespace {
+            Some(namespace) => format!("{namespace}/{file_name}"),
+            None => file_name,
+        };
+        Ok(TranslationsFile { path, hash })
     }
 }
 
+fn write_sibling(path: &Path, extension: &str, content: &[u8]) -> std::io::Result<()> {
+    let mut file_name = path.as_os_str().to_owned();
+    file_name.push(".");
+    file_name.push(extension);
+    std::fs::write(file_name, content)
+}
+
+/// Write the manifest of the translations files as a Rust module,
+/// exposing `TRANSLATIONS_FILES` and `translations_file(locale, namespace)` to find the path of each file.
+pub fn write_manifest_module<P: AsRef<Path>>(
+    manifest: &TranslationsManifest,
+    path: P,
+) -> std::io::Result<()> {
+    let ts = manifest.to_module();
+
+    #[cfg(feature = "pretty_print")]
+    let ts = {
+        let as_file = syn::parse_quote!(#ts);
+        prettyplease::unparse(&as_file)
+    };
+
+    if let Some(parent) = path.as_ref().parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = File::create(path)?;
+
+    use std::io::Write;
+    write!(&mut file, "{ts}")
+}
+
 impl Display for TranslationsFormatter<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_char('[')?;
@@ -1,7 +1,8 @@
-//! Codegen options
+//! Codegen and translations writer options
 
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
+use leptos_i18n_codegen::manifest::{TranslationsEncoding, TranslationsManifest};
 use proc_macro2::TokenStream;
 
 /// Options for the leptos_i18n codegen
@@ -19,6 +20,17 @@ pub struct CodegenOptions<'a> {
     /// Generate docs on `Locale` enum, subkeys, namespaces keys, and interpolations to display available keys or required arguments.
     /// `true` by default
     pub gen_docs: bool,
+    /// Generate a "lite" module, that does not depend on `leptos` or `leptos_i18n`.
+    /// It only contains the `Locale` enum, the keys and the `td_string!`/`td_display!` macros returning plain values,
+    /// keys using plurals, ranges or formatters are skipped.
+    /// `false` by default
+    pub lite: bool,
+    /// Manifest of the translations files returned by `write_to_dir_with_options`,
+    /// used to resolve the `{hash}` placeholder of the `translations_uri` and decode the files.
+    pub translations_manifest: Option<&'a TranslationsManifest>,
+    /// Generate `Locale::translate_dyn`, looking up the translations by the path of their key given at runtime.
+    /// `false` by default
+    pub translate_dyn: bool,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -38,6 +50,9 @@ impl<'a> CodegenOptions<'a> {
             module_file_name: DEFAULT_FILE_NAME.as_ref(),
             crate_path: None,
             gen_docs: true,
+            lite: false,
+            translations_manifest: None,
+            translate_dyn: false,
         }
     }
 
@@ -76,4 +91,194 @@ impl<'a> CodegenOptions<'a> {
     pub fn gen_docs(self, gen_docs: bool) -> Self {
         Self { gen_docs, ..self }
     }
+
+    /// Generate a "lite" module, usable without `leptos` or `leptos_i18n`, for exemple in a backend crate or a CLI.
+    ///
+    /// The generated module has no context: the locale is given explicitly and translations are plain values.
+    /// Only literals, variables and components (rendered as HTML tags) are supported,
+    /// keys using plurals, ranges or formatters are not generated and a warning naming each of them is emitted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use leptos_i18n_build::options::CodegenOptions;
+    /// let options = CodegenOptions::new().lite(true);
+    /// ```
+    ///
+    /// Then in the crate, the `td_string!` and `td_display!` macros must be imported from the module:
+    ///
+    /// ```rust, ignore
+    /// use i18n::*;
+    ///
+    /// let greeting: String = td_string!(Locale::en, hello_name, name = "John");
+    /// println!("{}", td_display!(Locale::fr, hello_world));
+    /// ```
+    pub fn lite(self, lite: bool) -> Self {
+        Self { lite, ..self }
+    }
+
+    /// Manifest of the translations files returned by `TranslationsType::write_to_dir_with_options`.
+    ///
+    /// With the `dynamic_load` feature, the client then requests each translation unit at its hashed URL,
+    /// given by the `{hash}` placeholder of the `translations_uri`, and decodes it with the encoding it was written with.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let manifest = translations_infos
+    ///     .get_translations()
+    ///     .write_to_dir_with_options("target/i18n", &WriteOptions::new().hash_file_names(true))?;
+    ///
+    /// // with `translations_uri("/i18n/{locale}.{hash}.json")`
+    /// let options = CodegenOptions::new().translations_manifest(Some(&manifest));
+    /// translations_infos.generate_i18n_module_with_options(mod_directory, options)?;
+    /// ```
+    pub fn translations_manifest(
+        self,
+        translations_manifest: Option<&'a TranslationsManifest>,
+    ) -> Self {
+        Self {
+            translations_manifest,
+            ..self
+        }
+    }
+
+    /// Generate `Locale::translate_dyn` and `Locale::translate_dyn_with_args`,
+    /// returning the translation of a key from its path, for keys only known at runtime such as keys read from a CMS.
+    ///
+    /// The translations in the lookup table are embedded in the binary, even with the "dynamic_load" feature,
+    /// and overrides are not applied. Only keys made of strings and variables are in the table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use leptos_i18n_build::options::CodegenOptions;
+    /// let options = CodegenOptions::new().translate_dyn(true);
+    /// ```
+    ///
+    /// Then in the crate:
+    ///
+    /// ```rust, ignore
+    /// let message = Locale::fr.translate_dyn("errors.not_found");
+    /// let greeting = Locale::en.translate_dyn_with_args("greetings.hello", &[("name", "John")]);
+    /// ```
+    pub fn translate_dyn(self, translate_dyn: bool) -> Self {
+        Self {
+            translate_dyn,
+            ..self
+        }
+    }
+}
+
+type CompressFn = dyn Fn(&[u8]) -> std::io::Result<Vec<u8>> + Send + Sync;
+
+/// A compression algorithm, used to write a pre-compressed sibling of each translations file.
+#[derive(Clone)]
+pub struct Compressor {
+    extension: &'static str,
+    compress: Arc<CompressFn>,
+}
+
+impl Compressor {
+    /// Create a compressor writing its output to a file with the given extension appended, for exemple `"br"`.
+    pub fn new(
+        extension: &'static str,
+        compress: impl Fn(&[u8]) -> std::io::Result<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Compressor {
+            extension,
+            compress: Arc::new(compress),
+        }
+    }
+
+    /// The extension appended to the compressed files.
+    pub fn extension(&self) -> &'static str {
+        self.extension
+    }
+
+    /// Compress the given bytes.
+    pub fn compress(&self, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+        (self.compress)(bytes)
+    }
+}
+
+impl std::fmt::Debug for Compressor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Compressor")
+            .field("extension", &self.extension)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Options for writing the translations files
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct WriteOptions {
+    /// Add the hash of the content to the file names, `{locale}.{hash}.json` instead of `{locale}.json`,
+    /// so they can be served with immutable caching.
+    /// `false` by default
+    pub hash_file_names: bool,
+    /// Encoding of the files.
+    /// `TranslationsEncoding::Json` by default
+    pub encoding: TranslationsEncoding,
+    /// Write a gzip compressed sibling of each file, with the `.gz` extension appended.
+    /// `false` by default
+    pub gzip: bool,
+    /// Write a brotli compressed sibling of each file, with the `.br` extension appended.
+    /// `false` by default
+    pub brotli: bool,
+    /// Additional compressors, each writing a sibling of each file.
+    pub compressors: Vec<Compressor>,
+}
+
+impl WriteOptions {
+    /// Create the default options, writing plain JSON files named after the locales.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the hash of the content to the file names, `{locale}.{hash}.json` instead of `{locale}.json`.
+    ///
+    /// Use the `{hash}` placeholder in the `translations_uri` and give the returned manifest to `CodegenOptions::translations_manifest`
+    /// for the client to request the hashed files.
+    pub fn hash_file_names(self, hash_file_names: bool) -> Self {
+        Self {
+            hash_file_names,
+            ..self
+        }
+    }
+
+    /// Encoding of the files.
+    ///
+    /// `TranslationsEncoding::Binary` writes length-prefixed strings in `.bin` files,
+    /// smaller and faster to decode than JSON as no escaping is needed.
+    pub fn encoding(self, encoding: TranslationsEncoding) -> Self {
+        Self { encoding, ..self }
+    }
+
+    /// Write a gzip compressed sibling of each file, with the `.gz` extension appended.
+    pub fn gzip(self, gzip: bool) -> Self {
+        Self { gzip, ..self }
+    }
+
+    /// Write a brotli compressed sibling of each file, with the `.br` extension appended.
+    pub fn brotli(self, brotli: bool) -> Self {
+        Self { brotli, ..self }
+    }
+
+    /// Add a compressor writing a sibling of each file.
+    ///
+    /// # Example
+    ///
+    /// Write `.zst` files with the `zstd` crate:
+    ///
+    /// ```rust, ignore
+    /// # use leptos_i18n_build::options::{Compressor, WriteOptions};
+    /// let zstd = Compressor::new("zst", |bytes| zstd::encode_all(bytes, 19));
+    /// let options = WriteOptions::new().gzip(true).brotli(true).add_compressor(zstd);
+    /// ```
+    pub fn add_compressor(mut self, compressor: Compressor) -> Self {
+        self.compressors.push(compressor);
+        self
+    }
 }
@@ -8,9 +8,13 @@
 //! This crate must be used with `leptos_i18n` and should'nt be used outside of it.
 
 use leptos_i18n_parser::parse_locales::{ParsedLocales, error::Result};
+use manifest::TranslationsManifest;
 use proc_macro2::TokenStream;
 
+pub mod lite;
 pub mod load_locales;
+pub mod manifest;
+pub mod translate_dyn;
 pub mod utils;
 
 pub fn gen_code(
@@ -19,6 +23,8 @@ pub fn gen_code(
     emit_diagnostics: bool,
     top_level_attributes: Option<&TokenStream>,
     gen_docs: bool,
+    translations_manifest: Option<&TranslationsManifest>,
+    translate_dyn: bool,
 ) -> Result<TokenStream> {
     load_locales::load_locales(
         parsed_locales,
@@ -26,5 +32,7 @@ pub fn gen_code(
         emit_diagnostics,
         top_level_attributes,
         gen_docs,
+        translations_manifest,
+        translate_dyn,
     )
 }
@@ -0,0 +1,686 @@
+//! Codegen for the "lite" module: a context-free version of the `i18n` module,
+//! with no dependency on `leptos` or `leptos_i18n`, meant for backend crates and CLIs.
+//!
+//! Only the features that don't need a runtime are supported: literals, variables and components (rendered as plain tags).
+//! Keys using plurals, ranges or formatters are skipped, with a warning naming each of them.
+
+use std::collections::BTreeMap;
+
+use leptos_i18n_parser::{
+    formatters::VarBounds,
+    parse_locales::{
+        ParsedLocales,
+        error::{Diagnostics, Result, Warning},
+        locale::{BuildersKeys, DefaultedLocales, InterpolOrLit, Locale, LocaleValue},
+        parsed_value::{Attribute, AttributeValue, Literal, ParsedValue},
+    },
+    utils::{Key, KeyPath, UnwrapAt},
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+pub fn gen_lite_code(
+    parsed_locales: &ParsedLocales,
+    top_level_attributes: Option<&TokenStream>,
+    translate_dyn: bool,
+) -> Result<TokenStream> {
+    let ParsedLocales {
+        cfg,
+        builder_keys,
+        diag,
+        ..
+    } = parsed_locales;
+
+    let enum_ident = syn::Ident::new("Locale", Span::call_site());
+    let keys_ident = syn::Ident::new("I18nKeys", Span::call_site());
+
+    let locale_enum =
+        create_locale_enum(&enum_ident, &keys_ident, &cfg.locales, &cfg.default_locale);
+
+    let locale_type = match builder_keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            let namespaces_types = namespaces.iter().map(|namespace| {
+                let ns_keys = keys.get(&namespace.key).unwrap_at("gen_lite_code_1");
+                let mod_ident = format_ident!("ns_{}", namespace.key);
+                let type_ident = &namespace.key;
+                let ns_type = create_locale_type(
+                    &namespace.key.ident,
+                    &enum_ident,
+                    &namespace.locales,
+                    &ns_keys.0,
+                    &mut KeyPath::new(Some(namespace.key.clone())),
+                    diag,
+                );
+                quote! {
+                    pub mod #mod_ident {
+                        #[allow(unused)]
+                        use super::{#enum_ident, MissingArg};
+
+                        #ns_type
+                    }
+
+                    pub use #mod_ident::#type_ident;
+                }
+            });
+            let accessors = namespaces.iter().map(|namespace| {
+                let key = &namespace.key;
+                let mod_ident = format_ident!("ns_{}", namespace.key);
+                quote! {
+                    pub const fn #key(self) -> namespaces::#mod_ident::#key {
+                        namespaces::#mod_ident::#key(self.0)
+                    }
+                }
+            });
+            quote! {
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                #[allow(non_camel_case_types)]
+                pub struct #keys_ident(#enum_ident);
+
+                #[allow(non_snake_case)]
+                impl #keys_ident {
+                    #(#accessors)*
+                }
+
+                #[doc(hidden)]
+                pub mod namespaces {
+                    #[allow(unused)]
+                    use super::{#enum_ident, MissingArg};
+
+                    #(#namespaces_types)*
+                }
+            }
+        }
+        BuildersKeys::Locales { locales, keys } => create_locale_type(
+            &keys_ident,
+            &enum_ident,
+            locales,
+            &keys.0,
+            &mut KeyPath::new(None),
+            diag,
+        ),
+    };
+
+    let translate_dyn = translate_dyn
+        .then(|| crate::translate_dyn::create_translate_dyn(builder_keys, &enum_ident));
+
+    let macros = create_macros();
+
+    Ok(quote! {
+        pub mod i18n {
+            #![allow(unused_braces)]
+            #![allow(clippy::needless_borrow)]
+            #top_level_attributes
+
+            #locale_enum
+
+            #translate_dyn
+
+            /// Placeholder for arguments not yet given to a builder, `Display` is only implemented once all arguments are set.
+            #[derive(Clone, Copy, Debug)]
+            pub struct MissingArg;
+
+            #locale_type
+
+            #macros
+        }
+    })
+}
+
+fn create_locale_enum(
+    enum_ident: &syn::Ident,
+    keys_ident: &syn::Ident,
+    locales: &[Key],
+    default: &Key,
+) -> TokenStream {
+    let as_str_match_arms = locales.iter().map(|key| {
+        let name = &*key.name;
+        quote!(#enum_ident::#key => #name)
+    });
+    let from_str_match_arms = locales.iter().map(|key| {
+        let name = &*key.name;
+        quote!(#name => Ok(#enum_ident::#key))
+    });
+    let variants = locales.iter().map(|key| {
+        if key == default {
+            quote!(#[default] #key)
+        } else {
+            quote!(#key)
+        }
+    });
+
+    quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        #[allow(non_camel_case_types)]
+        pub enum #enum_ident {
+            #(#variants,)*
+        }
+
+        impl #enum_ident {
+            /// Return the name of the locale.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(#as_str_match_arms,)*
+                }
+            }
+
+            /// Return all the locales.
+            pub const fn get_all() -> &'static [Self] {
+                &[#(#enum_ident::#locales,)*]
+            }
+
+            /// Return the keys for this locale.
+            pub const fn get_keys(self) -> #keys_ident {
+                #keys_ident(self)
+            }
+        }
+
+        impl core::str::FromStr for #enum_ident {
+            type Err = ();
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                    #(#from_str_match_arms,)*
+                    _ => Err(())
+                }
+            }
+        }
+
+        impl core::fmt::Display for #enum_ident {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl AsRef<str> for #enum_ident {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+    }
+}
+
+fn create_macros() -> TokenStream {
+    quote! {
+        /// Return the translation of the given key as a value implementing `Display`.
+        ///
+        /// Arguments are given with `name = value` or just `name` if a variable of the same name is in scope.
+        /// The macro needs to be imported, for exemple with `use i18n::*`.
+        #[allow(unused_macros)]
+        macro_rules! td_display {
+            (@args $builder:expr;) => {
+                $builder
+            };
+            (@args $builder:expr; $arg:ident = $value:expr $(, $($rest:tt)*)?) => {
+                td_display!(@args $builder.$arg($value); $($($rest)*)?)
+            };
+            (@args $builder:expr; $arg:ident $(, $($rest:tt)*)?) => {
+                td_display!(@args $builder.$arg($arg); $($($rest)*)?)
+            };
+            ($locale:expr, $($key:ident).+ $(, $($args:tt)*)?) => {
+                td_display!(@args $locale.get_keys()$(.$key())+; $($($args)*)?)
+            };
+        }
+
+        /// Same as `td_display!` but return a `String`.
+        ///
+        /// The macro needs to be imported, for exemple with `use i18n::*`.
+        #[allow(unused_macros)]
+        macro_rules! td_string {
+            (@args $builder:expr;) => {
+                $builder
+            };
+            (@args $builder:expr; $arg:ident = $value:expr $(, $($rest:tt)*)?) => {
+                td_string!(@args $builder.$arg($value); $($($rest)*)?)
+            };
+            (@args $builder:expr; $arg:ident $(, $($rest:tt)*)?) => {
+                td_string!(@args $builder.$arg($arg); $($($rest)*)?)
+            };
+            ($locale:expr, $($key:ident).+ $(, $($args:tt)*)?) => {
+                ::std::string::ToString::to_string(&td_string!(@args $locale.get_keys()$(.$key())+; $($($args)*)?))
+            };
+        }
+
+        #[allow(unused_imports)]
+        pub(crate) use {td_display, td_string};
+    }
+}
+
+fn is_supported(value: &ParsedValue) -> bool {
+    match value {
+        ParsedValue::Default
+        | ParsedValue::Literal(_)
+        | ParsedValue::Dummy(_)
+        | ParsedValue::Subkeys(_) => true,
+        ParsedValue::Variable { bounds, .. } => !matches!(bounds, VarBounds::Formatted { .. }),
+        ParsedValue::Component { inner, .. } => inner.as_deref().is_none_or(is_supported),
+        ParsedValue::Bloc(values) => values.iter().all(is_supported),
+        ParsedValue::ForeignKey(foreign_key) => {
+            is_supported(foreign_key.borrow().as_inner("lite_is_supported"))
+        }
+        ParsedValue::Ranges(_) | ParsedValue::Plurals(_) => false,
+    }
+}
+
+/// Return the value for each locale, following defaulted locales.
+pub(crate) fn values_by_locale<'a>(
+    locales: &'a [Locale],
+    key: &Key,
+    defaults: &DefaultedLocales,
+) -> Vec<(&'a Key, &'a ParsedValue)> {
+    locales
+        .iter()
+        .map(|locale| {
+            let mut value = locale.keys.get(key).unwrap_at("lite_values_by_locale_1");
+            if matches!(value, ParsedValue::Default) {
+                let default_to = defaults.default_of(&locale.top_locale_name);
+                value = locales
+                    .iter()
+                    .find(|locale| &locale.top_locale_name == default_to)
+                    .and_then(|locale| locale.keys.get(key))
+                    .unwrap_at("lite_values_by_locale_2");
+            }
+            (&locale.top_locale_name, value)
+        })
+        .collect()
+}
+
+fn literal_to_tokens(lit: &Literal) -> TokenStream {
+    match lit {
+        Literal::String(s, _) => quote!(#s),
+        Literal::Signed(v) => quote!(#v),
+        Literal::Unsigned(v) => quote!(#v),
+        Literal::Float(v) => quote!(#v),
+        Literal::Bool(v) => quote!(#v),
+    }
+}
+
+fn attribute_fmt(attr: &Attribute) -> Option<TokenStream> {
+    let key = &attr.key;
+    let ts = match &attr.value {
+        None | Some(AttributeValue::Literal(Literal::Bool(true))) => {
+            let s = format!(" {}", key);
+            quote!(__formatter.write_str(#s)?;)
+        }
+        Some(AttributeValue::Literal(Literal::Bool(false))) => return None,
+        Some(AttributeValue::Literal(Literal::String(value, _))) => {
+            let s = format!(" {}=\"{}\"", key, value);
+            quote!(__formatter.write_str(#s)?;)
+        }
+        Some(AttributeValue::Literal(Literal::Signed(v))) => {
+            let s = format!(" {}=\"{}\"", key, v);
+            quote!(__formatter.write_str(#s)?;)
+        }
+        Some(AttributeValue::Literal(Literal::Unsigned(v))) => {
+            let s = format!(" {}=\"{}\"", key, v);
+            quote!(__formatter.write_str(#s)?;)
+        }
+        Some(AttributeValue::Literal(Literal::Float(v))) => {
+            let s = format!(" {}=\"{}\"", key, v);
+            quote!(__formatter.write_str(#s)?;)
+        }
+        Some(AttributeValue::Variable(var)) => {
+            let fstr = format!(" {}=\"{{}}\"", key);
+            quote!(::core::write!(__formatter, #fstr, self.#var)?;)
+        }
+    };
+    Some(ts)
+}
+
+fn value_fmt(value: &ParsedValue, tokens: &mut TokenStream) {
+    match value {
+        ParsedValue::Default => unreachable!("defaulted value should never have been rendered"),
+        ParsedValue::Subkeys(_) => unreachable!("subkeys should never have been rendered"),
+        ParsedValue::Ranges(_) | ParsedValue::Plurals(_) => {
+            unreachable!("unsupported values should have been filtered")
+        }
+        ParsedValue::Literal(Literal::String(s, _)) => {
+            tokens.extend(quote!(__formatter.write_str(#s)?;))
+        }
+        ParsedValue::Literal(lit) => {
+            let lit = literal_to_tokens(lit);
+            tokens.extend(quote!(core::fmt::Display::fmt(&#lit, __formatter)?;))
+        }
+        ParsedValue::Variable { key, .. } => {
+            tokens.extend(quote!(core::fmt::Display::fmt(&self.#key, __formatter)?;))
+        }
+        ParsedValue::Component {
+            key,
+            inner,
+            attributes,
+        } => {
+            let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            let open = format!("<{}", name);
+            let attrs = attributes.0.iter().filter_map(attribute_fmt);
+            tokens.extend(quote! {
+                __formatter.write_str(#open)?;
+                #(#attrs)*
+            });
+            match inner {
+                Some(inner) => {
+                    tokens.extend(quote!(__formatter.write_str(">")?;));
+                    value_fmt(inner, tokens);
+                    let close = format!("</{}>", name);
+                    tokens.extend(quote!(__formatter.write_str(#close)?;));
+                }
+                None => tokens.extend(quote!(__formatter.write_str(" />")?;)),
+            }
+        }
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                value_fmt(value, tokens);
+            }
+        }
+        ParsedValue::ForeignKey(foreign_key) => {
+            value_fmt(foreign_key.borrow().as_inner("lite_value_fmt"), tokens);
+        }
+        ParsedValue::Dummy(_) => {}
+    }
+}
+
+fn create_builder(
+    key: &Key,
+    enum_ident: &syn::Ident,
+    vars: &[Key],
+    values: &[(&Key, &ParsedValue)],
+) -> (syn::Ident, TokenStream) {
+    let builder_ident = format_ident!("{}_builder", key);
+    let generics = (0..vars.len())
+        .map(|i| format_ident!("__T{}", i))
+        .collect::<Vec<_>>();
+
+    let setters = vars.iter().enumerate().map(|(i, var)| {
+        let output_generics = generics
+            .iter()
+            .enumerate()
+            .map(|(j, generic)| if i == j { quote!(__V) } else { quote!(#generic) });
+        let fields = vars.iter().enumerate().map(|(j, other)| {
+            if i == j {
+                quote!(#other: __value)
+            } else {
+                quote!(#other: self.#other)
+            }
+        });
+        let setter = format_ident!("{}", var.name.strip_prefix("var_").unwrap_or(&var.name));
+        quote! {
+            pub fn #setter<__V: core::fmt::Display>(self, __value: __V) -> #builder_ident<#(#output_generics,)*> {
+                #builder_ident {
+                    __locale: self.__locale,
+                    #(#fields,)*
+                }
+            }
+        }
+    });
+
+    let match_arms = values.iter().map(|(locale, value)| {
+        let mut tokens = TokenStream::new();
+        value_fmt(value, &mut tokens);
+        quote! {
+            #enum_ident::#locale => {
+                #tokens
+            }
+        }
+    });
+
+    let ts = quote! {
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types, non_snake_case)]
+        pub struct #builder_ident<#(#generics = MissingArg,)*> {
+            __locale: #enum_ident,
+            #(#vars: #generics,)*
+        }
+
+        #[allow(non_snake_case)]
+        impl #builder_ident {
+            pub const fn new(locale: #enum_ident) -> Self {
+                #builder_ident {
+                    __locale: locale,
+                    #(#vars: MissingArg,)*
+                }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<#(#generics,)*> #builder_ident<#(#generics,)*> {
+            #(#setters)*
+        }
+
+        impl<#(#generics: core::fmt::Display,)*> core::fmt::Display for #builder_ident<#(#generics,)*> {
+            fn fmt(&self, __formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self.__locale {
+                    #(#match_arms)*
+                }
+                Ok(())
+            }
+        }
+    };
+    (builder_ident, ts)
+}
+
+fn create_locale_type(
+    type_ident: &syn::Ident,
+    enum_ident: &syn::Ident,
+    locales: &[Locale],
+    keys: &BTreeMap<Key, LocaleValue>,
+    key_path: &mut KeyPath,
+    diag: &Diagnostics,
+) -> TokenStream {
+    let mut accessors = TokenStream::new();
+    let mut builders = TokenStream::new();
+    let mut subkeys = TokenStream::new();
+
+    for (key, value) in keys {
+        match value {
+            LocaleValue::Value {
+                value: InterpolOrLit::Lit(literal_type),
+                defaults,
+            } => {
+                let literal_type = crate::load_locales::locale::LiteralType::from(*literal_type);
+                let match_arms =
+                    values_by_locale(locales, key, defaults)
+                        .into_iter()
+                        .map(|(locale, value)| {
+                            let lit = match value {
+                                ParsedValue::Literal(lit) => literal_to_tokens(lit),
+                                ParsedValue::ForeignKey(foreign_key) => {
+                                    match foreign_key.borrow().as_inner("lite_literal") {
+                                        ParsedValue::Literal(lit) => literal_to_tokens(lit),
+                                        _ => quote!(""),
+                                    }
+                                }
+                                _ => quote!(""),
+                            };
+                            quote!(#enum_ident::#locale => #lit)
+                        });
+                accessors.extend(quote! {
+                    pub const fn #key(self) -> #literal_type {
+                        match self.0 {
+                            #(#match_arms,)*
+                        }
+                    }
+                });
+            }
+            LocaleValue::Value {
+                value: InterpolOrLit::Interpol(interpolation_keys),
+                defaults,
+            } => {
+                let values = values_by_locale(locales, key, defaults);
+                if !values.iter().all(|(_, value)| is_supported(value)) {
+                    let key_path = key_path.push_key(key.clone());
+                    diag.emit_warning(Warning::LiteUnsupportedKey {
+                        key_path: key_path.clone(),
+                    });
+                    continue;
+                }
+                let vars = interpolation_keys
+                    .iter_vars()
+                    .map(|(key, _)| key)
+                    .collect::<Vec<_>>();
+                let (builder_ident, builder) = create_builder(key, enum_ident, &vars, &values);
+                builders.extend(builder);
+                accessors.extend(quote! {
+                    pub const fn #key(self) -> builders::#builder_ident {
+                        builders::#builder_ident::new(self.0)
+                    }
+                });
+            }
+            LocaleValue::Subkeys { locales, keys } => {
+                let mod_ident = format_ident!("sk_{}", key);
+                let sk_ident = format_ident!("{}_subkeys", key);
+                let mut key_path = key_path.push_key(key.clone());
+                let sk_type = create_locale_type(
+                    &sk_ident,
+                    enum_ident,
+                    locales,
+                    &keys.0,
+                    &mut key_path,
+                    diag,
+                );
+                subkeys.extend(quote! {
+                    pub mod #mod_ident {
+                        #[allow(unused)]
+                        use super::{#enum_ident, MissingArg};
+
+                        #sk_type
+                    }
+                });
+                accessors.extend(quote! {
+                    pub const fn #key(self) -> #mod_ident::#sk_ident {
+                        #mod_ident::#sk_ident(self.0)
+                    }
+                });
+            }
+        }
+    }
+
+    let builders = (!builders.is_empty()).then(|| {
+        quote! {
+            #[doc(hidden)]
+            pub mod builders {
+                #[allow(unused)]
+                use super::{#enum_ident, MissingArg};
+
+                #builders
+            }
+        }
+    });
+
+    quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types)]
+        pub struct #type_ident(pub(crate) #enum_ident);
+
+        #[allow(non_snake_case)]
+        impl #type_ident {
+            #accessors
+        }
+
+        #builders
+
+        #subkeys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use leptos_i18n_parser::parse_locales::{
+        locale::{BuildersKeysInner, InterpolationKeys},
+        parsed_value::Attributes,
+        plurals::{PluralRuleType, Plurals},
+    };
+
+    use super::*;
+
+    fn key(name: &str) -> Key {
+        Key::new(name).unwrap()
+    }
+
+    fn make_locale(keys: BTreeMap<Key, ParsedValue>) -> Locale {
+        Locale {
+            top_locale_name: key("en"),
+            name: key("en"),
+            keys,
+            markdown_keys: BTreeSet::new(),
+            strings: vec![],
+            key_strings: BTreeMap::new(),
+            top_locale_string_count: 0,
+        }
+    }
+
+    fn interpol_value() -> LocaleValue {
+        LocaleValue::Value {
+            value: InterpolOrLit::Interpol(InterpolationKeys::default()),
+            defaults: DefaultedLocales::new(key("en")),
+        }
+    }
+
+    #[test]
+    fn test_unsupported_keys_are_reported() {
+        let plural = ParsedValue::Plurals(Plurals {
+            rule_type: PluralRuleType::Cardinal,
+            count_key: key("count"),
+            other: Box::new(ParsedValue::Literal(Literal::String(
+                "items".to_string(),
+                0,
+            ))),
+            forms: BTreeMap::new(),
+        });
+        let component = ParsedValue::Component {
+            key: key("b"),
+            inner: Some(Box::new(ParsedValue::Literal(Literal::String(
+                "bold".to_string(),
+                1,
+            )))),
+            attributes: Attributes(vec![]),
+        };
+        let subkeys_locale = make_locale(BTreeMap::from([
+            (key("items"), plural.clone()),
+            (key("bold"), component),
+        ]));
+        let locale = make_locale(BTreeMap::from([
+            (key("items"), plural),
+            (key("sub"), ParsedValue::Subkeys(None)),
+        ]));
+        let keys = BTreeMap::from([
+            (key("items"), interpol_value()),
+            (
+                key("sub"),
+                LocaleValue::Subkeys {
+                    locales: vec![subkeys_locale],
+                    keys: BuildersKeysInner(BTreeMap::from([
+                        (key("items"), interpol_value()),
+                        (key("bold"), interpol_value()),
+                    ])),
+                },
+            ),
+        ]);
+
+        let diag = Diagnostics::new();
+        let ts = create_locale_type(
+            &format_ident!("I18nKeys"),
+            &format_ident!("Locale"),
+            &[locale],
+            &keys,
+            &mut KeyPath::new(Some(key("ns"))),
+            &diag,
+        )
+        .to_string();
+
+        let warnings = diag
+            .warnings()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            [
+                "Key \"ns::items\" uses plurals, ranges or formatters, it is not generated in the lite module",
+                "Key \"ns::sub.items\" uses plurals, ranges or formatters, it is not generated in the lite module",
+            ]
+        );
+        // the skipped keys have no accessor, the supported ones are kept
+        assert!(!ts.contains("fn items"));
+        assert!(ts.contains("fn bold"));
+        assert!(ts.contains("fn sub"));
+    }
+}
@@ -0,0 +1,122 @@
+//! Code generated for the "hot_reload" feature.
+//!
+//! The server needs to know where the locales files are and how to parse them again,
+//! and which strings each key uses to map the new strings to the indices of the compiled tables.
+
+use leptos_i18n_parser::parse_locales::{
+    ParsedLocales,
+    locale::{BuildersKeys, Locale},
+    options::{Config, FileFormat},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+
+/// Adjust the parsing options for the "hot_reload" feature, before parsing the translations.
+pub fn configure(cfg: &mut Config) {
+    if super::HOT_RELOAD {
+        // each string must belong to a single key to be replaced at runtime.
+        cfg.options.deduplicate_strings = false;
+    }
+}
+
+fn create_unit(namespace: Option<&str>, locale: &Locale) -> TokenStream {
+    let locale_name = &*locale.top_locale_name.name;
+    let namespace = match namespace {
+        Some(namespace) => quote!(Some(#namespace)),
+        None => quote!(None),
+    };
+    let strings_count = locale.top_locale_string_count;
+    let keys = locale.key_strings.iter().map(|(path, indices)| {
+        let path = &**path;
+        quote!((#path, &[#(#indices,)*]))
+    });
+    quote! {
+        l_i18n_crate::__private::hot_reload::HotReloadUnit {
+            locale: #locale_name,
+            namespace: #namespace,
+            strings_count: #strings_count,
+            keys: &[#(#keys,)*],
+        }
+    }
+}
+
+pub fn create_hot_reload_config(
+    parsed_locales: &ParsedLocales,
+    enum_ident: &syn::Ident,
+) -> TokenStream {
+    let ParsedLocales {
+        cfg,
+        builder_keys,
+        tracked_files,
+        ..
+    } = parsed_locales;
+
+    // translations declared with `declare_locales!` have no files to watch.
+    let locales_dir = tracked_files.as_ref().map(|_| {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        manifest_dir
+            .join(&cfg.locales_path)
+            .to_string_lossy()
+            .into_owned()
+    });
+    let locales_dir = match locales_dir {
+        Some(dir) => quote!(Some(#dir)),
+        None => quote!(None),
+    };
+
+    let file_format = match cfg.options.file_format {
+        FileFormat::Json => quote!(Some("json")),
+        FileFormat::Json5 => quote!(Some("json5")),
+        FileFormat::Yaml => quote!(Some("yaml")),
+        FileFormat::Toml => quote!(Some("toml")),
+        _ => quote!(None),
+    };
+
+    let default_locale = &*cfg.default_locale.name;
+    let locales = cfg.locales.iter().map(|locale| &*locale.name);
+    let namespaces = cfg.namespaces.iter().map(|namespace| &*namespace.name);
+    let extensions = cfg.extensions.iter().map(|(locale, inherit_from)| {
+        let locale = &*locale.name;
+        let inherit_from = &*inherit_from.name;
+        quote!((#locale, #inherit_from))
+    });
+    let runtime_fallback = cfg.options.runtime_fallback;
+
+    let units: Vec<TokenStream> = match builder_keys {
+        BuildersKeys::Locales { locales, .. } => locales
+            .iter()
+            .map(|locale| create_unit(None, locale))
+            .collect(),
+        BuildersKeys::NameSpaces { namespaces, .. } => namespaces
+            .iter()
+            .flat_map(|namespace| {
+                namespace
+                    .locales
+                    .iter()
+                    .map(|locale| create_unit(Some(&namespace.key.name), locale))
+            })
+            .collect(),
+    };
+
+    quote! {
+        impl #enum_ident {
+            #[doc(hidden)]
+            pub fn __hot_reload_config__() -> &'static l_i18n_crate::__private::hot_reload::HotReloadConfig {
+                static CONFIG: l_i18n_crate::__private::hot_reload::HotReloadConfig = l_i18n_crate::__private::hot_reload::HotReloadConfig {
+                    locales_dir: #locales_dir,
+                    file_format: #file_format,
+                    default_locale: #default_locale,
+                    locales: &[#(#locales,)*],
+                    namespaces: &[#(#namespaces,)*],
+                    extensions: &[#(#extensions,)*],
+                    runtime_fallback: #runtime_fallback,
+                    units: &[#(#units,)*],
+                };
+                &CONFIG
+            }
+        }
+    }
+}
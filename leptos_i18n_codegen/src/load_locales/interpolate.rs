@@ -3,7 +3,7 @@ use std::collections::{BTreeMap, BTreeSet};
 use leptos_i18n_parser::{
     formatters::VarBounds,
     parse_locales::{
-        locale::{DefaultedLocales, InterpolationKeys, Locale},
+        locale::{AttributeType, ComponentAttributes, DefaultedLocales, InterpolationKeys, Locale},
         options::ParseOptions,
         parsed_value::ParsedValue,
     },
@@ -12,14 +12,299 @@ use leptos_i18n_parser::{
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 
-use super::parsed_value;
+use super::{locale::LiteralType, parsed_value};
 // use super::parsed_value::InterpolationKeys;
 // use super::parsed_value::RangeOrPlural;
-use super::{parsed_value::TRANSLATIONS_KEY, ranges::RangeType, strings_accessor_method_name};
+use super::{
+    DISPLAY_MODE, HOT_RELOAD, MARKDOWN, OVERRIDES, parsed_value::TRANSLATIONS_KEY,
+    ranges::RangeType, strings_accessor_method_name,
+};
 use crate::utils::EitherOfWrapper;
 
 pub const LOCALE_FIELD_KEY: &str = "_locale";
 
+/// The attributes structs of the components of a key.
+pub type ComponentsAttrs = BTreeMap<Key, ComponentAttrs>;
+
+/// Struct generated for the attributes the translations give to a component.
+///
+/// An attribute missing from some uses of the component is an `Option`,
+/// an attribute bound to a variable has the type of that variable.
+pub struct ComponentAttrs {
+    pub ident: syn::Ident,
+    pub generics: Vec<syn::Ident>,
+    pub fields: Vec<AttributeField>,
+    /// Attributes with different types across the translations can't be typed, using the struct then fails the build.
+    pub conflicts: Option<String>,
+}
+
+pub struct AttributeField {
+    pub name: String,
+    pub ident: syn::Ident,
+    pub ty: TokenStream,
+    pub optional: bool,
+}
+
+impl ComponentAttrs {
+    fn new(
+        builder_ident: &syn::Ident,
+        comp: &Key,
+        attributes: Option<&ComponentAttributes>,
+        path: &str,
+    ) -> Self {
+        let comp_ident = comp.ident.to_string();
+        let comp_ident = comp_ident.strip_prefix("comp_").unwrap_or(&comp_ident);
+        let ident = format_ident!("{}_{}_attrs", builder_ident, comp_ident);
+        let mut generics = BTreeSet::new();
+        let fields = attributes
+            .into_iter()
+            .flat_map(ComponentAttributes::iter)
+            .map(|(name, attr_type, optional)| {
+                let ty = match attr_type {
+                    AttributeType::Literal(lit_type) => {
+                        LiteralType::from(*lit_type).to_token_stream()
+                    }
+                    AttributeType::Variable(key) => {
+                        let generic = format_ident!("__{}__", key);
+                        let ty = generic.to_token_stream();
+                        generics.insert(generic);
+                        ty
+                    }
+                };
+                let ty = if optional {
+                    quote!(core::option::Option<#ty>)
+                } else {
+                    ty
+                };
+                AttributeField {
+                    name: name.to_owned(),
+                    ident: Self::field_ident(name),
+                    ty,
+                    optional,
+                }
+            })
+            .collect();
+        let comp_name = comp.name.strip_prefix("comp_").unwrap_or(&comp.name);
+        let conflicts = attributes
+            .into_iter()
+            .flat_map(ComponentAttributes::conflicts)
+            .map(|(name, type1, type2)| {
+                format!("attribute {name:?} of component <{comp_name}> at key \"{path}\" is given the type {type1} but also {type2}")
+            })
+            .reduce(|acc, conflict| format!("{acc}, {conflict}"))
+            .map(|conflicts| format!("Conflicting types across translations: {conflicts}."));
+        ComponentAttrs {
+            ident,
+            generics: generics.into_iter().collect(),
+            fields,
+            conflicts,
+        }
+    }
+
+    /// Attributes names can be keywords, such as `type` or `for`.
+    fn field_ident(name: &str) -> syn::Ident {
+        syn::parse_str(name)
+            .or_else(|_| syn::parse_str(&format!("r#{name}")))
+            .unwrap_or_else(|_| format_ident!("{}_", name))
+    }
+
+    fn as_type(&self) -> TokenStream {
+        let ComponentAttrs {
+            ident, generics, ..
+        } = self;
+        quote!(#ident<#(#generics,)*>)
+    }
+
+    fn definition(&self, comp: &Key, path: &str) -> TokenStream {
+        let ComponentAttrs {
+            ident,
+            generics,
+            fields,
+            ..
+        } = self;
+        let comp_name = comp.name.strip_prefix("comp_").unwrap_or(&comp.name);
+        let doc = format!(
+            "Attributes given to the `<{comp_name}>` component by the translations of `{path}`."
+        );
+        let fields = fields.iter().map(
+            |AttributeField {
+                 name, ident, ty, ..
+             }| {
+                let doc = format!("`{name}` attribute");
+                quote! {
+                    #[doc = #doc]
+                    pub #ident: #ty
+                }
+            },
+        );
+        quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types, non_snake_case)]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct #ident<#(#generics,)*> {
+                #(#fields,)*
+            }
+        }
+    }
+}
+
+/// Variables and components the runtime overrides of an interpolated key can use.
+///
+/// Overrides can't give attributes, so only the components whose attributes are all optional can be used,
+/// and only the variables rendered on their own, with the first of their formatters.
+pub struct OverrideArgs {
+    pub vars: Vec<(Key, VarBounds)>,
+    pub comps: Vec<Key>,
+    pub comps_self_closed: Vec<Key>,
+}
+
+impl OverrideArgs {
+    /// `None` if the key can't be overridden because it uses plurals or ranges.
+    pub fn new(keys: &InterpolationKeys) -> Option<Self> {
+        let mut vars = Vec::new();
+        for (key, infos) in keys.iter_vars() {
+            if infos.range_count.is_some() {
+                return None;
+            }
+            let bounds = infos.bounds.iter().find(|bounds| {
+                !matches!(
+                    bounds,
+                    VarBounds::AttributeValue | VarBounds::NestedAttributeValue | VarBounds::Dummy
+                )
+            });
+            if let Some(bounds) = bounds {
+                vars.push((key, bounds.clone()));
+            }
+        }
+        let no_required_attrs = |key: &Key| {
+            keys.get_comp_attributes(key).is_none_or(|attrs| {
+                attrs.conflicts().next().is_none() && attrs.iter().all(|(_, _, optional)| optional)
+            })
+        };
+        let comps = keys.iter_comps().filter(no_required_attrs).collect();
+        let comps_self_closed = keys
+            .iter_comps_self_closed()
+            .filter(no_required_attrs)
+            .collect();
+        Some(OverrideArgs {
+            vars,
+            comps,
+            comps_self_closed,
+        })
+    }
+
+    /// Name of the variable or component in the overrides, without the "var_" or "comp_" prefix of its key.
+    pub fn name(key: &Key) -> &str {
+        key.name
+            .strip_prefix("var_")
+            .or_else(|| key.name.strip_prefix("comp_"))
+            .unwrap_or(&key.name)
+    }
+
+    /// Variables and components closures given to the template of an override to render it.
+    fn view_closures(
+        &self,
+        locale_field: &Key,
+        comps_attrs: &ComponentsAttrs,
+    ) -> (TokenStream, TokenStream) {
+        let captured_vars = self.vars.iter().map(|(key, _)| key);
+        let var_arms = self.vars.iter().map(|(key, bounds)| {
+            let name = Self::name(key);
+            let ts = bounds.var_to_view(&key.ident, &locale_field.ident);
+            quote! {
+                #name => l_i18n_crate::__private::overrides::any_view({
+                    let #key = core::clone::Clone::clone(&#key);
+                    #ts
+                })
+            }
+        });
+        let var_closure = quote! {{
+            #(let #captured_vars = core::clone::Clone::clone(&#captured_vars);)*
+            move |__name: &str| match __name {
+                #(#var_arms,)*
+                _ => l_i18n_crate::__private::overrides::any_view(()),
+            }
+        }};
+
+        let typed_attrs = |key: &Key| {
+            let attrs = comps_attrs
+                .get(key)
+                .unwrap_at("OverrideArgs::view_closures_1");
+            let attrs_ty = attrs.as_type();
+            let ident = &attrs.ident;
+            let fields = attrs.fields.iter().map(|field| &field.ident);
+            quote! {
+                let __typed_attrs: #attrs_ty = #ident { #(#fields: core::option::Option::None,)* };
+            }
+        };
+        let comp_arms = self.comps.iter().map(|key| {
+            let name = Self::name(key);
+            let typed_attrs = typed_attrs(key);
+            quote! {
+                (#name, core::option::Option::Some(__children)) => {
+                    #typed_attrs
+                    l_i18n_crate::__private::overrides::any_view(l_i18n_crate::__private::InterpolateComp::to_view(&#key, __children, &::std::vec::Vec::new(), &__typed_attrs))
+                }
+            }
+        });
+        let comp_self_closed_arms = self.comps_self_closed.iter().map(|key| {
+            let name = Self::name(key);
+            let typed_attrs = typed_attrs(key);
+            quote! {
+                (#name, core::option::Option::None) => {
+                    #typed_attrs
+                    l_i18n_crate::__private::overrides::any_view(l_i18n_crate::__private::InterpolateCompSelfClosed::to_view(&#key, &::std::vec::Vec::new(), &__typed_attrs))
+                }
+            }
+        });
+        let captured_comps = self.comps.iter().chain(&self.comps_self_closed);
+        let comp_closure = quote! {{
+            #(let #captured_comps = core::clone::Clone::clone(&#captured_comps);)*
+            move |__name: &str, __children: core::option::Option<l_i18n_crate::reexports::leptos::children::ChildrenFn>| match (__name, __children) {
+                #(#comp_arms,)*
+                #(#comp_self_closed_arms,)*
+                _ => l_i18n_crate::__private::overrides::any_view(()),
+            }
+        }};
+        (var_closure, comp_closure)
+    }
+
+    /// Variables and components closures given to the template of an override to format it.
+    fn fmt_closures(&self, locale_field: &Key) -> (TokenStream, TokenStream) {
+        let var_arms = self.vars.iter().map(|(key, bounds)| {
+            let name = Self::name(key);
+            let ts = bounds.var_fmt(key, locale_field);
+            quote!(#name => #ts)
+        });
+        let var_closure = quote! {
+            |__name, __formatter| match __name {
+                #(#var_arms,)*
+                _ => Ok(()),
+            }
+        };
+        let comp_arms = self.comps.iter().map(|key| {
+            let name = Self::name(key);
+            quote! {
+                (#name, core::option::Option::Some(__children)) => l_i18n_crate::display::DisplayComponent::fmt(#key, __formatter, __children, l_i18n_crate::display::Attributes(&[]))
+            }
+        });
+        let comp_self_closed_arms = self.comps_self_closed.iter().map(|key| {
+            let name = Self::name(key);
+            quote! {
+                (#name, core::option::Option::None) => l_i18n_crate::display::DisplayComponent::fmt_self_closing(#key, __formatter, l_i18n_crate::display::Attributes(&[]))
+            }
+        });
+        let comp_closure = quote! {
+            |__name, __formatter, __children| match (__name, __children) {
+                #(#comp_arms,)*
+                #(#comp_self_closed_arms,)*
+                _ => Ok(()),
+            }
+        };
+        (var_closure, comp_closure)
+    }
+}
+
 #[derive(Clone)]
 enum EitherIter<A, B> {
     Iter1(A),
@@ -83,6 +368,9 @@ enum VarOrComp {
     Comp {
         into_view: syn::Ident,
         self_closed: bool,
+        attrs_ty: TokenStream,
+        /// The component comes from Markdown and can be left to `MarkdownComp`.
+        markdown: bool,
     },
 }
 
@@ -135,12 +423,14 @@ impl Field {
             VarOrComp::Comp {
                 into_view,
                 self_closed,
+                attrs_ty,
+                ..
             } => {
                 let ts = [
                     if *self_closed {
-                        quote!(#generic: l_i18n_crate::__private::InterpolateCompSelfClosed<#into_view>)
+                        quote!(#generic: l_i18n_crate::__private::InterpolateCompSelfClosed<#into_view, #attrs_ty>)
                     } else {
-                        quote!(#generic: l_i18n_crate::__private::InterpolateComp<#into_view>)
+                        quote!(#generic: l_i18n_crate::__private::InterpolateComp<#into_view, #attrs_ty>)
                     },
                     quote!(#into_view: l_i18n_crate::__private::AttributesArgMarker),
                 ];
@@ -156,10 +446,7 @@ impl Field {
                 let ts = Self::get_fmt_var_generics(generic, bounds, *plural);
                 EitherIter::Iter1(ts.into_iter())
             }
-            VarOrComp::Comp {
-                into_view,
-                self_closed: _,
-            } => {
+            VarOrComp::Comp { into_view, .. } => {
                 let ts = [
                     quote!(#generic: l_i18n_crate::display::DisplayComponent<#into_view>),
                     quote!(#into_view),
@@ -215,7 +502,22 @@ impl Field {
 }
 
 impl Interpolation {
-    fn make_fields(keys: &InterpolationKeys) -> Vec<Field> {
+    fn make_comps_attrs(
+        builder_ident: &syn::Ident,
+        keys: &InterpolationKeys,
+        path: &str,
+    ) -> ComponentsAttrs {
+        keys.iter_comps()
+            .chain(keys.iter_comps_self_closed())
+            .map(|key| {
+                let attrs =
+                    ComponentAttrs::new(builder_ident, &key, keys.get_comp_attributes(&key), path);
+                (key, attrs)
+            })
+            .collect()
+    }
+
+    fn make_fields(keys: &InterpolationKeys, comps_attrs: &ComponentsAttrs) -> Vec<Field> {
         let vars = keys.iter_vars().map(|(key, infos)| {
             let mut bounds = infos.bounds.iter().cloned().collect::<Vec<_>>();
             bounds.sort(); // the sort is to have consistent codegen
@@ -231,11 +533,20 @@ impl Interpolation {
             }
         });
 
+        let attrs_ty = |key: &Key| {
+            comps_attrs
+                .get(key)
+                .map(ComponentAttrs::as_type)
+                .unwrap_at("Interpolation::make_fields_1")
+        };
+
         let comps = keys.iter_comps().map(|key| {
             let into_view = format_ident!("__into_view_{}__", key);
             let var_or_comp = VarOrComp::Comp {
                 into_view,
                 self_closed: false,
+                attrs_ty: attrs_ty(&key),
+                markdown: keys.is_markdown_comp(&key),
             };
             let generic = format_ident!("__{}__", key);
             Field {
@@ -250,6 +561,8 @@ impl Interpolation {
             let var_or_comp = VarOrComp::Comp {
                 into_view,
                 self_closed: true,
+                attrs_ty: attrs_ty(&key),
+                markdown: keys.is_markdown_comp(&key),
             };
             let generic = format_ident!("__{}__", key);
             Field {
@@ -302,7 +615,21 @@ impl Interpolation {
 
         let computed_defaults = defaults.compute();
 
-        let fields = Self::make_fields(keys);
+        let report_fallback = super::report_fallback(
+            enum_ident,
+            &computed_defaults,
+            &key_path.to_string_with_key(key),
+            quote!(#locale_field),
+        );
+
+        let path = key_path.to_string_with_key(key);
+        let comps_attrs = Self::make_comps_attrs(&ident, keys, &path);
+        let fields = Self::make_fields(keys, &comps_attrs);
+        let override_args = if OVERRIDES {
+            OverrideArgs::new(keys)
+        } else {
+            None
+        };
 
         let type_def = Self::create_types(
             &ident,
@@ -313,6 +640,10 @@ impl Interpolation {
             &fields,
         );
 
+        let attrs_types = comps_attrs
+            .iter()
+            .map(|(comp, attrs)| attrs.definition(comp, &path));
+
         let dummy_impl = Self::dummy_impl(
             &ident,
             &dummy_ident,
@@ -321,6 +652,7 @@ impl Interpolation {
             &locale_field,
             &into_view_field,
             &fields,
+            &comps_attrs,
             options,
         );
 
@@ -334,11 +666,20 @@ impl Interpolation {
             key_path,
             locale_type_ident,
             &computed_defaults,
+            report_fallback.as_ref(),
+            &comps_attrs,
+            override_args.as_ref(),
             options,
         );
 
         let debug_impl = Self::debug_impl(&builder_name, &ident, &fields);
 
+        let fill_markdown_impl = if MARKDOWN {
+            Self::fill_markdown_impl(&typed_builder_name, &fields)
+        } else {
+            quote!()
+        };
+
         let (display_impl, builder_display) = if options.interpolate_display {
             let display_impl = Self::display_impl(
                 key,
@@ -348,8 +689,11 @@ impl Interpolation {
                 &locale_field,
                 &fields,
                 &locales,
+                key_path,
                 locale_type_ident,
                 &computed_defaults,
+                report_fallback.as_ref(),
+                override_args.as_ref(),
             );
             let builder_display = Self::builder_string_build_fns(
                 enum_ident,
@@ -365,10 +709,14 @@ impl Interpolation {
         let imp = quote! {
             #type_def
 
+            #(#attrs_types)*
+
             #into_view_impl
 
             #debug_impl
 
+            #fill_markdown_impl
+
             #display_impl
 
             #builder_display
@@ -526,6 +874,49 @@ impl Interpolation {
         }
     }
 
+    /// Same as `display_builder` with the components set to be rendered with the `HtmlComponents` registry, for `t_html!`.
+    fn html_builder_fn(
+        ident: &syn::Ident,
+        enum_ident: &syn::Ident,
+        typed_builder_name: &syn::Ident,
+        locale_field: &Key,
+        into_view_field: &Key,
+        fields: &[Field],
+    ) -> TokenStream {
+        let is_comp = |field: &&Field| matches!(field.var_or_comp, VarOrComp::Comp { .. });
+        let left_generics = fields
+            .iter()
+            .filter(|field| !is_comp(field))
+            .flat_map(Field::as_fmt_bounded_generic);
+        let right_generics = fields.iter().flat_map(|field| {
+            if is_comp(&field) {
+                vec![quote!(l_i18n_crate::html::HtmlComp), quote!(())]
+            } else {
+                field.as_string_right_generics().collect()
+            }
+        });
+        let builder_marker = fields.iter().map(|field| {
+            if is_comp(&field) {
+                quote!((l_i18n_crate::html::HtmlComp,))
+            } else {
+                quote!(())
+            }
+        });
+        let into_views = fields.iter().filter(is_comp).map(|_| quote!(()));
+        let set_comps = fields.iter().filter(is_comp).map(|field| {
+            let key = &field.key;
+            let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            quote!(.#key(l_i18n_crate::html::HtmlComp::new(#name)))
+        });
+
+        quote! {
+            #[allow(non_camel_case_types)]
+            pub fn html_builder<#(#left_generics,)*>(self) -> #typed_builder_name<#(#right_generics,)* ((#enum_ident,), (core::marker::PhantomData<(#(#into_views,)*)>,), #(#builder_marker,)*)> {
+                #ident::builder().#locale_field(self.#locale_field).#into_view_field(core::marker::PhantomData)#(#set_comps)*
+            }
+        }
+    }
+
     fn dummy_impl(
         ident: &syn::Ident,
         dummy_ident: &syn::Ident,
@@ -534,6 +925,7 @@ impl Interpolation {
         locale_field: &Key,
         into_view_field: &Key,
         fields: &[Field],
+        comps_attrs: &ComponentsAttrs,
         options: &ParseOptions,
     ) -> TokenStream {
         let left_generics = fields.iter().flat_map(Field::as_bounded_generic);
@@ -543,20 +935,37 @@ impl Interpolation {
         let empty_builder_marker = fields.iter().map(|_| quote!(()));
 
         let display_builder_fn = if options.interpolate_display {
-            Self::display_builder_fn(
+            let display_builder_fn = Self::display_builder_fn(
                 ident,
                 enum_ident,
                 typed_builder_name,
                 locale_field,
                 into_view_field,
                 fields,
-            )
+            );
+            let html_builder_fn = Self::html_builder_fn(
+                ident,
+                enum_ident,
+                typed_builder_name,
+                locale_field,
+                into_view_field,
+                fields,
+            );
+            quote! {
+                #display_builder_fn
+
+                #html_builder_fn
+            }
         } else {
             quote!()
         };
 
         let into_views = fields.iter().filter_map(Field::as_into_view_generic);
 
+        let typed_comps_fns = comps_attrs
+            .iter()
+            .map(|(comp, attrs)| Self::typed_comp_fn(comp, attrs));
+
         let string_builder_trait_impl = if options.interpolate_display {
             quote! {
                 impl l_i18n_crate::__private::InterpolationStringBuilder for #dummy_ident {}
@@ -579,12 +988,47 @@ impl Interpolation {
                 }
 
                 #display_builder_fn
+
+                #(#typed_comps_fns)*
             }
 
             #string_builder_trait_impl
         }
     }
 
+    /// Used by the `t!` macro for closures of the form `|attrs, children| ...`, so they are typed with the attributes struct of the component.
+    fn typed_comp_fn(comp: &Key, attrs: &ComponentAttrs) -> TokenStream {
+        let fn_ident = format_ident!("__typed_{}", comp);
+        let generics = &attrs.generics;
+        let attrs_ty = attrs.as_type();
+        // The deprecation shows the conflict in `cargo check`, the panic is only evaluated
+        // when the function is instantiated, failing the build of the crates using the typed form.
+        let (deprecated, conflict_error) = attrs
+            .conflicts
+            .as_ref()
+            .map(|conflicts| {
+                (
+                    quote!(#[deprecated(note = #conflicts)]),
+                    quote!(const { panic!("{}", #conflicts) };),
+                )
+            })
+            .unzip();
+        quote! {
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #deprecated
+            pub fn #fn_ident<#(#generics,)* __O, __F>(self, f: __F) -> l_i18n_crate::__private::TypedComp<__F>
+            where
+                __F: Fn(#attrs_ty, l_i18n_crate::reexports::leptos::children::ChildrenFn) -> __O + Clone + Send + Sync + 'static,
+                __O: l_i18n_crate::reexports::leptos::IntoView + 'static,
+            {
+                #conflict_error
+                let _ = self;
+                l_i18n_crate::__private::TypedComp::new(f)
+            }
+        }
+    }
+
     fn create_types(
         ident: &syn::Ident,
         dummy_ident: &syn::Ident,
@@ -624,6 +1068,107 @@ impl Interpolation {
         }
     }
 
+    /// `fill_markdown` sets the Markdown components not given to `t!` to `MarkdownComp`,
+    /// each of them is filled by a `FillMarkdownComp<I>` impl, `I` being the index of the field.
+    fn fill_markdown_impl(typed_builder_name: &syn::Ident, fields: &[Field]) -> TokenStream {
+        let raw_generics = fields
+            .iter()
+            .flat_map(Field::as_right_generics)
+            .collect::<Vec<_>>();
+        // the typed builder tracks the set fields in a tuple, the locale and the into views marker come first.
+        let states = (0..fields.len() + 2)
+            .map(|i| format_ident!("__state_{}__", i))
+            .collect::<Vec<_>>();
+
+        let markdown_fields = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                matches!(field.var_or_comp, VarOrComp::Comp { markdown: true, .. })
+            })
+            .collect::<Vec<_>>();
+
+        let fill_comps_impls = markdown_fields.iter().map(|(index, field)| {
+            let Field { key, generic, .. } = field;
+            let tag = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            let markdown_comp = quote!(l_i18n_crate::__private::MarkdownComp);
+            let state_index = index + 2;
+            let other_generics = raw_generics.iter().filter(|g| **g != generic);
+            let filled_generics = raw_generics
+                .iter()
+                .map(|g| {
+                    if *g == generic {
+                        markdown_comp.clone()
+                    } else {
+                        g.to_token_stream()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let other_states = states
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != state_index)
+                .map(|(_, state)| state)
+                .collect::<Vec<_>>();
+            let with_state = |state: TokenStream| {
+                states.iter().enumerate().map(move |(i, s)| {
+                    if i == state_index {
+                        state.clone()
+                    } else {
+                        s.to_token_stream()
+                    }
+                })
+            };
+            let unset_states = with_state(quote!(()));
+            let filled_states = with_state(quote!((#markdown_comp,)));
+            let set_states = with_state(quote!((#generic,)));
+            quote! {
+                #[allow(non_camel_case_types)]
+                impl<#(#other_generics,)* #(#other_states,)*> l_i18n_crate::__private::FillMarkdownComp<#index> for #typed_builder_name<#(#filled_generics,)* (#(#unset_states,)*)> {
+                    type Output = #typed_builder_name<#(#filled_generics,)* (#(#filled_states,)*)>;
+
+                    fn fill(self) -> Self::Output {
+                        self.#key(l_i18n_crate::__private::MarkdownComp::new(#tag))
+                    }
+                }
+
+                #[allow(non_camel_case_types)]
+                impl<#(#raw_generics,)* #(#other_states,)*> l_i18n_crate::__private::FillMarkdownComp<#index> for #typed_builder_name<#(#raw_generics,)* (#(#set_states,)*)> {
+                    type Output = Self;
+
+                    fn fill(self) -> Self::Output {
+                        self
+                    }
+                }
+            }
+        });
+
+        let mut output = quote!(Self);
+        let mut body = quote!(self);
+        let mut bounds = vec![];
+        for (index, _) in &markdown_fields {
+            bounds.push(quote!(#output: l_i18n_crate::__private::FillMarkdownComp<#index>));
+            output = quote!(<#output as l_i18n_crate::__private::FillMarkdownComp<#index>>::Output);
+            body = quote!(l_i18n_crate::__private::FillMarkdownComp::<#index>::fill(#body));
+        }
+
+        quote! {
+            #(#fill_comps_impls)*
+
+            #[allow(non_camel_case_types)]
+            impl<#(#raw_generics,)* __fields__> #typed_builder_name<#(#raw_generics,)* __fields__> {
+                #[doc(hidden)]
+                #[inline]
+                pub fn fill_markdown(self) -> #output
+                where
+                    #(#bounds,)*
+                {
+                    #body
+                }
+            }
+        }
+    }
+
     fn debug_impl(builder_name: &str, ident: &syn::Ident, fields: &[Field]) -> TokenStream {
         let left_generics = fields.iter().flat_map(Field::as_right_generics);
 
@@ -647,8 +1192,11 @@ impl Interpolation {
         locale_field: &Key,
         fields: &[Field],
         locales: &[&Locale],
+        key_path: &KeyPath,
         locale_type_ident: &syn::Ident,
         defaults: &BTreeMap<Key, BTreeSet<Key>>,
+        report_fallback: Option<&TokenStream>,
+        override_args: Option<&OverrideArgs>,
     ) -> TokenStream {
         let left_generics = fields.iter().flat_map(Field::as_fmt_bounded_generic);
 
@@ -662,6 +1210,12 @@ impl Interpolation {
         let fields_key = fields.iter().map(|f| &f.key);
 
         let destructure = quote!(let #ident { #(#fields_key,)* #locale_field, .. } = &self.1;);
+        let report_fallback = report_fallback.map(|report| {
+            quote! {
+                let #locale_field = *#locale_field;
+                #report
+            }
+        });
 
         let translations_holder_enum_ident = format_ident!("{}Enum", display_struct_ident);
         let locales_impls = Self::create_locale_string_impl(
@@ -674,13 +1228,44 @@ impl Interpolation {
 
         let str_name = display_struct_ident.to_string();
 
+        let lookup_override = override_args.map(|override_args| {
+            let path = key_path.to_string_with_key(key);
+            let (var_closure, comp_closure) = override_args.fmt_closures(locale_field);
+            quote! {
+                if let Some(__template) = l_i18n_crate::__private::overrides::lookup_template(self.1.#locale_field, #path) {
+                    return __template.fmt(__formatter, #var_closure, #comp_closure);
+                }
+            }
+        });
+
+        let fmt_body = quote! {
+            #destructure
+            #report_fallback
+            #lookup_override
+            match self.0 {
+                #(
+                    #locales_impls,
+                )*
+            }
+        };
+        let fmt_body = if DISPLAY_MODE {
+            let path = key_path.to_string_with_key(key);
+            quote! {
+                l_i18n_crate::__private::display_mode::fmt_message(__formatter, #path, |__formatter| {
+                    #fmt_body
+                })
+            }
+        } else {
+            fmt_body
+        };
+
         let translations_holder_enum = if cfg!(all(feature = "dynamic_load", not(feature = "ssr")))
         {
             let translations_holder_enum_ident_variants = locales.iter().map(|locale| {
                 let top_locale = &locale.top_locale_name.ident;
                 let strings_count = locale.top_locale_string_count;
                 quote! {
-                    #top_locale(&'static [Box<str>; #strings_count])
+                    #top_locale(l_i18n_crate::__private::KeyTranslations<#strings_count>)
                 }
             });
 
@@ -701,6 +1286,7 @@ impl Interpolation {
         };
 
         let new_fn = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+            let path = key_path.to_string_with_key(key);
             let match_arms = locales.iter().map(|locale| {
                 let defaulted = defaults.get(&locale.top_locale_name).map(|defaulted_locales| {
                     defaulted_locales.iter().map(|key| {
@@ -712,7 +1298,7 @@ impl Interpolation {
                 let strings_count = locale.top_locale_string_count;
                 quote! {
                     #enum_ident::#top_locale #defaulted => {
-                        let translations: &'static [Box<str>; #strings_count] = super::#locale_type_ident::#string_accessor().await;
+                        let translations = l_i18n_crate::__private::KeyTranslations::<#strings_count>::new(super::#locale_type_ident::#string_accessor().await, #path);
                         #translations_holder_enum_ident::#top_locale(translations)
                     }
                 }
@@ -752,12 +1338,7 @@ impl Interpolation {
             #[allow(non_camel_case_types)]
             impl<#(#left_generics,)*> ::core::fmt::Display for #display_struct_ident<#(#right_generics,)*> {
                 fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    #destructure
-                    match self.0 {
-                        #(
-                            #locales_impls,
-                        )*
-                    }
+                    #fmt_body
                 }
             }
 
@@ -778,6 +1359,9 @@ impl Interpolation {
         key_path: &KeyPath,
         locale_type_ident: &syn::Ident,
         defaults: &BTreeMap<Key, BTreeSet<Key>>,
+        report_fallback: Option<&TokenStream>,
+        comps_attrs: &ComponentsAttrs,
+        override_args: Option<&OverrideArgs>,
         options: &ParseOptions,
     ) -> TokenStream {
         let left_generics = fields.iter().flat_map(Field::as_bounded_generic);
@@ -801,33 +1385,70 @@ impl Interpolation {
 
         let destructure = quote!(let Self { #(#fields_key,)* #locale_field, .. } = self;);
 
-        let locales_impls =
-            Self::create_locale_impl(key, enum_ident, locales, locale_type_ident, defaults);
+        let locales_impls = Self::create_locale_impl(
+            key,
+            enum_ident,
+            locales,
+            locale_type_ident,
+            defaults,
+            comps_attrs,
+            key_path.to_string_with_key(key),
+        );
+        let body = quote! {
+            match #locale_field {
+                #(
+                    #locales_impls,
+                )*
+            }
+        };
+        // the override of the key, if any, is rendered instead of the translations.
+        let body = match override_args {
+            Some(override_args) => {
+                let path = key_path.to_string_with_key(key);
+                let (var_closure, comp_closure) =
+                    override_args.view_closures(locale_field, comps_attrs);
+                quote! {
+                    match l_i18n_crate::__private::overrides::lookup_template(#locale_field, #path) {
+                        Some(__template) => l_i18n_crate::reexports::leptos::either::Either::Left(__template.into_view(#var_closure, #comp_closure)),
+                        None => l_i18n_crate::reexports::leptos::either::Either::Right(#body),
+                    }
+                }
+            }
+            None => body,
+        };
+        let body = quote! {
+            #report_fallback
+            #body
+        };
         if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+            let body = if DISPLAY_MODE {
+                let path = key_path.to_string_with_key(key);
+                quote!(l_i18n_crate::__private::display_mode::view_fut(#path, async move { #body }).await)
+            } else {
+                body
+            };
             quote! {
                 #[allow(non_camel_case_types)]
                 impl<#(#left_generics,)*> #ident<#(#right_generics,)*> {
                     pub async fn into_view(self) -> impl l_i18n_crate::reexports::leptos::IntoView + Clone + 'static {
                         #destructure
-                        match #locale_field {
-                            #(
-                                #locales_impls,
-                            )*
-                        }
+                        #body
                     }
                 }
             }
         } else {
+            let body = if DISPLAY_MODE {
+                let path = key_path.to_string_with_key(key);
+                quote!(l_i18n_crate::__private::display_mode::view(#path, move || { #body }))
+            } else {
+                body
+            };
             quote! {
                 #[allow(non_camel_case_types)]
                 impl<#(#left_generics,)*> #ident<#(#right_generics,)*> {
                     pub fn into_view(self) -> impl l_i18n_crate::reexports::leptos::IntoView + Clone + 'static {
                         #destructure
-                        match #locale_field {
-                            #(
-                                #locales_impls,
-                            )*
-                        }
+                        #body
                     }
                 }
             }
@@ -840,6 +1461,8 @@ impl Interpolation {
         locales: &'a [&Locale],
         locale_type_ident: &'a syn::Ident,
         defaults: &'a BTreeMap<Key, BTreeSet<Key>>,
+        comps_attrs: &'a ComponentsAttrs,
+        path: String,
     ) -> impl Iterator<Item = TokenStream> + 'a {
         let either_wrapper = EitherOfWrapper::new(locales.len());
         locales
@@ -854,7 +1477,11 @@ impl Interpolation {
                     .get(key)
                     .unwrap_at("create_locale_impl_1");
 
-                let value = parsed_value::to_token_stream(value, locale.top_locale_string_count);
+                let value = parsed_value::to_token_stream(
+                    value,
+                    locale.top_locale_string_count,
+                    comps_attrs,
+                );
 
                 let wrapped_value = either_wrapper.wrap(i, value);
 
@@ -873,11 +1500,11 @@ impl Interpolation {
                     quote!{
                         #enum_ident::#locale_key #defaulted => {
                             #[allow(unused)]
-                            let #translations_key: &'static [Box<str>; #strings_count] = super::#locale_type_ident::#string_accessor().await;
+                            let #translations_key = l_i18n_crate::__private::KeyTranslations::<#strings_count>::new(super::#locale_type_ident::#string_accessor().await, #path);
                             #wrapped_value
                         }
                     }
-                } else if cfg!(all(feature = "dynamic_load", feature = "ssr")) {
+                } else if HOT_RELOAD || cfg!(all(feature = "dynamic_load", feature = "ssr")) {
                     quote!{
                         #enum_ident::#locale_key #defaulted => {
                             #[allow(unused)]
@@ -931,7 +1558,7 @@ impl Interpolation {
                         #value
                     }
                 }
-            } else if cfg!(all(feature = "dynamic_load", feature = "ssr")) {
+            } else if HOT_RELOAD || cfg!(all(feature = "dynamic_load", feature = "ssr")) {
                 quote!{
                     #enum_ident::#locale_key #defaulted => {
                         #[allow(unused)]
@@ -939,7 +1566,7 @@ impl Interpolation {
                         #value
                     }
                 }
-            }else {
+            } else {
                 quote!{
                     #enum_ident::#locale_key #defaulted => {
                         #[allow(unused)]
@@ -1,22 +1,32 @@
-use std::{collections::BTreeMap, ops::Not};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Not,
+    rc::Rc,
+};
 
+pub mod hot_reload;
 pub mod interpolate;
 pub mod locale;
 pub mod parsed_value;
 pub mod plurals;
 pub mod ranges;
 
+use crate::manifest::{TranslationsEncoding, TranslationsManifest};
 use interpolate::Interpolation;
 use leptos_i18n_parser::{
+    binary,
     parse_locales::{
         ParsedLocales,
         error::{Error, Result},
-        locale::{BuildersKeys, BuildersKeysInner, InterpolOrLit, Locale, LocaleValue, Namespace},
+        locale::{
+            BuildersKeys, BuildersKeysInner, DefaultedLocales, InterpolOrLit, Locale, LocaleValue,
+            Namespace,
+        },
         options::ParseOptions,
         parsed_value::ParsedValue,
     },
     utils::{
-        UnwrapAt,
+        UnwrapAt, content_hash,
         key::{Key, KeyPath},
     },
 };
@@ -25,12 +35,57 @@ use parsed_value::TRANSLATIONS_KEY;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 
+/// The client fetches the translations as static files at `translations_uri` instead of calling a server function.
+const FETCH_STATIC_FILES: bool = cfg!(all(
+    feature = "dynamic_load",
+    any(
+        feature = "csr",
+        all(feature = "static_translations", not(feature = "ssr"))
+    )
+));
+
+/// The strings tables can be replaced at runtime, so they can't be read in a const context.
+pub(crate) const HOT_RELOAD: bool =
+    cfg!(all(feature = "hot_reload", not(feature = "dynamic_load")));
+
+/// Rendering a key defaulted to another locale is reported to the `I18nContext::on_fallback` hooks.
+const TRACK_FALLBACKS: bool = cfg!(feature = "track_fallbacks");
+
+/// The translations can be shown as their keys or pseudo-localized at runtime, see `I18nContext::set_display_mode`.
+pub(crate) const DISPLAY_MODE: bool = cfg!(feature = "display_mode");
+
+/// The translations can be overridden at runtime, see `OverrideArgs` for the interpolated keys.
+pub(crate) const OVERRIDES: bool = cfg!(feature = "translation_overrides");
+
+/// The Markdown components not given to `t!` are rendered as the HTML element they are named after, see `MarkdownComp`.
+pub(crate) const MARKDOWN: bool = cfg!(feature = "markdown");
+
+/// The value of literal keys can change at runtime. Their accessors stay `const` and return a `LitResolver`,
+/// which gives the value from the locale files in a const context and makes the runtime lookups when the value is used.
+const RUNTIME_LITERALS: bool = cfg!(all(
+    not(feature = "dynamic_load"),
+    any(
+        feature = "translation_overrides",
+        feature = "hot_reload",
+        feature = "track_fallbacks",
+        feature = "display_mode"
+    )
+));
+
+/// A server function serving the translations is generated.
+const HAS_SERVER_FN: bool = cfg!(all(
+    feature = "dynamic_load",
+    not(any(feature = "csr", feature = "static_translations"))
+));
+
 pub fn load_locales(
     parsed_locales: &ParsedLocales,
     crate_path: Option<&syn::Path>,
     emit_diagnostics: bool,
     top_level_attributes: Option<&TokenStream>,
     gen_docs: bool,
+    translations_manifest: Option<&TranslationsManifest>,
+    translate_dyn: bool,
 ) -> Result<TokenStream> {
     let default_crate_path = syn::Path::from(syn::Ident::new("leptos_i18n", Span::call_site()));
     let crate_path = crate_path.unwrap_or(&default_crate_path);
@@ -42,10 +97,25 @@ pub fn load_locales(
         ..
     } = parsed_locales;
 
-    if cfg!(all(feature = "csr", feature = "dynamic_load")) && cfg.translations_uri.is_none() {
+    if cfg!(all(
+        feature = "dynamic_load",
+        any(feature = "csr", feature = "static_translations")
+    )) && cfg.translations_uri.is_none()
+    {
         return Err(Error::MissingTranslationsURI.into());
     }
 
+    if FETCH_STATIC_FILES
+        && cfg
+            .translations_uri
+            .as_deref()
+            .is_some_and(|uri| uri.contains("{hash}"))
+        && translations_manifest
+            .is_none_or(|manifest| manifest.iter().any(|(_, _, file)| file.hash.is_none()))
+    {
+        return Err(Error::MissingTranslationsHashes.into());
+    }
+
     let deprecated_ranges = if diag.has_ranges() {
         Some(quote! {
             mod __warn_deprecated_ranges {
@@ -71,6 +141,7 @@ pub fn load_locales(
         &enum_ident,
         &translation_unit_enum_ident,
         cfg.translations_uri.as_deref(),
+        translations_manifest,
         &cfg.options,
         gen_docs,
     );
@@ -82,7 +153,12 @@ pub fn load_locales(
         &cfg.locales,
         gen_docs,
     )?;
+    let translate_dyn = translate_dyn
+        .then(|| crate::translate_dyn::create_translate_dyn(builder_keys, &enum_ident));
+    let hot_reload_config =
+        HOT_RELOAD.then(|| hot_reload::create_hot_reload_config(parsed_locales, &enum_ident));
     let scopes_mod = create_scopes_module(builder_keys);
+    let key_handles_mod = create_key_handles_module(builder_keys, &enum_ident, gen_docs);
 
     let mut macros_reexport = vec![
         quote!(t),
@@ -98,6 +174,11 @@ pub fn load_locales(
         quote!(tu_display),
         quote!(td_string),
         quote!(td_display),
+        quote!(t_html),
+        quote!(tu_html),
+        quote!(td_html),
+        quote!(td_entries),
+        quote!(t_key),
     ];
 
     let providers = if cfg!(feature = "islands") {
@@ -116,6 +197,12 @@ pub fn load_locales(
                 /// If the "dir" attribute should be set on the root `<html>` element. (default to true)
                 #[prop(optional)]
                 set_dir_attr_on_html: Option<bool>,
+                /// If a `<meta http-equiv="content-language">` tag with the current locale should be added to the `<head>`. (default to false)
+                #[prop(optional)]
+                set_content_language_meta: Option<bool>,
+                /// If `og:locale` and `og:locale:alternate` meta tags should be added to the `<head>`. (default to false)
+                #[prop(optional)]
+                set_og_locale_meta: Option<bool>,
                 /// Enable the use of a cookie to save the choosen locale (default to true).
                 /// Does nothing without the "cookie" feature
                 #[prop(optional)]
@@ -128,6 +215,8 @@ pub fn load_locales(
                 l_i18n_crate::context::provide_i18n_context_component_island::<#enum_ident>(
                     set_lang_attr_on_html,
                     set_dir_attr_on_html,
+                    set_content_language_meta,
+                    set_og_locale_meta,
                     enable_cookie,
                     cookie_name,
                     children
@@ -157,6 +246,18 @@ pub fn load_locales(
             }
         }
     } else {
+        let (loading_options_prop, loading_options_arg) = if cfg!(feature = "dynamic_load") {
+            (
+                quote! {
+                    /// How the translations are loaded, see `leptos_i18n::loading`.
+                    #[prop(optional)]
+                    loading_options: Option<l_i18n_crate::loading::LoadingOptions>,
+                },
+                quote!(loading_options,),
+            )
+        } else {
+            (quote!(), quote!())
+        };
         quote! {
             use leptos::prelude::TypedChildren;
 
@@ -170,6 +271,12 @@ pub fn load_locales(
                 /// If the "dir" attribute should be set on the root `<html>` element. (default to true)
                 #[prop(optional)]
                 set_dir_attr_on_html: Option<bool>,
+                /// If a `<meta http-equiv="content-language">` tag with the current locale should be added to the `<head>`. (default to false)
+                #[prop(optional)]
+                set_content_language_meta: Option<bool>,
+                /// If `og:locale` and `og:locale:alternate` meta tags should be added to the `<head>`. (default to false)
+                #[prop(optional)]
+                set_og_locale_meta: Option<bool>,
                 /// Enable the use of a cookie to save the choosen locale (default to true).
                 /// Does nothing without the "cookie" feature
                 #[prop(optional)]
@@ -183,15 +290,27 @@ pub fn load_locales(
                 /// Options for getting the Accept-Language header, see `leptos_use::UseLocalesOptions`.
                 #[prop(optional)]
                 ssr_lang_header_getter: Option<UseLocalesOptions>,
+                /// Ordered list of detectors used to find the initial locale, see `leptos_i18n::detectors`.
+                #[prop(optional)]
+                detectors: Option<LocaleDetectors<#enum_ident>>,
+                /// Where to save the locale, see `leptos_i18n::storage`. (default to a cookie)
+                #[prop(optional)]
+                storage: Option<LocaleStorage<#enum_ident>>,
+                #loading_options_prop
                 children: TypedChildren<Chil>
             ) -> impl IntoView {
                 l_i18n_crate::context::provide_i18n_context_component::<#enum_ident, Chil>(
                     set_lang_attr_on_html,
                     set_dir_attr_on_html,
+                    set_content_language_meta,
+                    set_og_locale_meta,
                     enable_cookie,
                     cookie_name,
                     cookie_options,
                     ssr_lang_header_getter,
+                    detectors,
+                    storage,
+                    #loading_options_arg
                     children
                 )
             }
@@ -242,10 +361,16 @@ pub fn load_locales(
 
             #locale_enum
 
+            #translate_dyn
+
+            #hot_reload_config
+
             #locale_type
 
             #scopes_mod
 
+            #key_handles_mod
+
             #[inline]
             #[track_caller]
             pub fn use_i18n() -> l_i18n_crate::I18nContext<#enum_ident> {
@@ -274,6 +399,10 @@ pub fn load_locales(
                 use std::borrow::Cow;
                 #[allow(unused_imports)]
                 use l_i18n_crate::context::{CookieOptions, UseLocalesOptions};
+                #[allow(unused_imports)]
+                use l_i18n_crate::detectors::LocaleDetectors;
+                #[allow(unused_imports)]
+                use l_i18n_crate::storage::LocaleStorage;
 
                 #providers
             }
@@ -333,7 +462,7 @@ fn create_locales_enum(
         .map(|(variant, constant)| quote!(#enum_ident::#variant => &#constant))
         .collect::<Vec<_>>();
 
-    let server_fn_mod = if cfg!(all(feature = "dynamic_load", not(feature = "csr"))) {
+    let server_fn_mod = if HAS_SERVER_FN {
         quote! {
             mod server_fn {
                 #[allow(unused_imports)]
@@ -348,7 +477,7 @@ fn create_locales_enum(
                 }
             }
         }
-    } else if cfg!(all(feature = "dynamic_load", feature = "csr")) {
+    } else if FETCH_STATIC_FILES {
         quote! {
             mod server_fn {
                 #[allow(unused_imports)]
@@ -360,11 +489,25 @@ fn create_locales_enum(
                 }
             }
         }
+    } else if cfg!(feature = "dynamic_load") {
+        // static translations on the server, the client fetches the files so no server function is needed.
+        quote! {
+            mod server_fn {
+                #[allow(unused_imports)]
+                use super::{l_i18n_crate, #enum_ident, #keys_ident, #translation_unit_enum_ident};
+                use l_i18n_crate::reexports::leptos::server_fn::ServerFnError;
+
+                pub async fn i18n_request_translations(locale: #enum_ident, translations_id: #translation_unit_enum_ident) -> Result<l_i18n_crate::__private::fetch_translations::LocaleServerFnOutput, ServerFnError> {
+                    let strings = #keys_ident::__i18n_request_translations__(locale, translations_id);
+                    Ok(l_i18n_crate::__private::fetch_translations::LocaleServerFnOutput::new(strings))
+                }
+            }
+        }
     } else {
         quote!()
     };
 
-    let server_fn_type = if cfg!(all(feature = "dynamic_load", not(feature = "csr"))) {
+    let server_fn_type = if HAS_SERVER_FN {
         quote!(
             type ServerFn = server_fn::I18nRequestTranslationsServerFn;
         )
@@ -394,6 +537,84 @@ fn create_locales_enum(
     } else {
         quote!()
     };
+
+    let translation_keys = if cfg!(feature = "translation_overrides") {
+        let mut entries = Vec::new();
+        match keys {
+            BuildersKeys::NameSpaces { keys, .. } => {
+                for (namespace, keys) in keys {
+                    let mut key_path = KeyPath::new(Some(namespace.clone()));
+                    collect_translation_keys(&keys.0, &mut key_path, &mut entries);
+                }
+            }
+            BuildersKeys::Locales { keys, .. } => {
+                collect_translation_keys(&keys.0, &mut KeyPath::new(None), &mut entries);
+            }
+        }
+        let entries = entries.iter().map(|(path, args)| {
+            let interpolated = args.is_some();
+            let names = |keys: &mut dyn Iterator<Item = &Key>| {
+                keys.map(interpolate::OverrideArgs::name)
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            };
+            let (variables, components, self_closed_components) = args
+                .as_ref()
+                .map(|args| {
+                    (
+                        names(&mut args.vars.iter().map(|(key, _)| key)),
+                        names(&mut args.comps.iter()),
+                        names(&mut args.comps_self_closed.iter()),
+                    )
+                })
+                .unwrap_or_default();
+            quote! {
+                l_i18n_crate::overrides::OverridableKey {
+                    path: #path,
+                    interpolated: #interpolated,
+                    variables: &[#(#variables,)*],
+                    components: &[#(#components,)*],
+                    self_closed_components: &[#(#self_closed_components,)*],
+                }
+            }
+        });
+        quote! {
+            fn translation_keys() -> &'static [l_i18n_crate::overrides::OverridableKey] {
+                &[#(#entries,)*]
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let defaulted_keys = if TRACK_FALLBACKS {
+        let mut defaulted_keys = BTreeMap::new();
+        match keys {
+            BuildersKeys::NameSpaces { keys, .. } => {
+                for (namespace, keys) in keys {
+                    let mut key_path = KeyPath::new(Some(namespace.clone()));
+                    collect_defaulted_keys(&keys.0, &mut key_path, &mut defaulted_keys);
+                }
+            }
+            BuildersKeys::Locales { keys, .. } => {
+                collect_defaulted_keys(&keys.0, &mut KeyPath::new(None), &mut defaulted_keys);
+            }
+        }
+        let match_arms = locales.iter().map(|locale| {
+            let paths = defaulted_keys.get(locale).into_iter().flatten();
+            quote!(#enum_ident::#locale => &[#(#paths,)*])
+        });
+        quote! {
+            fn defaulted_keys(self) -> &'static [&'static str] {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let ld = icu_locale::LocaleDirectionality::new_common();
 
     let locids = locales
@@ -532,6 +753,10 @@ fn create_locales_enum(
             #request_translations
 
             #init_translations
+
+            #translation_keys
+
+            #defaulted_keys
         }
 
         impl core::str::FromStr for #enum_ident {
@@ -628,10 +853,20 @@ impl<'a> Subkeys<'a> {
     }
 }
 
+/// Hash of the strings of a translation unit, used to invalidate the translations cached by the client.
+fn hash_strings(strings: &[Rc<str>]) -> String {
+    content_hash(&binary::encode_strings(strings))
+}
+
 fn strings_accessor_method_name(locale: &Locale) -> Ident {
     format_ident!("__get_{}_translations__", locale.top_locale_name)
 }
 
+/// With the "hot_reload" feature, name of the const accessor to the strings of the locale files at compile time.
+fn static_strings_accessor_method_name(locale: &Locale) -> Ident {
+    format_ident!("__get_{}_static_translations__", locale.top_locale_name)
+}
+
 fn create_scopes_module(keys: &BuildersKeys) -> TokenStream {
     match keys {
         BuildersKeys::NameSpaces { keys, .. } => {
@@ -712,6 +947,278 @@ fn create_scopes_module_inner(
         })
 }
 
+fn create_key_handles_module(
+    keys: &BuildersKeys,
+    enum_ident: &syn::Ident,
+    gen_docs: bool,
+) -> TokenStream {
+    let inner = match keys {
+        BuildersKeys::NameSpaces { keys, .. } => keys
+            .iter()
+            .map(|(namespace, keys)| {
+                let mut key_path = KeyPath::new(Some(namespace.clone()));
+                let inner = create_key_handles_module_inner(
+                    &keys.0,
+                    enum_ident,
+                    &mut key_path,
+                    &[namespace],
+                    gen_docs,
+                );
+                quote! {
+                    pub mod #namespace {
+                        #[allow(unused)]
+                        use super::{#enum_ident, l_i18n_crate};
+
+                        #inner
+                    }
+                }
+            })
+            .collect(),
+        BuildersKeys::Locales { keys, .. } => create_key_handles_module_inner(
+            &keys.0,
+            enum_ident,
+            &mut KeyPath::new(None),
+            &[],
+            gen_docs,
+        ),
+    };
+
+    quote! {
+        /// Handles to the plain string keys, usable as values with `t_key!`.
+        pub mod keys {
+            #[allow(unused)]
+            use super::{#enum_ident, l_i18n_crate};
+
+            #inner
+        }
+    }
+}
+
+fn create_key_handles_module_inner(
+    keys: &BTreeMap<Key, LocaleValue>,
+    enum_ident: &syn::Ident,
+    key_path: &mut KeyPath,
+    accessors: &[&Key],
+    gen_docs: bool,
+) -> TokenStream {
+    let mut ts = TokenStream::new();
+    for (key, value) in keys {
+        match value {
+            LocaleValue::Value {
+                value: InterpolOrLit::Lit(t),
+                ..
+            } if LiteralType::from(*t) == LiteralType::String => {
+                let path = key_path.to_string_with_key(key);
+                let docs = gen_docs.then(|| {
+                    let docs = format!("Handle to the `{}` key.", path);
+                    quote!(#[doc = #docs])
+                });
+                let translate = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+                    quote! {
+                        #[allow(clippy::manual_async_fn)]
+                        fn translate(self, locale: #enum_ident) -> impl core::future::Future<Output = &'static str> {
+                            async move {
+                                #enum_ident::get_keys_const(locale) #(.#accessors())* .#key().inner().await
+                            }
+                        }
+                    }
+                } else if RUNTIME_LITERALS {
+                    quote! {
+                        fn translate(self, locale: #enum_ident) -> &'static str {
+                            #enum_ident::get_keys_const(locale) #(.#accessors())* .#key().build_string()
+                        }
+                    }
+                } else {
+                    quote! {
+                        fn translate(self, locale: #enum_ident) -> &'static str {
+                            #enum_ident::get_keys_const(locale) #(.#accessors())* .#key().inner()
+                        }
+                    }
+                };
+                ts.extend(quote! {
+                    #docs
+                    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+                    #[allow(non_camel_case_types)]
+                    pub struct #key;
+
+                    impl l_i18n_crate::KeyHandle for #key {
+                        type Locale = #enum_ident;
+                        const PATH: &'static str = #path;
+
+                        #translate
+                    }
+                });
+            }
+            LocaleValue::Subkeys { keys, .. } => {
+                let mut pushed_key = key_path.push_key(key.clone());
+                let accessors = accessors
+                    .iter()
+                    .copied()
+                    .chain(Some(key))
+                    .collect::<Vec<_>>();
+                let inner = create_key_handles_module_inner(
+                    &keys.0,
+                    enum_ident,
+                    &mut pushed_key,
+                    &accessors,
+                    gen_docs,
+                );
+                ts.extend(quote! {
+                    #[allow(non_snake_case)]
+                    pub mod #key {
+                        #[allow(unused)]
+                        use super::{#enum_ident, l_i18n_crate};
+
+                        #inner
+                    }
+                });
+            }
+            LocaleValue::Value { .. } => {}
+        }
+    }
+    ts
+}
+
+/// Collect the path of the keys that can be overridden, with the variables and components the overrides of the interpolated ones can use.
+fn collect_translation_keys(
+    keys: &BTreeMap<Key, LocaleValue>,
+    key_path: &mut KeyPath,
+    entries: &mut Vec<(String, Option<interpolate::OverrideArgs>)>,
+) {
+    for (key, value) in keys {
+        match value {
+            LocaleValue::Value {
+                value: InterpolOrLit::Lit(t),
+                ..
+            } if LiteralType::from(*t) == LiteralType::String => {
+                entries.push((key_path.to_string_with_key(key), None));
+            }
+            LocaleValue::Value {
+                value: InterpolOrLit::Interpol(interpolation_keys),
+                ..
+            } => {
+                if let Some(args) = interpolate::OverrideArgs::new(interpolation_keys) {
+                    entries.push((key_path.to_string_with_key(key), Some(args)));
+                }
+            }
+            LocaleValue::Subkeys { keys, .. } => {
+                let mut pushed_key = key_path.push_key(key.clone());
+                collect_translation_keys(&keys.0, &mut pushed_key, entries);
+            }
+            LocaleValue::Value { .. } => {}
+        }
+    }
+}
+
+/// Report the rendering of a key to the fallback hooks when `locale` is one of the locales using the value of another locale.
+pub(crate) fn report_fallback(
+    enum_ident: &syn::Ident,
+    defaults: &BTreeMap<Key, BTreeSet<Key>>,
+    path: &str,
+    locale: TokenStream,
+) -> Option<TokenStream> {
+    if !TRACK_FALLBACKS || defaults.is_empty() {
+        return None;
+    }
+    let defaulted = defaults.values().flatten();
+    Some(quote! {
+        l_i18n_crate::__private::fallbacks::report(#locale, #path, &[#(#enum_ident::#defaulted,)*]);
+    })
+}
+
+fn collect_defaulted_keys(
+    keys: &BTreeMap<Key, LocaleValue>,
+    key_path: &mut KeyPath,
+    defaulted_keys: &mut BTreeMap<Key, Vec<String>>,
+) {
+    for (key, value) in keys {
+        match value {
+            LocaleValue::Value { defaults, .. } => {
+                for locale in defaults.compute().into_values().flatten() {
+                    defaulted_keys
+                        .entry(locale)
+                        .or_default()
+                        .push(key_path.to_string_with_key(key));
+                }
+            }
+            LocaleValue::Subkeys { keys, .. } => {
+                let mut pushed_key = key_path.push_key(key.clone());
+                collect_defaulted_keys(&keys.0, &mut pushed_key, defaulted_keys);
+            }
+        }
+    }
+}
+
+fn create_entries_iter(
+    enum_ident: &syn::Ident,
+    literal_keys: &[(&Key, LiteralType, &DefaultedLocales)],
+) -> TokenStream {
+    let string_keys = literal_keys
+        .iter()
+        .filter(|(_, literal_type, _)| *literal_type == LiteralType::String)
+        .map(|(key, _, _)| *key)
+        .collect::<Vec<_>>();
+
+    let (value_type, into_value) = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        (
+            quote!(
+                l_i18n_crate::__private::LitWrapperFut<
+                    l_i18n_crate::__private::BoxedLitFut<&'static str>,
+                >
+            ),
+            quote!(.boxed()),
+        )
+    } else if cfg!(feature = "dynamic_load") {
+        (
+            quote!(
+                l_i18n_crate::__private::LitWrapperFut<
+                    l_i18n_crate::__private::LitWrapper<&'static str>,
+                >
+            ),
+            quote!(),
+        )
+    } else if RUNTIME_LITERALS {
+        (
+            quote!(l_i18n_crate::__private::LitResolver<&'static str, #enum_ident>),
+            quote!(),
+        )
+    } else {
+        (
+            quote!(l_i18n_crate::__private::LitWrapper<&'static str>),
+            quote!(),
+        )
+    };
+
+    let iter = if string_keys.is_empty() {
+        quote!(core::iter::empty())
+    } else {
+        let keys_count = string_keys.len();
+        let names = string_keys.iter().map(|key| &*key.name);
+        let arms = string_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| quote!(#i => self.#key() #into_value,));
+        quote! {
+            const KEYS: [&str; #keys_count] = [#(#names,)*];
+            KEYS.into_iter().enumerate().map(move |(i, key)| {
+                let value: #value_type = match i {
+                    #(#arms)*
+                    _ => unreachable!(),
+                };
+                (key, value)
+            })
+        }
+    };
+
+    // prefixed so it can't clash with a key, use `td_entries!` to reach it.
+    quote! {
+        #[doc(hidden)]
+        pub fn __entries(self) -> impl Iterator<Item = (&'static str, #value_type)> + Clone {
+            #iter
+        }
+    }
+}
+
 fn create_locale_type_inner<const IS_TOP: bool>(
     type_ident: &syn::Ident,
     parent_ident: Option<&syn::Ident>,
@@ -722,6 +1229,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
     key_path: &mut KeyPath,
     namespace_name: Option<&str>,
     translations_uri: Option<&str>,
+    translations_manifest: Option<&TranslationsManifest>,
     options: &ParseOptions,
     docs: &TokenStream,
     gen_docs: bool,
@@ -768,6 +1276,34 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                     }
                 }
             } else {
+                // with the "translation_overrides" feature, string keys first look for a runtime override,
+                // and with the "display_mode" feature they return their key in the `DisplayMode::Keys` mode.
+                let is_string = *literal_type == LiteralType::String;
+                let path = key_path.to_string_with_key(key);
+                let locale = if RUNTIME_LITERALS {
+                    quote!(__locale)
+                } else {
+                    quote!(self.0)
+                };
+                let lookup_override = match (DISPLAY_MODE && is_string, cfg!(feature = "translation_overrides") && is_string) {
+                    (true, true) => Some(quote! {
+                        let overridden = l_i18n_crate::__private::display_mode::key(#path)
+                            .or_else(|| l_i18n_crate::__private::overrides::lookup(#locale, #path));
+                    }),
+                    (true, false) => Some(quote! {
+                        let overridden = l_i18n_crate::__private::display_mode::key(#path);
+                    }),
+                    (false, true) => Some(quote! {
+                        let overridden = l_i18n_crate::__private::overrides::lookup(#locale, #path);
+                    }),
+                    (false, false) => None,
+                };
+                let report_fallback = report_fallback(
+                    enum_ident,
+                    &computed_defaults,
+                    &path,
+                    locale,
+                );
                 let match_arms = locales.iter().filter_map(|locale| {
                     let lit = locale
                         .keys
@@ -783,14 +1319,23 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                             quote!(| #enum_ident::#key)
                         }).collect::<TokenStream>()
                     });
-                    let lit = parsed_value::to_token_stream(lit, locale.top_locale_string_count);
-                    let ts = if *literal_type == LiteralType::String {
+                    let lit = parsed_value::to_token_stream(
+                        lit,
+                        locale.top_locale_string_count,
+                        &Default::default(),
+                    );
+                    let lit = if DISPLAY_MODE && is_string {
+                        quote!(l_i18n_crate::__private::display_mode::message(#lit))
+                    } else {
+                        lit
+                    };
+                    let ts = if is_string {
                         let strings_count = locale.top_locale_string_count;
                         if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
                             quote! {
                                 #enum_ident::#ident #defaulted => {
                                     #[allow(unused)]
-                                    let #translations_key: &'static [Box<str>; #strings_count] = #type_ident::#accessor().await;
+                                    let #translations_key = l_i18n_crate::__private::KeyTranslations::<#strings_count>::new(#type_ident::#accessor().await, #path);
                                     l_i18n_crate::__private::LitWrapper::new(#lit)
                                 }
                             }
@@ -827,9 +1372,19 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                     Some(ts)
                 });
                 if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+                    let return_override = lookup_override.is_some().then(|| {
+                        quote! {
+                            if let Some(s) = overridden {
+                                return l_i18n_crate::__private::LitWrapper::new(s);
+                            }
+                        }
+                    });
                     quote! {
                         pub fn #key(self) -> l_i18n_crate::__private::LitWrapperFut<impl std::future::Future<Output = l_i18n_crate::__private::LitWrapper<#literal_type>>> {
+                            #lookup_override
                             let fut = async move {
+                                #return_override
+                                #report_fallback
                                 match self.0 {
                                     #(
                                         #match_arms
@@ -840,8 +1395,18 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                         }
                     }
                 } else if cfg!(all(feature = "dynamic_load", feature = "ssr")) {
+                    let return_override = lookup_override.is_some().then(|| {
+                        quote! {
+                            if let Some(s) = overridden {
+                                return l_i18n_crate::__private::LitWrapperFut::new_not_fut(s);
+                            }
+                        }
+                    });
                     quote! {
                         pub fn #key(self) -> l_i18n_crate::__private::LitWrapperFut<l_i18n_crate::__private::LitWrapper<#literal_type>> {
+                            #lookup_override
+                            #return_override
+                            #report_fallback
                             match self.0 {
                                 #(
                                     #match_arms
@@ -849,6 +1414,91 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                             }
                         }
                     }
+                } else if RUNTIME_LITERALS {
+                    let return_override = lookup_override.is_some().then(|| {
+                        quote! {
+                            if let Some(s) = overridden {
+                                return s;
+                            }
+                        }
+                    });
+                    let mut const_arms = Vec::new();
+                    let mut runtime_arms = Vec::new();
+                    for locale in locales {
+                        let lit = locale
+                            .keys
+                            .get(key)
+                            .unwrap_at("create_locale_type_inner_2");
+                        if matches!(lit, ParsedValue::Default) {
+                            continue;
+                        }
+                        let ident = &locale.top_locale_name;
+                        let defaulted = computed_defaults.get(&locale.top_locale_name).map(|defaulted_locales| {
+                            defaulted_locales.iter().map(|key| {
+                                quote!(| #enum_ident::#key)
+                            }).collect::<TokenStream>()
+                        });
+                        let strings_count = locale.top_locale_string_count;
+                        let const_lit = parsed_value::literal_to_const_token_stream(lit, strings_count);
+                        let runtime_lit = parsed_value::to_token_stream(lit, strings_count, &Default::default());
+                        if !is_string {
+                            const_arms.push(quote!(#enum_ident::#ident #defaulted => #const_lit));
+                            runtime_arms.push(quote!(#enum_ident::#ident #defaulted => #runtime_lit));
+                            continue;
+                        }
+                        let runtime_lit = if DISPLAY_MODE {
+                            quote!(l_i18n_crate::__private::display_mode::message(#runtime_lit))
+                        } else {
+                            runtime_lit
+                        };
+                        // with the "hot_reload" feature the strings are read at runtime, the strings from the locale files
+                        // at compile time are still accessible in a const context.
+                        let accessor = strings_accessor_method_name(locale);
+                        let (const_accessor, runtime_translations) = if HOT_RELOAD {
+                            (
+                                static_strings_accessor_method_name(locale),
+                                quote!(let #translations_key: &'static [&'static str; #strings_count] = #type_ident::#accessor();),
+                            )
+                        } else {
+                            (
+                                accessor.clone(),
+                                quote!(const #translations_key: &[&str; #strings_count] = #type_ident::#accessor();),
+                            )
+                        };
+                        const_arms.push(quote! {
+                            #enum_ident::#ident #defaulted => {
+                                #[allow(unused)]
+                                const #translations_key: &[&str; #strings_count] = #type_ident::#const_accessor();
+                                #const_lit
+                            }
+                        });
+                        runtime_arms.push(quote! {
+                            #enum_ident::#ident #defaulted => {
+                                #[allow(unused)]
+                                #runtime_translations
+                                #runtime_lit
+                            }
+                        });
+                    }
+                    quote! {
+                        pub const fn #key(self) -> l_i18n_crate::__private::LitResolver<#literal_type, #enum_ident> {
+                            let lit = match self.0 {
+                                #(
+                                    #const_arms,
+                                )*
+                            };
+                            l_i18n_crate::__private::LitResolver::new(lit, self.0, |__locale| {
+                                #lookup_override
+                                #return_override
+                                #report_fallback
+                                match __locale {
+                                    #(
+                                        #runtime_arms,
+                                    )*
+                                }
+                            })
+                        }
+                    }
                 } else {
                     quote! {
                         pub const fn #key(self) -> l_i18n_crate::__private::LitWrapper<#literal_type> {
@@ -864,6 +1514,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
         })
         .collect::<Vec<_>>();
 
+    let entries_iter = create_entries_iter(enum_ident, &literal_keys);
+
     let subkeys = keys
         .iter()
         .filter_map(|(key, value)| match value {
@@ -887,6 +1539,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
             &mut pushed_key,
             namespace_name,
             translations_uri,
+            translations_manifest,
             options,
             &sk.docs,
             gen_docs,
@@ -979,7 +1632,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
 
                 let get_fn = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
                     quote! {
-                        pub async fn get_translations() -> &'static [Box<str>; #strings_count] {
+                        pub async fn get_translations() -> Option<&'static [Box<str>; #strings_count]> {
                             <Self as l_i18n_crate::__private::fetch_translations::TranslationUnit>::request_strings().await
                         }
                     }
@@ -991,6 +1644,22 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                         }
                     }
 
+                } else if HOT_RELOAD {
+                    let locale_str = &*locale.top_locale_name.name;
+                    let namespace = match namespace_name {
+                        Some(namespace) => quote!(Some(#namespace)),
+                        None => quote!(None),
+                    };
+                    quote! {
+                        pub fn get_translations() -> &'static [&'static str; #strings_count] {
+                            l_i18n_crate::__private::hot_reload::strings(
+                                #enum_ident::__hot_reload_config__(),
+                                #locale_str,
+                                #namespace,
+                                <Self as l_i18n_crate::__private::fetch_translations::TranslationUnit>::STRINGS,
+                            )
+                        }
+                    }
                 } else {
                     quote! {
                         pub const fn get_translations() -> &'static [&'static str; #strings_count] {
@@ -999,16 +1668,22 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                     }
                 };
 
-                let request_translations = if cfg!(all(feature = "dynamic_load", feature = "csr")) {
+                let request_translations = if FETCH_STATIC_FILES {
                     let uri = translations_uri.expect("Missing URI"); // Already check before
                     // trigger with rustc 1.85, still in nightly tho
                     // #[allow(clippy::literal_string_with_formatting_args)]
-                    let endpoint = uri.replace("{locale}", &locale.name.name).replace("{namespace}", namespace_name.unwrap_or(""));
+                    let file = translations_manifest.and_then(|manifest| manifest.get(namespace_name, &locale.name.name));
+                    let hash = file.and_then(|file| file.hash.as_deref()).unwrap_or("");
+                    let endpoint = uri.replace("{locale}", &locale.name.name).replace("{namespace}", namespace_name.unwrap_or("")).replace("{hash}", hash);
+                    let output_codec = match translations_manifest.map(TranslationsManifest::encoding) {
+                        Some(TranslationsEncoding::Binary) => quote!(l_i18n_crate::__private::fetch_translations::Binary),
+                        Some(TranslationsEncoding::Json) | None => quote!(l_i18n_crate::reexports::leptos::server_fn::codec::Json),
+                    };
                     quote! {
                         pub async fn __i18n_request_translations__() -> Result<l_i18n_crate::__private::fetch_translations::LocaleServerFnOutput, l_i18n_crate::reexports::leptos::server_fn::ServerFnError> {
                             use l_i18n_crate::reexports::leptos::server_fn;
 
-                            #[l_i18n_crate::reexports::leptos::server(endpoint = #endpoint, prefix = "", input = l_i18n_crate::reexports::leptos::server_fn::codec::GetUrl, output = l_i18n_crate::reexports::leptos::server_fn::codec::Json)]
+                            #[l_i18n_crate::reexports::leptos::server(endpoint = #endpoint, prefix = "", input = l_i18n_crate::reexports::leptos::server_fn::codec::GetUrl, output = #output_codec)]
                             pub async fn i18n_request_translations_inner() -> Result<l_i18n_crate::__private::fetch_translations::LocaleServerFnOutput, server_fn::ServerFnError>;
 
                             i18n_request_translations_inner().await
@@ -1029,7 +1704,10 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                         const STRINGS: &[&str; #strings_count] = &[#(#strings,)*];
                     }
                 } else {
+                    let build_hash = hash_strings(strings);
                     quote! {
+                        const BUILD_HASH: &'static str = #build_hash;
+
                         fn get_strings_lock() -> &'static l_i18n_crate::__private::fetch_translations::OnceCell<Box<Self::Strings>> {
                             Self::__get_strings_lock()
                         }
@@ -1090,7 +1768,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
             Some(parent) if !IS_TOP => {
                 if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
                     quote! {
-                        pub async fn #accessor_ident() -> &'static [Box<str>; #strings_count] {
+                        pub async fn #accessor_ident() -> Option<&'static [Box<str>; #strings_count]> {
                             super::super::#parent::#accessor_ident().await
                         }
                     }
@@ -1100,6 +1778,17 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                             super::super::#parent::#accessor_ident()
                         }
                     }
+                } else if HOT_RELOAD {
+                    let static_accessor_ident = static_strings_accessor_method_name(locale);
+                    quote! {
+                        pub fn #accessor_ident() -> &'static [&'static str; #strings_count] {
+                            super::super::#parent::#accessor_ident()
+                        }
+
+                        pub const fn #static_accessor_ident() -> &'static [&'static str; #strings_count] {
+                            super::super::#parent::#static_accessor_ident()
+                        }
+                    }
                 } else {
                     quote! {
                         pub const fn #accessor_ident() -> &'static [&'static str; #strings_count] {
@@ -1112,7 +1801,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                 let string_holder = format_ident!("{}_{}", type_ident, locale.top_locale_name);
                 if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
                     quote! {
-                        pub async fn #accessor_ident() -> &'static [Box<str>; #strings_count] {
+                        pub async fn #accessor_ident() -> Option<&'static [Box<str>; #strings_count]> {
                             #string_holder::get_translations().await
                         }
                     }
@@ -1122,6 +1811,17 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                             #string_holder::get_translations()
                         }
                     }
+                } else if HOT_RELOAD {
+                    let static_accessor_ident = static_strings_accessor_method_name(locale);
+                    quote! {
+                        pub fn #accessor_ident() -> &'static [&'static str; #strings_count] {
+                            #string_holder::get_translations()
+                        }
+
+                        pub const fn #static_accessor_ident() -> &'static [&'static str; #strings_count] {
+                            <#string_holder as l_i18n_crate::__private::fetch_translations::TranslationUnit>::STRINGS
+                        }
+                    }
                 } else {
                     quote! {
                         pub const fn #accessor_ident() -> &'static [&'static str; #strings_count] {
@@ -1137,7 +1837,7 @@ fn create_locale_type_inner<const IS_TOP: bool>(
         let match_arms = locales.iter().map(|locale| {
             let string_holder = format_ident!("{}_{}", type_ident, locale.top_locale_name);
             let locale_name = &locale.top_locale_name;
-            if cfg!(all(feature = "dynamic_load", feature = "csr")) {
+            if FETCH_STATIC_FILES {
                 quote! {
                     #enum_ident::#locale_name => #string_holder::__i18n_request_translations__().await
                 }
@@ -1147,25 +1847,23 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                 }
             }
         });
-        let match_stmt = if cfg!(all(
-            feature = "dynamic_load",
-            not(any(feature = "ssr", feature = "csr"))
-        )) {
-            quote! {
-                unreachable!(
-                    "This function should not have been called on the client!"
-                )
-            }
-        } else {
-            quote! {
-                match _locale {
-                    #(
-                        #match_arms,
-                    )*
+        let match_stmt =
+            if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) && !FETCH_STATIC_FILES {
+                quote! {
+                    unreachable!(
+                        "This function should not have been called on the client!"
+                    )
                 }
-            }
-        };
-        if cfg!(all(feature = "dynamic_load", feature = "csr")) {
+            } else {
+                quote! {
+                    match _locale {
+                        #(
+                            #match_arms,
+                        )*
+                    }
+                }
+            };
+        if FETCH_STATIC_FILES {
             quote! {
                 #[doc(hidden)]
                 pub async fn __i18n_request_translations__(_locale: #enum_ident, _: ()) -> Result<l_i18n_crate::__private::fetch_translations::LocaleServerFnOutput, l_i18n_crate::reexports::leptos::server_fn::ServerFnError> {
@@ -1215,6 +1913,56 @@ fn create_locale_type_inner<const IS_TOP: bool>(
         quote!()
     };
 
+    let preload_fns = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        let preload_match_arms = locales.iter().map(|locale| {
+            let accessor_ident = strings_accessor_method_name(locale);
+            let locale_name = &locale.top_locale_name;
+            quote! {
+                #enum_ident::#locale_name => {
+                    Self::#accessor_ident().await;
+                }
+            }
+        });
+        let is_loaded = match parent_ident {
+            Some(parent) if !IS_TOP => quote! {
+                <super::super::#parent as l_i18n_crate::LocaleKeys>::is_loaded(locale)
+            },
+            _ => {
+                let match_arms = locales.iter().map(|locale| {
+                    let string_holder = format_ident!("{}_{}", type_ident, locale.top_locale_name);
+                    let locale_name = &locale.top_locale_name;
+                    quote! {
+                        #enum_ident::#locale_name => <#string_holder as l_i18n_crate::__private::fetch_translations::TranslationUnit>::get_strings_lock().get().is_some()
+                    }
+                });
+                quote! {
+                    match locale {
+                        #(
+                            #match_arms,
+                        )*
+                    }
+                }
+            }
+        };
+        quote! {
+            fn preload(locale: #enum_ident) -> impl ::core::future::Future<Output = ()> + Send + Sync + 'static {
+                async move {
+                    match locale {
+                        #(
+                            #preload_match_arms
+                        )*
+                    }
+                }
+            }
+
+            fn is_loaded(locale: #enum_ident) -> bool {
+                #is_loaded
+            }
+        }
+    } else {
+        quote!()
+    };
+
     quote! {
         #docs
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -1240,6 +1988,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
                 #subkeys_accessors
             )*
 
+            #entries_iter
+
             #(
                 #[allow(non_snake_case)]
                 #builder_accessors
@@ -1260,6 +2010,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
             fn from_locale(locale: #enum_ident) -> Self {
                 Self::__new_internal(locale)
             }
+
+            #preload_fns
         }
 
         #string_holders
@@ -1282,6 +2034,7 @@ fn create_namespaces_types(
     namespaces: &[Namespace],
     keys: &BTreeMap<Key, BuildersKeysInner>,
     translations_uri: Option<&str>,
+    translations_manifest: Option<&TranslationsManifest>,
     options: &ParseOptions,
     gen_docs: bool,
 ) -> TokenStream {
@@ -1333,6 +2086,7 @@ fn create_namespaces_types(
                 &mut key_path,
                 Some(&namespace.key.name),
                 translations_uri,
+                translations_manifest,
                 options,
                 docs,
                 gen_docs,
@@ -1380,30 +2134,28 @@ fn create_namespaces_types(
 
     let get_strings_match_arms = namespaces.iter().map(|(ns, namespace_module_ident, _)| {
         let ns_ident = &ns.key.ident;
-        let maybe_await = cfg!(all(feature = "dynamic_load", feature = "csr")).then(|| quote!(.await));
+        let maybe_await = FETCH_STATIC_FILES.then(|| quote!(.await));
         quote! {
             #translation_unit_enum_ident::#ns_ident => namespaces::#namespace_module_ident::#ns_ident::__i18n_request_translations__(locale, ()) #maybe_await
         }
     });
 
-    let get_strings_match_stmt = if cfg!(all(
-        feature = "dynamic_load",
-        not(any(feature = "ssr", feature = "csr"))
-    )) {
-        quote! {
-            unreachable!(
-                "This function should not have been called on the client!"
-            )
-        }
-    } else {
-        quote! {
-            match translations_id {
-                #(
-                    #get_strings_match_arms,
-                )*
+    let get_strings_match_stmt =
+        if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) && !FETCH_STATIC_FILES {
+            quote! {
+                unreachable!(
+                    "This function should not have been called on the client!"
+                )
             }
-        }
-    };
+        } else {
+            quote! {
+                match translations_id {
+                    #(
+                        #get_strings_match_arms,
+                    )*
+                }
+            }
+        };
 
     let init_translations = if cfg!(all(feature = "dynamic_load", feature = "hydrate")) {
         let match_arms = namespaces.iter().map(|(ns, namespace_module_ident, _)| {
@@ -1426,7 +2178,46 @@ fn create_namespaces_types(
         quote!()
     };
 
-    let translation_request_fn = if cfg!(all(feature = "dynamic_load", feature = "csr")) {
+    let preload_fns = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        let namespaces_types = namespaces
+            .iter()
+            .map(|(ns, namespace_module_ident, _)| {
+                let ns_ident = &ns.key.ident;
+                quote!(namespaces::#namespace_module_ident::#ns_ident)
+            })
+            .collect::<Vec<_>>();
+        quote! {
+            fn preload(locale: #enum_ident) -> impl ::core::future::Future<Output = ()> + Send + Sync + 'static {
+                async move {
+                    l_i18n_crate::__private::fetch_translations::join!(
+                        #(
+                            <#namespaces_types as l_i18n_crate::LocaleKeys>::preload(locale),
+                        )*
+                    );
+                }
+            }
+
+            fn is_loaded(locale: #enum_ident) -> bool {
+                #(
+                    <#namespaces_types as l_i18n_crate::LocaleKeys>::is_loaded(locale)
+                )&&*
+            }
+
+            fn preload_matching(locale: #enum_ident, loaded_locale: #enum_ident) -> impl ::core::future::Future<Output = ()> + Send + Sync + 'static {
+                async move {
+                    l_i18n_crate::__private::fetch_translations::join!(
+                        #(
+                            <#namespaces_types as l_i18n_crate::LocaleKeys>::preload_matching(locale, loaded_locale),
+                        )*
+                    );
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let translation_request_fn = if FETCH_STATIC_FILES {
         quote! {
             #[doc(hidden)]
             #[allow(unused_variables)]
@@ -1479,6 +2270,8 @@ fn create_namespaces_types(
             fn from_locale(locale: #enum_ident) -> Self {
                 Self::__new_internal(locale)
             }
+
+            #preload_fns
         }
 
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1537,6 +2330,7 @@ fn create_locale_type(
     enum_ident: &syn::Ident,
     translation_unit_enum_ident: &syn::Ident,
     translations_uri: Option<&str>,
+    translations_manifest: Option<&TranslationsManifest>,
     options: &ParseOptions,
     gen_docs: bool,
 ) -> TokenStream {
@@ -1548,6 +2342,7 @@ fn create_locale_type(
             namespaces,
             keys,
             translations_uri,
+            translations_manifest,
             options,
             gen_docs,
         ),
@@ -1571,6 +2366,7 @@ fn create_locale_type(
                 &mut KeyPath::new(None),
                 None,
                 translations_uri,
+                translations_manifest,
                 options,
                 &docs,
                 gen_docs,
@@ -1613,3 +2409,61 @@ fn gen_keys_doc(docs: &mut String, keys: &BTreeMap<Key, LocaleValue>) -> core::f
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> Key {
+        Key::new(name).unwrap()
+    }
+
+    fn value(defaulted: &[(&str, &str)]) -> LocaleValue {
+        let mut defaults = DefaultedLocales::new(key("en"));
+        for (locale, default_to) in defaulted {
+            defaults.push(key(locale), key(default_to));
+        }
+        LocaleValue::Value {
+            value: InterpolOrLit::Lit(
+                leptos_i18n_parser::parse_locales::locale::LiteralType::String,
+            ),
+            defaults,
+        }
+    }
+
+    #[test]
+    fn test_collect_defaulted_keys() {
+        let keys = BTreeMap::from([
+            (key("translated"), value(&[])),
+            (key("missing"), value(&[("fr", "en"), ("de", "fr")])),
+            (
+                key("sub"),
+                LocaleValue::Subkeys {
+                    locales: vec![],
+                    keys: BuildersKeysInner(BTreeMap::from([(
+                        key("inner"),
+                        value(&[("fr", "en")]),
+                    )])),
+                },
+            ),
+        ]);
+
+        let mut defaulted_keys = BTreeMap::new();
+        collect_defaulted_keys(
+            &keys,
+            &mut KeyPath::new(Some(key("ns"))),
+            &mut defaulted_keys,
+        );
+
+        assert_eq!(
+            defaulted_keys,
+            BTreeMap::from([
+                (key("de"), vec!["ns::missing".to_string()]),
+                (
+                    key("fr"),
+                    vec!["ns::missing".to_string(), "ns::sub.inner".to_string()]
+                ),
+            ])
+        );
+    }
+}
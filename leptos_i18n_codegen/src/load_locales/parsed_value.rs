@@ -9,9 +9,13 @@ use leptos_i18n_parser::{
     utils::{Key, KeyPath, UnwrapAt},
 };
 
-use super::{interpolate::LOCALE_FIELD_KEY, plurals, ranges};
+use super::{
+    DISPLAY_MODE, HOT_RELOAD,
+    interpolate::{ComponentAttrs, ComponentsAttrs, LOCALE_FIELD_KEY},
+    plurals, ranges,
+};
 
-pub const TRANSLATIONS_KEY: &str = if cfg!(feature = "dynamic_load") {
+pub const TRANSLATIONS_KEY: &str = if cfg!(feature = "dynamic_load") || HOT_RELOAD {
     "__i18n_translations__"
 } else {
     "I18N_TRANSLATIONS"
@@ -47,11 +51,22 @@ pub enum Literal<'a> {
 }
 
 impl Literal<'_> {
+    /// Same as `to_token_stream`, but strings displayed as text go through the runtime display mode.
+    fn to_text_token_stream(&self, strings_count: usize) -> TokenStream {
+        let ts = self.to_token_stream(strings_count);
+        match self {
+            Literal::String(..) if DISPLAY_MODE => {
+                quote!(l_i18n_crate::__private::display_mode::text(#ts))
+            }
+            _ => ts,
+        }
+    }
+
     fn to_token_stream(&self, strings_count: usize) -> TokenStream {
         match self {
             Literal::String(_, index) => {
                 let translations_key = Key::new(TRANSLATIONS_KEY).unwrap_at("TRANSLATIONS_KEY");
-                if cfg!(feature = "dynamic_load") {
+                if cfg!(feature = "dynamic_load") || HOT_RELOAD {
                     quote!(l_i18n_crate::__private::index_translations::<#strings_count, #index>(#translations_key))
                 } else {
                     quote! {
@@ -75,12 +90,17 @@ fn flatten(
     tokens: &mut Vec<TokenStream>,
     locale_field: &Key,
     strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
 ) {
     match this {
         ParsedValue::Default => unreachable!("defaulted value should never have been rendered"),
         ParsedValue::Subkeys(_) => unreachable!("subkeys should never have been rendered"),
-        ParsedValue::Literal(lit) => tokens.push(Literal::from(lit).to_token_stream(strings_count)),
-        ParsedValue::Ranges(ranges) => tokens.push(ranges::to_token_stream(ranges, strings_count)),
+        ParsedValue::Literal(lit) => {
+            tokens.push(Literal::from(lit).to_text_token_stream(strings_count))
+        }
+        ParsedValue::Ranges(ranges) => {
+            tokens.push(ranges::to_token_stream(ranges, strings_count, comps_attrs))
+        }
         ParsedValue::Variable { key, bounds } => {
             let ts = bounds.var_to_view(&key.ident, &locale_field.ident);
             tokens.push(quote! {{
@@ -94,6 +114,12 @@ fn flatten(
             attributes,
         } => {
             let attrs = attributes_to_token_stream(attributes, strings_count);
+            let typed_attrs = comps_attrs
+                .get(key)
+                .map(|comp_attrs| {
+                    typed_attributes_to_token_stream(comp_attrs, attributes, strings_count)
+                })
+                .unwrap_at("parsed_value::flatten_2");
             if let Some(inner) = inner {
                 let mut key_path = KeyPath::new(None);
                 let captured_keys = inner
@@ -107,7 +133,7 @@ fn flatten(
                         quote!(#(#keys)*)
                     });
 
-                let inner = to_token_stream(inner, strings_count);
+                let inner = to_token_stream(inner, strings_count, comps_attrs);
                 let f = quote!(
                     {
                         #captured_keys
@@ -117,33 +143,37 @@ fn flatten(
                 tokens.push(quote!({
                     let __boxed_children_fn = l_i18n_crate::reexports::leptos::children::ToChildren::to_children(#f);
                     let __attrs = { #attrs };
+                    let __typed_attrs = #typed_attrs;
                     let #key = core::clone::Clone::clone(&#key);
                     move || {
-                        l_i18n_crate::__private::InterpolateComp::to_view(&#key, core::clone::Clone::clone(&__boxed_children_fn), &__attrs)
+                        l_i18n_crate::__private::InterpolateComp::to_view(&#key, core::clone::Clone::clone(&__boxed_children_fn), &__attrs, &__typed_attrs)
                     }
                 }));
             } else {
                 tokens.push(quote!({
                     let __attrs = { #attrs };
+                    let __typed_attrs = #typed_attrs;
                     let #key = core::clone::Clone::clone(&#key);
-                    move || l_i18n_crate::__private::InterpolateCompSelfClosed::to_view(&#key, &__attrs)
+                    move || l_i18n_crate::__private::InterpolateCompSelfClosed::to_view(&#key, &__attrs, &__typed_attrs)
                 }));
             }
         }
 
         ParsedValue::Bloc(values) => {
             for value in values {
-                flatten(value, tokens, locale_field, strings_count);
+                flatten(value, tokens, locale_field, strings_count, comps_attrs);
             }
         }
         ParsedValue::ForeignKey(foreign_key) => {
             let f_value = foreign_key.borrow();
             let value = f_value.as_inner("flatten");
-            flatten(value, tokens, locale_field, strings_count);
-        }
-        ParsedValue::Plurals(plurals) => {
-            tokens.push(plurals::to_token_stream(plurals, strings_count))
+            flatten(value, tokens, locale_field, strings_count, comps_attrs);
         }
+        ParsedValue::Plurals(plurals) => tokens.push(plurals::to_token_stream(
+            plurals,
+            strings_count,
+            comps_attrs,
+        )),
         // don't emit any code for dummies, it will default to "" just for compiling
         ParsedValue::Dummy(_) => {}
     }
@@ -159,7 +189,7 @@ fn flatten_string(
         ParsedValue::Default => unreachable!("defaulted value should never have been rendered"),
         ParsedValue::Subkeys(_) => unreachable!("subkeys should never have been rendered"),
         ParsedValue::Literal(lit) => {
-            let ts = Literal::from(lit).to_token_stream(strings_count);
+            let ts = Literal::from(lit).to_text_token_stream(strings_count);
             tokens.push(quote!(core::fmt::Display::fmt(&#ts, __formatter)))
         }
         ParsedValue::Ranges(ranges) => tokens.push(ranges::as_string_impl(ranges, strings_count)),
@@ -211,10 +241,14 @@ fn flatten_string(
     }
 }
 
-pub fn to_token_stream(this: &ParsedValue, strings_count: usize) -> TokenStream {
+pub fn to_token_stream(
+    this: &ParsedValue,
+    strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
+) -> TokenStream {
     let mut tokens = Vec::new();
     let locale_field = Key::new(LOCALE_FIELD_KEY).unwrap_at("LOCALE_FIELD_KEY");
-    flatten(this, &mut tokens, &locale_field, strings_count);
+    flatten(this, &mut tokens, &locale_field, strings_count, comps_attrs);
 
     match &mut tokens[..] {
         [] => quote!(""),
@@ -223,6 +257,20 @@ pub fn to_token_stream(this: &ParsedValue, strings_count: usize) -> TokenStream
     }
 }
 
+/// Same as `to_token_stream` for the value of a literal key, but strings don't go through the runtime display mode,
+/// so the value can be computed in a const context.
+pub fn literal_to_const_token_stream(this: &ParsedValue, strings_count: usize) -> TokenStream {
+    match this {
+        ParsedValue::Literal(lit) => Literal::from(lit).to_token_stream(strings_count),
+        ParsedValue::ForeignKey(foreign_key) => {
+            let f_value = foreign_key.borrow();
+            let value = f_value.as_inner("literal_to_const_token_stream");
+            literal_to_const_token_stream(value, strings_count)
+        }
+        _ => to_token_stream(this, strings_count, &Default::default()),
+    }
+}
+
 pub fn as_string_impl(this: &ParsedValue, strings_count: usize) -> TokenStream {
     let mut tokens = Vec::new();
     let locale_field = Key::new(LOCALE_FIELD_KEY).unwrap_at("LOCALE_FIELD_KEY");
@@ -244,6 +292,36 @@ pub fn attributes_to_token_stream(this: &Attributes, strings_count: usize) -> To
     quote!(vec![#(#attrs),*])
 }
 
+/// Create the attributes struct of a component, the attributes missing from this use of the component are `None`.
+pub fn typed_attributes_to_token_stream(
+    comp_attrs: &ComponentAttrs,
+    this: &Attributes,
+    strings_count: usize,
+) -> TokenStream {
+    let fields = comp_attrs.fields.iter().map(|field| {
+        let ident = &field.ident;
+        let value = this
+            .0
+            .iter()
+            .find(|attr| attr.key == field.name)
+            .map(|attr| match &attr.value {
+                None => quote!(true),
+                Some(AttributeValue::Literal(lit)) => {
+                    Literal::from(lit).to_token_stream(strings_count)
+                }
+                Some(AttributeValue::Variable(key)) => quote!(core::clone::Clone::clone(&#key)),
+            });
+        let value = match value {
+            Some(value) if field.optional => quote!(core::option::Option::Some(#value)),
+            Some(value) => value,
+            None => quote!(core::option::Option::None),
+        };
+        quote!(#ident: #value)
+    });
+    let ident = &comp_attrs.ident;
+    quote!(#ident { #(#fields,)* })
+}
+
 pub fn attributes_as_string_impl(this: &Attributes, strings_count: usize) -> TokenStream {
     let attrs = this
         .0
@@ -9,7 +9,10 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
 use crate::{
-    load_locales::{interpolate::LOCALE_FIELD_KEY, parsed_value},
+    load_locales::{
+        interpolate::{ComponentsAttrs, LOCALE_FIELD_KEY},
+        parsed_value,
+    },
     utils::EitherOfWrapper,
 };
 
@@ -112,11 +115,15 @@ pub fn as_string_impl(this: &Plurals, count_key: &Key, strings_count: usize) ->
     }}
 }
 
-pub fn to_token_stream(this: &Plurals, strings_count: usize) -> TokenStream {
+pub fn to_token_stream(
+    this: &Plurals,
+    strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
+) -> TokenStream {
     let either_of = EitherOfWrapper::new(this.forms.len() + 1);
     let match_arms = this.forms.iter().enumerate().map(|(i, (form, value))| {
         let form = PluralForm::from(*form);
-        let ts = parsed_value::to_token_stream(value, strings_count);
+        let ts = parsed_value::to_token_stream(value, strings_count, comps_attrs);
         let ts = either_of.wrap(i, ts);
         quote!(#form => { #ts })
     });
@@ -144,7 +151,7 @@ pub fn to_token_stream(this: &Plurals, strings_count: usize) -> TokenStream {
 
     let count_key = &this.count_key;
 
-    let other_ts = parsed_value::to_token_stream(&this.other, strings_count);
+    let other_ts = parsed_value::to_token_stream(&this.other, strings_count, comps_attrs);
     let other = either_of.wrap(this.forms.len(), other_ts);
 
     quote! {
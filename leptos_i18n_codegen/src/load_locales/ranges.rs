@@ -11,7 +11,10 @@ use leptos_i18n_parser::{
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
-use crate::{load_locales::parsed_value, utils::EitherOfWrapper};
+use crate::{
+    load_locales::{interpolate::ComponentsAttrs, parsed_value},
+    utils::EitherOfWrapper,
+};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
 pub enum RangeType {
@@ -115,10 +118,11 @@ fn to_tokens_integers<T: RangeNumber>(
     ranges: &[(Range<T>, ParsedValue)],
     count_key: &Key,
     strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
 ) -> TokenStream {
     let either_of = EitherOfWrapper::new(ranges.len());
     let match_arms = ranges.iter().enumerate().map(|(i, (range, value))| {
-        let ts = parsed_value::to_token_stream(value, strings_count);
+        let ts = parsed_value::to_token_stream(value, strings_count, comps_attrs);
         let ts = either_of.wrap(i, ts);
         let range = range_to_token_stream(range);
         quote!(#range => { #ts })
@@ -162,10 +166,11 @@ fn to_tokens_floats<T: RangeNumber>(
     ranges: &[(Range<T>, ParsedValue)],
     count_key: &Key,
     strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
 ) -> TokenStream {
     let either_of = EitherOfWrapper::new(ranges.len());
     let mut ifs = ranges.iter().enumerate().map(|(i, (range, value))| {
-        let ts = parsed_value::to_token_stream(value, strings_count);
+        let ts = parsed_value::to_token_stream(value, strings_count, comps_attrs);
         let ts = either_of.wrap(i, ts);
         match range_to_condition(range) {
             None => quote!({ #ts }),
@@ -206,34 +211,42 @@ fn to_tokens_floats<T: RangeNumber>(
     }
 }
 
-pub fn to_token_stream(this: &Ranges, strings_count: usize) -> TokenStream {
+pub fn to_token_stream(
+    this: &Ranges,
+    strings_count: usize,
+    comps_attrs: &ComponentsAttrs,
+) -> TokenStream {
     match &this.inner {
         UntypedRangesInner::I8(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::I16(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::I32(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::I64(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::U8(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::U16(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::U32(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
         }
         UntypedRangesInner::U64(ranges) => {
-            to_tokens_integers(ranges, &this.count_key, strings_count)
+            to_tokens_integers(ranges, &this.count_key, strings_count, comps_attrs)
+        }
+        UntypedRangesInner::F32(ranges) => {
+            to_tokens_floats(ranges, &this.count_key, strings_count, comps_attrs)
+        }
+        UntypedRangesInner::F64(ranges) => {
+            to_tokens_floats(ranges, &this.count_key, strings_count, comps_attrs)
         }
-        UntypedRangesInner::F32(ranges) => to_tokens_floats(ranges, &this.count_key, strings_count),
-        UntypedRangesInner::F64(ranges) => to_tokens_floats(ranges, &this.count_key, strings_count),
     }
 }
 
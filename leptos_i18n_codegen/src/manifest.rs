@@ -0,0 +1,105 @@
+//! Manifest of the translations files written by `leptos_i18n_build`.
+//!
+//! When given to the codegen, the `{hash}` placeholder of the `translations_uri` is replaced by the hash of each translation unit,
+//! and the client decodes the files with the encoding they were written with.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+
+/// Encoding of the translations files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TranslationsEncoding {
+    /// A JSON array of strings, written in `.json` files.
+    #[default]
+    Json,
+    /// Length-prefixed strings, written in `.bin` files.
+    ///
+    /// The file starts with the number of strings as a little-endian `u32`,
+    /// then each string is its length in bytes as a little-endian `u32` followed by its UTF-8 bytes.
+    Binary,
+}
+
+impl TranslationsEncoding {
+    /// Extension of the files written with this encoding.
+    pub fn extension(self) -> &'static str {
+        match self {
+            TranslationsEncoding::Json => "json",
+            TranslationsEncoding::Binary => "bin",
+        }
+    }
+}
+
+/// A translations file, relative to the directory the translations were written in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationsFile {
+    /// Path of the file, with `/` as separator.
+    pub path: String,
+    /// Hash of the content of the file, if the file name is hashed.
+    pub hash: Option<String>,
+}
+
+/// Map each translation unit to the file containing its translations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranslationsManifest {
+    encoding: TranslationsEncoding,
+    files: BTreeMap<(Option<String>, String), TranslationsFile>,
+}
+
+impl TranslationsManifest {
+    /// Create an empty manifest for files written with the given encoding.
+    pub fn new(encoding: TranslationsEncoding) -> Self {
+        TranslationsManifest {
+            encoding,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Encoding of the files.
+    pub fn encoding(&self) -> TranslationsEncoding {
+        self.encoding
+    }
+
+    /// Register the file of a translation unit.
+    pub fn insert(&mut self, namespace: Option<&str>, locale: &str, file: TranslationsFile) {
+        self.files
+            .insert((namespace.map(str::to_owned), locale.to_owned()), file);
+    }
+
+    /// Return the file of a translation unit.
+    pub fn get(&self, namespace: Option<&str>, locale: &str) -> Option<&TranslationsFile> {
+        self.files
+            .get(&(namespace.map(str::to_owned), locale.to_owned()))
+    }
+
+    /// Iterate over the translation units as `(namespace, locale, file)`.
+    pub fn iter(&self) -> impl Iterator<Item = (Option<&str>, &str, &TranslationsFile)> {
+        self.files
+            .iter()
+            .map(|((namespace, locale), file)| (namespace.as_deref(), locale.as_str(), file))
+    }
+
+    /// Generate a Rust module exposing the path of each translations file.
+    pub fn to_module(&self) -> TokenStream {
+        let files = self.iter().map(|(namespace, locale, file)| {
+            let namespace = match namespace {
+                Some(namespace) => quote!(Some(#namespace)),
+                None => quote!(None),
+            };
+            let path = &file.path;
+            quote!((#namespace, #locale, #path))
+        });
+        quote! {
+            /// Translations files as `(namespace, locale, path)`, paths are relative to the translations directory.
+            pub const TRANSLATIONS_FILES: &[(Option<&str>, &str, &str)] = &[#(#files,)*];
+
+            /// Return the path of the translations file for the given locale and namespace.
+            pub fn translations_file(locale: &str, namespace: Option<&str>) -> Option<&'static str> {
+                TRANSLATIONS_FILES
+                    .iter()
+                    .find(|(ns, loc, _)| *loc == locale && *ns == namespace)
+                    .map(|(_, _, path)| *path)
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Codegen for `Locale::translate_dyn`: a lookup table from the dotted path of the keys to their value,
+//! for keys only known at runtime, such as paths stored in a configuration file or a CMS.
+//!
+//! Only literals and variables are supported, keys using components, plurals, ranges or formatters are not in the table.
+
+use leptos_i18n_parser::{
+    formatters::VarBounds,
+    parse_locales::{
+        locale::{BuildersKeys, Locale, LocaleValue},
+        parsed_value::{Literal, ParsedValue},
+    },
+    utils::{Key, UnwrapAt},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::lite::values_by_locale;
+
+enum Segment {
+    Str(String),
+    Var(String),
+}
+
+/// Flatten `value` in `segments`, return `None` if it is not supported.
+fn push_segments(value: &ParsedValue, segments: &mut Vec<Segment>) -> Option<()> {
+    match value {
+        ParsedValue::Literal(lit) => {
+            let s = match lit {
+                Literal::String(s, _) => s.clone(),
+                Literal::Signed(v) => v.to_string(),
+                Literal::Unsigned(v) => v.to_string(),
+                Literal::Float(v) => v.to_string(),
+                Literal::Bool(v) => v.to_string(),
+            };
+            match segments.last_mut() {
+                Some(Segment::Str(last)) => last.push_str(&s),
+                _ => segments.push(Segment::Str(s)),
+            }
+        }
+        ParsedValue::Variable { key, bounds } => {
+            if matches!(bounds, VarBounds::Formatted { .. }) {
+                return None;
+            }
+            let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+            segments.push(Segment::Var(name.to_owned()));
+        }
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                push_segments(value, segments)?;
+            }
+        }
+        ParsedValue::ForeignKey(foreign_key) => {
+            push_segments(
+                foreign_key.borrow().as_inner("translate_dyn_segments"),
+                segments,
+            )?;
+        }
+        ParsedValue::Dummy(_) => {}
+        ParsedValue::Default
+        | ParsedValue::Subkeys(_)
+        | ParsedValue::Component { .. }
+        | ParsedValue::Ranges(_)
+        | ParsedValue::Plurals(_) => return None,
+    }
+    Some(())
+}
+
+fn segments_to_tokens(segments: &[Segment]) -> TokenStream {
+    let segments = segments.iter().map(|segment| match segment {
+        Segment::Str(s) => quote!(__DynSegment::Str(#s)),
+        Segment::Var(name) => quote!(__DynSegment::Var(#name)),
+    });
+    quote!(&[#(#segments,)*])
+}
+
+/// Push the match arms of the keys in `keys` and their subkeys, with the paths prefixed by `prefixes`.
+fn push_match_arms(
+    enum_ident: &syn::Ident,
+    locales: &[Locale],
+    keys: &std::collections::BTreeMap<Key, LocaleValue>,
+    prefixes: &[String],
+    arms: &mut Vec<TokenStream>,
+) {
+    for (key, value) in keys {
+        let paths = prefixes
+            .iter()
+            .map(|prefix| format!("{prefix}{}", key.name))
+            .collect::<Vec<_>>();
+        match value {
+            LocaleValue::Value { defaults, .. } => {
+                let Some(locale_arms) = values_by_locale(locales, key, defaults)
+                    .into_iter()
+                    .map(|(locale, value)| {
+                        let mut segments = vec![];
+                        push_segments(value, &mut segments)?;
+                        let segments = segments_to_tokens(&segments);
+                        Some(quote!(#enum_ident::#locale => #segments))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                arms.push(quote! {
+                    #(#paths)|* => match self {
+                        #(#locale_arms,)*
+                    }
+                });
+            }
+            LocaleValue::Subkeys { locales, keys } => {
+                let prefixes = paths
+                    .iter()
+                    .map(|path| format!("{path}."))
+                    .collect::<Vec<_>>();
+                push_match_arms(enum_ident, locales, &keys.0, &prefixes, arms);
+            }
+        }
+    }
+}
+
+/// Create the `translate_dyn` methods of the `Locale` enum.
+pub fn create_translate_dyn(builder_keys: &BuildersKeys, enum_ident: &syn::Ident) -> TokenStream {
+    let mut arms = vec![];
+    match builder_keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            for namespace in namespaces {
+                let ns_keys = &keys
+                    .get(&namespace.key)
+                    .unwrap_at("create_translate_dyn_1")
+                    .0;
+                // same as `t!`, and as the display of the key paths in diagnostics.
+                let prefixes = [
+                    format!("{}.", namespace.key.name),
+                    format!("{}::", namespace.key.name),
+                ];
+                push_match_arms(
+                    enum_ident,
+                    &namespace.locales,
+                    ns_keys,
+                    &prefixes,
+                    &mut arms,
+                );
+            }
+        }
+        BuildersKeys::Locales { locales, keys } => {
+            push_match_arms(enum_ident, locales, &keys.0, &[String::new()], &mut arms);
+        }
+    }
+
+    quote! {
+        #[doc(hidden)]
+        #[derive(Clone, Copy, Debug)]
+        pub enum __DynSegment {
+            Str(&'static str),
+            Var(&'static str),
+        }
+
+        impl #enum_ident {
+            /// Return the translation of the key at `key_path`, such as `"errors.not_found"`, or `None` if there is no such key.
+            ///
+            /// Only keys made of strings and variables can be looked up, variables are left as `{{ name }}`,
+            /// use `translate_dyn_with_args` to give them a value.
+            pub fn translate_dyn(self, key_path: &str) -> Option<::std::borrow::Cow<'static, str>> {
+                self.translate_dyn_with_args(key_path, &[])
+            }
+
+            /// Return the translation of the key at `key_path` with the variables replaced by the value of the argument with the same name,
+            /// or `None` if there is no such key. Variables without an argument are left as `{{ name }}`.
+            pub fn translate_dyn_with_args(
+                self,
+                key_path: &str,
+                args: &[(&str, &str)],
+            ) -> Option<::std::borrow::Cow<'static, str>> {
+                let segments: &'static [__DynSegment] = match key_path {
+                    #(#arms,)*
+                    _ => return None,
+                };
+                match segments {
+                    [] => Some(::std::borrow::Cow::Borrowed("")),
+                    [__DynSegment::Str(s)] => Some(::std::borrow::Cow::Borrowed(s)),
+                    _ => {
+                        let mut out = ::std::string::String::new();
+                        for segment in segments {
+                            match segment {
+                                __DynSegment::Str(s) => out.push_str(s),
+                                __DynSegment::Var(name) => {
+                                    match args.iter().find(|(arg, _)| arg == name) {
+                                        Some((_, value)) => out.push_str(value),
+                                        None => {
+                                            out.push_str("{{ ");
+                                            out.push_str(name);
+                                            out.push_str(" }}");
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Some(::std::borrow::Cow::Owned(out))
+                    }
+                }
+            }
+        }
+    }
+}
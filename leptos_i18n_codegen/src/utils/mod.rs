@@ -2,6 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, format_ident, quote};
 use syn::Token;
 
+#[derive(Clone)]
 pub enum Keys {
     SingleKey(syn::Ident),
     Subkeys(Vec<syn::Ident>),
@@ -35,6 +36,20 @@ impl syn::parse::Parse for Keys {
     }
 }
 
+impl Keys {
+    /// Return the keys as written, joined with a `.`.
+    pub fn path(&self) -> String {
+        match self {
+            Keys::SingleKey(key) => key.to_string(),
+            Keys::Subkeys(keys) => keys
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("."),
+        }
+    }
+}
+
 impl quote::ToTokens for Keys {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
@@ -0,0 +1,347 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Ident, LitStr, parse_macro_input, spanned::Spanned};
+
+struct ContainerOptions {
+    path: syn::Path,
+    prefix: Option<LitStr>,
+    key: Option<LitStr>,
+}
+
+impl ContainerOptions {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut path = None;
+        let mut prefix = None;
+        let mut key = None;
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("i18n")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    path = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("prefix") {
+                    prefix = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("key") {
+                    key = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected one of `path`, `prefix` or `key`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(ContainerOptions {
+            path: path.unwrap_or_else(|| syn::parse_quote!(crate::i18n)),
+            prefix,
+            key,
+        })
+    }
+
+    fn default_key(&self, ident: &Ident) -> LitStr {
+        let name = to_snake_case(&ident.to_string());
+        let key = match &self.prefix {
+            Some(prefix) => format!("{}.{}", prefix.value(), name),
+            None => name,
+        };
+        LitStr::new(&key, ident.span())
+    }
+}
+
+fn parse_variant_key(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut key = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("i18n")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                key = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `key`"))
+            }
+        })?;
+    }
+    Ok(key)
+}
+
+fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut s = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().copied().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                s.push('_');
+            }
+        }
+        s.extend(c.to_lowercase());
+    }
+    s
+}
+
+/// Turn "status.pending" or "ns::status.pending" into the path of the generated key handle.
+fn key_handle_path(path: &syn::Path, key: &LitStr) -> syn::Result<TokenStream> {
+    let value = key.value();
+    let segments = value
+        .split(['.', ':'])
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            syn::parse_str::<Ident>(segment)
+                .map(|ident| Ident::new(&ident.to_string(), key.span()))
+                .map_err(|_| syn::Error::new(key.span(), format!("invalid key \"{}\"", value)))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    if segments.is_empty() {
+        return Err(syn::Error::new(key.span(), "key can't be empty"));
+    }
+    Ok(quote!(#path::keys #(::#segments)*))
+}
+
+pub fn derive_translate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_translate_inner(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_translate_inner(input: DeriveInput) -> syn::Result<TokenStream> {
+    let options = ContainerOptions::parse(&input.attrs)?;
+    let path = &options.path;
+
+    // (pattern, key handle) for each arm
+    let arms = match &input.data {
+        Data::Struct(_) => {
+            let key = match &options.key {
+                Some(key) => key.clone(),
+                None => options.default_key(&input.ident),
+            };
+            vec![(quote!(_), key_handle_path(path, &key)?)]
+        }
+        Data::Enum(data) => {
+            if let Some(key) = &options.key {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "`key` on an enum is not supported, use it on the variants instead",
+                ));
+            }
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let key = match parse_variant_key(&variant.attrs)? {
+                        Some(key) => key,
+                        None => options.default_key(ident),
+                    };
+                    Ok((quote!(Self::#ident { .. }), key_handle_path(path, &key)?))
+                })
+                .collect::<syn::Result<Vec<_>>>()?
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`Translate` can't be derived for unions",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // empty enums can't be matched by reference
+    let matched = if arms.is_empty() {
+        quote!(*self)
+    } else {
+        quote!(self)
+    };
+    let patterns = arms.iter().map(|(pattern, _)| pattern).collect::<Vec<_>>();
+    let handles = arms.iter().map(|(_, handle)| handle).collect::<Vec<_>>();
+
+    let translate = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        let captured = input
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .chain(input.generics.const_params().map(|param| &param.ident));
+        let indexes = (0..arms.len()).collect::<Vec<_>>();
+        quote! {
+            fn translate(&self, locale: #path::Locale) -> impl core::future::Future<Output = &'static str> + use<#(#captured),*> {
+                let index: usize = match #matched {
+                    #(#patterns => #indexes,)*
+                };
+                async move {
+                    match index {
+                        #(#indexes => leptos_i18n::KeyHandle::translate(#handles, locale).await,)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            fn translate(&self, locale: #path::Locale) -> &'static str {
+                match #matched {
+                    #(#patterns => leptos_i18n::KeyHandle::translate(#handles, locale),)*
+                }
+            }
+        }
+    };
+
+    let view_impls = view_impls(&input, path, &patterns, &handles);
+
+    Ok(quote! {
+        impl #impl_generics leptos_i18n::Translate for #name #ty_generics #where_clause {
+            type Locale = #path::Locale;
+
+            fn key_path(&self) -> &'static str {
+                match #matched {
+                    #(#patterns => <#handles as leptos_i18n::KeyHandle>::PATH,)*
+                }
+            }
+
+            #translate
+        }
+
+        #view_impls
+    })
+}
+
+/// The value is rendered with the locale of the `I18nContext`, so it can be used in `view!` like any other view.
+///
+/// `IntoView` is implemented for every type implementing `Render` and `RenderHtml`,
+/// they are implemented by rendering the view returned by `translate_view`.
+/// Only the index of the key is captured by that view, so the value does not need to be `Sync` or `'static`.
+fn view_impls(
+    input: &DeriveInput,
+    path: &syn::Path,
+    patterns: &[&TokenStream],
+    handles: &[&TokenStream],
+) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+    let where_clause = quote!(where Self: ::core::marker::Send, #predicates);
+    let indexes = (0..patterns.len()).collect::<Vec<_>>();
+
+    let translate = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        quote! {
+            async move {
+                match index {
+                    #(#indexes => leptos_i18n::KeyHandle::translate(#handles, locale).await,)*
+                    _ => unreachable!(),
+                }
+            }
+        }
+    } else {
+        quote! {
+            match index {
+                #(#indexes => leptos_i18n::KeyHandle::translate(#handles, locale),)*
+                _ => unreachable!(),
+            }
+        }
+    };
+
+    let leptos = quote!(leptos_i18n::reexports::leptos);
+    let tachys = quote!(#leptos::tachys);
+    let any_view = quote!(#leptos::prelude::AnyView);
+
+    quote! {
+        const _: () = {
+            use #tachys::view::{Render as _, RenderHtml as _, add_attr::AddAnyAttr as _};
+
+            fn __translate_view(index: usize) -> #any_view {
+                leptos_i18n::__private::translate_view(move |locale: #path::Locale| #translate)
+            }
+
+            impl #impl_generics #name #ty_generics #where_clause {
+                // empty enums have no key.
+                #[allow(unreachable_code)]
+                fn __into_translate_view(self) -> #any_view {
+                    let index: usize = match self {
+                        #(#patterns => #indexes,)*
+                    };
+                    __translate_view(index)
+                }
+            }
+
+            impl #impl_generics #tachys::view::Render for #name #ty_generics #where_clause {
+                type State = <#any_view as #tachys::view::Render>::State;
+
+                fn build(self) -> Self::State {
+                    self.__into_translate_view().build()
+                }
+
+                fn rebuild(self, state: &mut Self::State) {
+                    self.__into_translate_view().rebuild(state)
+                }
+            }
+
+            impl #impl_generics #tachys::view::add_attr::AddAnyAttr for #name #ty_generics #where_clause {
+                type Output<SomeNewAttr: #tachys::html::attribute::Attribute> =
+                    <#any_view as #tachys::view::add_attr::AddAnyAttr>::Output<SomeNewAttr>;
+
+                fn add_any_attr<NewAttr: #tachys::html::attribute::Attribute>(
+                    self,
+                    attr: NewAttr,
+                ) -> Self::Output<NewAttr>
+                where
+                    Self::Output<NewAttr>: #tachys::view::RenderHtml,
+                {
+                    self.__into_translate_view().add_any_attr(attr)
+                }
+            }
+
+            impl #impl_generics #tachys::view::RenderHtml for #name #ty_generics #where_clause {
+                type AsyncOutput = #any_view;
+                type Owned = #any_view;
+
+                const MIN_LENGTH: usize = 0;
+
+                fn dry_resolve(&mut self) {}
+
+                fn resolve(self) -> impl ::core::future::Future<Output = Self::AsyncOutput> + ::core::marker::Send {
+                    self.__into_translate_view().resolve()
+                }
+
+                fn to_html_with_buf(
+                    self,
+                    buf: &mut String,
+                    position: &mut #tachys::view::Position,
+                    escape: bool,
+                    mark_branches: bool,
+                    extra_attrs: Vec<#leptos::attr::any_attribute::AnyAttribute>,
+                ) {
+                    self.__into_translate_view()
+                        .to_html_with_buf(buf, position, escape, mark_branches, extra_attrs)
+                }
+
+                fn to_html_async_with_buf<const OUT_OF_ORDER: bool>(
+                    self,
+                    buf: &mut #tachys::ssr::StreamBuilder,
+                    position: &mut #tachys::view::Position,
+                    escape: bool,
+                    mark_branches: bool,
+                    extra_attrs: Vec<#leptos::attr::any_attribute::AnyAttribute>,
+                ) {
+                    self.__into_translate_view().to_html_async_with_buf::<OUT_OF_ORDER>(
+                        buf,
+                        position,
+                        escape,
+                        mark_branches,
+                        extra_attrs,
+                    )
+                }
+
+                fn hydrate<const FROM_SERVER: bool>(
+                    self,
+                    cursor: &#tachys::hydration::Cursor,
+                    position: &#tachys::view::PositionState,
+                ) -> Self::State {
+                    self.__into_translate_view().hydrate::<FROM_SERVER>(cursor, position)
+                }
+
+                fn into_owned(self) -> Self::Owned {
+                    self.__into_translate_view()
+                }
+            }
+        };
+    }
+}
@@ -9,9 +9,11 @@
 //! This crate must be used with `leptos_i18n` and should'nt be used outside of it.
 
 mod data_provider;
+pub(crate) mod derive_translate;
 pub(crate) mod load_locales;
 pub(crate) mod scoped;
 pub(crate) mod t_format;
+pub(crate) mod t_key;
 pub(crate) mod t_macro;
 pub(crate) mod t_plural;
 
@@ -79,6 +81,31 @@ pub fn td_display(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     t_macro::t_macro(tokens, InputType::Locale, OutputType::Display)
 }
 
+#[proc_macro]
+pub fn t_html(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, InputType::Context, OutputType::Html)
+}
+
+#[proc_macro]
+pub fn tu_html(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, InputType::Untracked, OutputType::Html)
+}
+
+#[proc_macro]
+pub fn td_html(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, InputType::Locale, OutputType::Html)
+}
+
+#[proc_macro]
+pub fn td_entries(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::entries_macro(tokens, InputType::Locale)
+}
+
+#[proc_macro]
+pub fn t_key(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_key::t_key(tokens)
+}
+
 #[proc_macro]
 pub fn use_i18n_scoped(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     scoped::use_i18n_scoped(tokens)
@@ -235,3 +262,14 @@ pub fn td_plural_ordinal(tokens: proc_macro::TokenStream) -> proc_macro::TokenSt
 pub fn derive_icu_data_provider(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     data_provider::derive_icu_data_provider(input)
 }
+
+/// Derive the `Translate` trait, mapping each variant (or the struct itself) to a translation key.
+///
+/// The key can be set with `#[i18n(key = "status.pending")]`, and defaults to the variant name in snake case
+/// under the optional `#[i18n(prefix = "status")]` set on the type.
+/// The `i18n` module is looked up at `crate::i18n` unless `#[i18n(path = my_crate::i18n)]` is given.
+/// `IntoView` is implemented too, rendering the translation in the locale of the `I18nContext`.
+#[proc_macro_derive(Translate, attributes(i18n))]
+pub fn derive_translate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_translate::derive_translate(input)
+}
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, fmt::Display};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
 
 use leptos_i18n_parser::{
     formatters::Formatters,
@@ -46,8 +49,15 @@ pub fn declare_locales(tokens: proc_macro::TokenStream) -> proc_macro::TokenStre
         tracked_files: None,
     };
 
-    let result =
-        leptos_i18n_codegen::gen_code(&parsed_locales, Some(&crate_path), true, None, true);
+    let result = leptos_i18n_codegen::gen_code(
+        &parsed_locales,
+        Some(&crate_path),
+        true,
+        None,
+        true,
+        None,
+        false,
+    );
     match result {
         Ok(ts) => ts.into(),
         Err(err) => {
@@ -146,7 +156,9 @@ fn parse_map_values(
         top_locale_name: locale.clone(),
         name: name.clone(),
         keys,
+        markdown_keys: BTreeSet::new(),
         strings: vec![],
+        key_strings: BTreeMap::new(),
         top_locale_string_count: 0,
     }))))
 }
@@ -389,7 +401,9 @@ fn parse_locale(
         top_locale_name: locale_key.clone(),
         name: locale_key,
         keys,
+        markdown_keys: BTreeSet::new(),
         strings: vec![],
+        key_strings: BTreeMap::new(),
         top_locale_string_count: 0,
     })
 }
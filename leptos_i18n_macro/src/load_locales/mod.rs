@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use leptos_i18n_parser::parse_locales::{
     cfg_file::ConfigFile,
     error::{Error, Result},
+    options::Config,
 };
 use proc_macro2::TokenStream;
 
@@ -31,10 +32,11 @@ pub fn load_locales() -> Result<TokenStream> {
     let mut manifest_dir_path = get_manifest_dir()?;
 
     let cfg_file = ConfigFile::new(&mut manifest_dir_path)?;
-    let cfg = cfg_file.into();
+    let mut cfg: Config = cfg_file.into();
+    leptos_i18n_codegen::load_locales::hot_reload::configure(&mut cfg);
 
     let parsed_locales =
         leptos_i18n_parser::parse_locales::parse_locales(Some(manifest_dir_path), cfg)?;
 
-    leptos_i18n_codegen::gen_code(&parsed_locales, None, true, None, true)
+    leptos_i18n_codegen::gen_code(&parsed_locales, None, true, None, true, None, false)
 }
@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_macro_input;
+
+struct KeyParsedInput {
+    pub context: syn::Expr,
+    pub handle: syn::Expr,
+}
+
+impl syn::parse::Parse for KeyParsedInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let context = input.parse()?;
+        input.parse::<syn::token::Comma>()?;
+        let handle = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<syn::token::Comma>()?;
+        }
+        Ok(KeyParsedInput { context, handle })
+    }
+}
+
+pub fn t_key(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as KeyParsedInput);
+    t_key_inner(input).into()
+}
+
+fn t_key_inner(input: KeyParsedInput) -> TokenStream {
+    let KeyParsedInput { context, handle } = input;
+    let translate = quote! {
+        leptos_i18n::KeyHandle::translate(_handle, leptos_i18n::I18nContext::get_locale(#context))
+    };
+    if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+        quote! {{
+            let _handle = #handle;
+            leptos_i18n::__private::future_renderer(move || #translate)
+        }}
+    } else {
+        quote! {{
+            let _handle = #handle;
+            move || #translate
+        }}
+    }
+}
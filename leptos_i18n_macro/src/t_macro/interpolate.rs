@@ -119,7 +119,30 @@ impl InterpolatedValue {
         }
     }
 
-    pub fn param(&mut self) -> (Ident, TokenStream) {
+    /// If this is a component given as a closure taking its typed attributes.
+    pub fn is_typed_comp(&self) -> bool {
+        match self {
+            InterpolatedValue::AssignedComp { value, .. } => Self::is_typed_closure(value),
+            _ => false,
+        }
+    }
+
+    /// A closure without type annotations taking two arguments, `|attrs, children| ...`,
+    /// is given the typed attributes of the component.
+    fn is_typed_closure(value: &Expr) -> bool {
+        match value {
+            Expr::Closure(closure) => {
+                closure.inputs.len() == 2
+                    && closure
+                        .inputs
+                        .iter()
+                        .all(|input| !matches!(input, syn::Pat::Type(_)))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn param(&mut self, typed_comps_key: Option<&TokenStream>) -> (Ident, TokenStream) {
         match self {
             InterpolatedValue::Var(ident) => (ident.clone(), quote!(#ident)),
             InterpolatedValue::Comp(ident) => (ident.clone(), quote!(#ident)),
@@ -131,7 +154,18 @@ impl InterpolatedValue {
             }
             InterpolatedValue::AssignedComp { key, value } => {
                 let key = key.clone();
-                let ts = quote!(#value);
+                let ts = match typed_comps_key {
+                    Some(get_key) if Self::is_typed_closure(value) => {
+                        let typed_fn = format_ident!("__typed_comp_{}", key);
+                        // the typed function is deprecated and fails the build when the translations disagree on the attributes types.
+                        quote! {{
+                            #[deny(deprecated)]
+                            let __typed_comp = #get_key.#typed_fn(#value);
+                            __typed_comp
+                        }}
+                    }
+                    _ => quote!(#value),
+                };
                 *self = InterpolatedValue::Comp(key.clone());
                 (key.clone(), ts)
             }
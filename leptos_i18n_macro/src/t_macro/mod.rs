@@ -14,6 +14,7 @@ pub enum OutputType {
     View,
     String,
     Display,
+    Html,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,17 +44,45 @@ pub fn t_macro_inner(
         mut interpolations,
     } = input;
 
-    let get_key = input_type.get_key(context, keys);
+    // with the "key_inspector" feature the context is also used to get the locale of the marker,
+    // so it is evaluated once beforehand.
+    // the markers would be escaped in HTML.
+    let inspect =
+        (cfg!(feature = "key_inspector") && output_type != OutputType::Html).then(|| Inspect {
+            path: keys.path(),
+            locale: input_type.get_locale_str(quote!(_i18n_ctx), output_type),
+        });
+    // components given as `|attrs, children| ...` are typed by the key,
+    // it is only used for its type so the locale is not tracked.
+    let typed_comps = output_type == OutputType::View
+        && interpolations
+            .iter()
+            .flatten()
+            .any(InterpolatedValue::is_typed_comp);
+    // the context is evaluated once when it is used more than once.
+    let hoist_context = inspect.is_some() || typed_comps;
+    let get_key = if hoist_context {
+        input_type.get_key(quote!(_i18n_ctx), keys.clone())
+    } else {
+        input_type.get_key(&context, keys.clone())
+    };
+    let typed_comps_key =
+        typed_comps.then(|| input_type.untracked().get_key(quote!(_i18n_ctx), keys));
     let (builder_fn, build_fn) = output_type.build_fns();
 
     let (inner, params) = if let Some(interpolations) = interpolations.as_mut() {
-        let (keys, values): (Vec<_>, Vec<_>) =
-            interpolations.iter_mut().map(|inter| inter.param()).unzip();
+        let (keys, values): (Vec<_>, Vec<_>) = interpolations
+            .iter_mut()
+            .map(|inter| inter.param(typed_comps_key.as_ref()))
+            .unzip();
         let params = quote! {
             let (#(#keys,)*) = (#(#values,)*);
         };
 
-        let get_key = if matches!(output_type, OutputType::String | OutputType::Display) {
+        let get_key = if matches!(
+            output_type,
+            OutputType::String | OutputType::Display | OutputType::Html
+        ) {
             quote! {
                 leptos_i18n::__private::InterpolationStringBuilder::check(#get_key)
             }
@@ -84,15 +113,67 @@ pub fn t_macro_inner(
         (inner, None)
     };
 
-    output_type.wrapp(inner, params, interpolations.as_deref())
+    let output = output_type.wrapp(inner, params, interpolations.as_deref(), inspect.as_ref());
+    if hoist_context {
+        quote! {
+            {
+                let _i18n_ctx = #context;
+                #output
+            }
+        }
+    } else {
+        output
+    }
+}
+
+/// Key path and locale given to the key inspector.
+pub struct Inspect {
+    path: String,
+    locale: TokenStream,
+}
+
+pub fn entries_macro(
+    tokens: proc_macro::TokenStream,
+    input_type: InputType,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as ParsedInput);
+    entries_macro_inner(input, input_type)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+pub fn entries_macro_inner(input: ParsedInput, input_type: InputType) -> syn::Result<TokenStream> {
+    let ParsedInput {
+        context,
+        keys,
+        interpolations,
+    } = input;
+
+    if interpolations.is_some() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "entries macros don't take interpolations, only the locale and the subkeys path.",
+        ));
+    }
+
+    let get_key = input_type.get_key(context, keys);
+
+    Ok(quote! {
+        #get_key.__entries().map(|(key, value)| (key, value.build_string()))
+    })
 }
 
 impl OutputType {
     pub fn build_fns(self) -> (TokenStream, TokenStream) {
         match self {
+            // the Markdown components not given are rendered as the HTML element they are named after.
+            OutputType::View if cfg!(feature = "markdown") => {
+                (quote!(builder), quote!(fill_markdown().build().into_view))
+            }
             OutputType::View => (quote!(builder), quote!(build().into_view)),
             OutputType::String => (quote!(display_builder), quote!(build_string)),
             OutputType::Display => (quote!(display_builder), quote!(build_display)),
+            OutputType::Html => (quote!(html_builder), quote!(build_display)),
         }
     }
 
@@ -111,43 +192,101 @@ impl OutputType {
         ts: TokenStream,
         params: Option<TokenStream>,
         interpolations: Option<&[InterpolatedValue]>,
+        inspect: Option<&Inspect>,
     ) -> TokenStream {
         match self {
             OutputType::View => {
                 let clone_values = interpolations.map(Self::clone_values);
-                if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
+                let view = if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
                     quote! {
+                        leptos_i18n::__private::future_renderer(move || {
+                            #clone_values
+                            #ts
+                        })
+                    }
+                } else {
+                    quote! {
+                        move || {
+                            #clone_values
+                            #ts
+                        }
+                    }
+                };
+                match inspect {
+                    Some(Inspect { path, locale }) => quote! {
                         {
                             #params
-                            leptos_i18n::__private::future_renderer(move || {
+                            leptos_i18n::__private::inspector::mark_view(#path, move || #locale, move || {
                                 #clone_values
-                                #ts
+                                #view
                             })
                         }
-                    }
-                } else {
-                    quote! {
+                    },
+                    None => quote! {
                         {
                             #params
-                            move || {
-                                #clone_values
-                                #ts
-                            }
+                            #view
                         }
-                    }
+                    },
                 }
             }
-            OutputType::String | OutputType::Display => quote! {
+            // translations are futures with the "dynamic_load" feature.
+            OutputType::Html if cfg!(feature = "dynamic_load") => quote! {
                 {
                     #params
-                    #ts
+                    async move {
+                        leptos_i18n::html::Html::new(#ts.await)
+                    }
                 }
             },
+            OutputType::Html => quote! {
+                {
+                    #params
+                    leptos_i18n::html::Html::new(#ts)
+                }
+            },
+            OutputType::String | OutputType::Display => {
+                let tag_fn = match self {
+                    OutputType::Display => quote!(tag_display),
+                    _ => quote!(tag_string),
+                };
+                match inspect {
+                    // translations are futures with the "dynamic_load" feature.
+                    Some(Inspect { path, locale }) if cfg!(feature = "dynamic_load") => quote! {
+                        {
+                            #params
+                            let _locale = #locale;
+                            async move {
+                                leptos_i18n::__private::inspector::#tag_fn(#path, _locale, #ts.await)
+                            }
+                        }
+                    },
+                    Some(Inspect { path, locale }) => quote! {
+                        {
+                            #params
+                            leptos_i18n::__private::inspector::#tag_fn(#path, #locale, #ts)
+                        }
+                    },
+                    None => quote! {
+                        {
+                            #params
+                            #ts
+                        }
+                    },
+                }
+            }
         }
     }
 }
 
 impl InputType {
+    pub fn untracked(self) -> Self {
+        match self {
+            InputType::Context | InputType::Untracked => InputType::Untracked,
+            InputType::Locale => InputType::Locale,
+        }
+    }
+
     pub fn get_key<T: ToTokens>(self, input: T, keys: Keys) -> TokenStream {
         match self {
             InputType::Context => quote!(leptos_i18n::I18nContext::get_keys(#input).#keys()),
@@ -157,4 +296,18 @@ impl InputType {
             InputType::Locale => quote!(leptos_i18n::Locale::get_keys(#input).#keys()),
         }
     }
+
+    /// Return the locale as a `&'static str`, views track it to update the marker when it changes.
+    pub fn get_locale_str(self, input: TokenStream, output_type: OutputType) -> TokenStream {
+        let locale = match (self, output_type) {
+            (InputType::Context, OutputType::View) => {
+                quote!(leptos_i18n::I18nContext::get_locale(#input))
+            }
+            (InputType::Context | InputType::Untracked, _) => {
+                quote!(leptos_i18n::I18nContext::get_locale_untracked(#input))
+            }
+            (InputType::Locale, _) => input,
+        };
+        quote!(leptos_i18n::Locale::as_str(#locale))
+    }
 }
@@ -0,0 +1,71 @@
+//! Binary encoding of the translations strings, shared by the files written by `leptos_i18n_build` and the client decoding them:
+//! the number of strings as a little-endian `u32`, then each string prefixed by its length in bytes as a little-endian `u32`.
+
+/// Encode the strings.
+pub fn encode_strings<S: AsRef<str>>(strings: &[S]) -> Vec<u8> {
+    let len = strings.iter().map(|s| s.as_ref().len() + 4).sum::<usize>() + 4;
+    let mut buff = Vec::with_capacity(len);
+    buff.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+    for s in strings {
+        let s = s.as_ref();
+        buff.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        buff.extend_from_slice(s.as_bytes());
+    }
+    buff
+}
+
+fn read_u32(bytes: &mut &[u8]) -> Result<usize, &'static str> {
+    let (len, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or("unexpected end of translations file")?;
+    *bytes = rest;
+    Ok(u32::from_le_bytes(*len) as usize)
+}
+
+/// Decode strings encoded with `encode_strings`.
+pub fn decode_strings(mut bytes: &[u8]) -> Result<Vec<Box<str>>, &'static str> {
+    let count = read_u32(&mut bytes)?;
+    let mut strings = Vec::with_capacity(count.min(bytes.len() / 4));
+    for _ in 0..count {
+        let len = read_u32(&mut bytes)?;
+        if bytes.len() < len {
+            return Err("unexpected end of translations file");
+        }
+        let (s, rest) = bytes.split_at(len);
+        let s = std::str::from_utf8(s).map_err(|_| "invalid UTF-8 in translations file")?;
+        strings.push(Box::from(s));
+        bytes = rest;
+    }
+    Ok(strings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let strings = ["", "hello", "héllo wörld", "{{ count }}"];
+        let bytes = encode_strings(&strings);
+        assert_eq!(
+            bytes.len(),
+            4 + strings.iter().map(|s| s.len() + 4).sum::<usize>()
+        );
+        let decoded = decode_strings(&bytes).unwrap();
+        assert_eq!(decoded, strings.map(Box::from));
+    }
+
+    #[test]
+    fn empty() {
+        let bytes = encode_strings::<&str>(&[]);
+        assert_eq!(bytes, [0, 0, 0, 0]);
+        assert!(decode_strings(&bytes).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(decode_strings(&[2, 0, 0, 0, 5, 0, 0, 0, b'h', b'e']).is_err());
+        assert!(decode_strings(&[1, 0, 0, 0, 1, 0, 0, 0, 0xff]).is_err());
+        assert!(decode_strings(&[1, 0]).is_err());
+    }
+}
@@ -46,11 +46,16 @@ impl FormatterToTokens for CurrencyFormatter {
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct CurrencyCode(pub TinyAsciiStr<3>);
+enum CurrencyCode {
+    Code(TinyAsciiStr<3>),
+    /// `$user`, the currency of the user preferences of the context,
+    /// with the code used when the user has none, `USD` or the one given with `$user(EUR)`.
+    User(TinyAsciiStr<3>),
+}
 
 impl Default for CurrencyCode {
     fn default() -> Self {
-        Self(tinystr!(3, "USD"))
+        Self::Code(tinystr!(3, "USD"))
     }
 }
 
@@ -62,14 +67,19 @@ enum CurrencyWidth {
 }
 
 impl CurrencyCode {
+    fn parse_code(code: &str) -> Result<TinyAsciiStr<3>, Cow<'static, str>> {
+        TinyAsciiStr::try_from_str(code).map_err(|err| Cow::Owned(format!("Invalid code: {err}")))
+    }
+
     pub fn from_arg(arg: Option<&str>) -> Result<Self, Cow<'static, str>> {
         match arg {
-            Some(v) => match TinyAsciiStr::try_from_str(v) {
-                Ok(code) => Ok(Self(code)),
-                Err(err) => {
-                    let err = format!("Invalid code: {err}");
-                    Err(Cow::Owned(err))
-                }
+            Some("$user") => Ok(Self::User(tinystr!(3, "USD"))),
+            Some(v) => match v
+                .strip_prefix("$user(")
+                .and_then(|fallback| fallback.strip_suffix(')'))
+            {
+                Some(fallback) => Self::parse_code(fallback.trim()).map(Self::User),
+                None => Self::parse_code(v).map(Self::Code),
             },
             None => Err(Cow::Borrowed("missing currency code")),
         }
@@ -78,10 +88,22 @@ impl CurrencyCode {
 
 impl ToTokens for CurrencyCode {
     fn to_token_stream(&self) -> TokenStream {
-        let code = Literal::string(self.0.as_str());
-        quote!(l_i18n_crate::reexports::icu::currency::CurrencyCode(
-            l_i18n_crate::reexports::tinystr!(3, #code)
-        ))
+        match self {
+            Self::Code(code) => {
+                let code = Literal::string(code.as_str());
+                quote!(l_i18n_crate::reexports::icu::currency::CurrencyCode(
+                    l_i18n_crate::reexports::tinystr!(3, #code)
+                ))
+            }
+            Self::User(fallback) => {
+                let fallback = Literal::string(fallback.as_str());
+                quote!(l_i18n_crate::__private::user_currency_code(
+                    l_i18n_crate::reexports::icu::currency::CurrencyCode(
+                        l_i18n_crate::reexports::tinystr!(3, #fallback)
+                    )
+                ))
+            }
+        }
     }
 
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -105,3 +127,49 @@ impl_to_tokens!(
         Narrow
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[(&str, Option<&str>)]) -> Result<CurrencyFormatter, Cow<'static, str>> {
+        let parser = CurrencyFormatterParser;
+        let mut builder = parser.builder();
+        for (arg_name, arg) in args {
+            let field = parser.parse_arg_name(arg_name)?;
+            parser.parse_arg(&mut builder, field, *arg)?;
+        }
+        parser.build(builder)
+    }
+
+    #[test]
+    fn parse_user_currency_code() {
+        let formatter = parse(&[("currency_code", Some("$user"))]).unwrap();
+        assert_eq!(
+            formatter,
+            CurrencyFormatter(CurrencyWidth::Short, CurrencyCode::User(tinystr!(3, "USD")))
+        );
+        let view = formatter.to_view(&syn::parse_quote!(price), &syn::parse_quote!(_locale));
+        assert!(view.to_string().contains("user_currency_code"));
+        assert_eq!(
+            parse(&[("currency_code", Some("$user(EUR)"))]).unwrap(),
+            CurrencyFormatter(CurrencyWidth::Short, CurrencyCode::User(tinystr!(3, "EUR")))
+        );
+        assert!(parse(&[("currency_code", Some("$user(EURO)"))]).is_err());
+    }
+
+    #[test]
+    fn parse_currency_code() {
+        assert_eq!(
+            parse(&[("currency_code", Some("EUR")), ("width", Some("narrow"))]).unwrap(),
+            CurrencyFormatter(
+                CurrencyWidth::Narrow,
+                CurrencyCode::Code(tinystr!(3, "EUR"))
+            )
+        );
+        assert_eq!(parse(&[]).unwrap(), CurrencyFormatter::default());
+        assert!(parse(&[("currency_code", Some("EURO"))]).is_err());
+        assert!(parse(&[("currency_code", Some("$users"))]).is_err());
+        assert!(parse(&[("currency_code", None)]).is_err());
+    }
+}
@@ -269,6 +269,8 @@ pub enum VarBounds {
     #[default]
     None,
     AttributeValue,
+    /// Attribute value of a component nested in the children of another component.
+    NestedAttributeValue,
     Formatted {
         formatter_name: &'static str,
         to_tokens: Rc<dyn FormatterToTokens>,
@@ -287,6 +289,12 @@ impl VarBounds {
         match self {
             Self::None => quote!(l_i18n_crate::__private::InterpolateVar),
             Self::AttributeValue => quote!(l_i18n_crate::reexports::leptos::attr::AttributeValue),
+            // captured by the children of the components they are nested in, like `InterpolateVar`.
+            Self::NestedAttributeValue => quote!(
+                l_i18n_crate::reexports::leptos::attr::AttributeValue
+                    + ::core::marker::Send
+                    + ::core::marker::Sync
+            ),
             Self::Dummy => quote!(l_i18n_crate::__private::AnyBound),
             Self::Formatted { to_tokens, .. } => to_tokens.view_bounds(),
         }
@@ -294,7 +302,9 @@ impl VarBounds {
     pub fn fmt_bounds(&self) -> TokenStream {
         match self {
             Self::None => quote!(::std::fmt::Display),
-            Self::AttributeValue => quote!(l_i18n_crate::display::AttributeValue),
+            Self::AttributeValue | Self::NestedAttributeValue => {
+                quote!(l_i18n_crate::display::AttributeValue)
+            }
             Self::Dummy => quote!(l_i18n_crate::__private::AnyBound),
             Self::Formatted { to_tokens, .. } => to_tokens.fmt_bounds(),
         }
@@ -302,7 +312,7 @@ impl VarBounds {
 
     pub fn var_to_view(&self, key: &syn::Ident, locale_field: &syn::Ident) -> TokenStream {
         match self {
-            Self::AttributeValue => {
+            Self::AttributeValue | Self::NestedAttributeValue => {
                 unreachable!("attributes values should be rendered by the component renderer.")
             }
             Self::None => {
@@ -316,7 +326,7 @@ impl VarBounds {
     }
     pub fn var_fmt(&self, key: &Key, locale_field: &Key) -> TokenStream {
         match self {
-            Self::AttributeValue => {
+            Self::AttributeValue | Self::NestedAttributeValue => {
                 unreachable!("attributes values should be rendered by the component renderer.")
             }
             Self::None => {
@@ -330,7 +340,7 @@ impl VarBounds {
     }
     pub fn var_to_impl_display(self, key: &syn::Ident, locale_field: &syn::Ident) -> TokenStream {
         match self {
-            Self::AttributeValue => {
+            Self::AttributeValue | Self::NestedAttributeValue => {
                 unreachable!("attributes values should be rendered by the component renderer.")
             }
             Self::None => unreachable!(
@@ -368,8 +378,11 @@ impl Ord for VarBounds {
                 },
             ) => self_name.cmp(other_name),
             (VarBounds::AttributeValue, VarBounds::AttributeValue) => Ordering::Equal,
-            (_, VarBounds::AttributeValue) => Ordering::Less,
-            (VarBounds::AttributeValue, _) => Ordering::Greater,
+            (VarBounds::AttributeValue, VarBounds::NestedAttributeValue) => Ordering::Less,
+            (VarBounds::NestedAttributeValue, VarBounds::AttributeValue) => Ordering::Greater,
+            (VarBounds::NestedAttributeValue, VarBounds::NestedAttributeValue) => Ordering::Equal,
+            (_, VarBounds::AttributeValue | VarBounds::NestedAttributeValue) => Ordering::Less,
+            (VarBounds::AttributeValue | VarBounds::NestedAttributeValue, _) => Ordering::Greater,
         }
     }
 }
@@ -385,6 +398,7 @@ impl Debug for VarBounds {
         match self {
             VarBounds::Dummy => f.write_str("Dummy"),
             VarBounds::AttributeValue => f.write_str("AttributeValue"),
+            VarBounds::NestedAttributeValue => f.write_str("NestedAttributeValue"),
             VarBounds::None => f.write_str("None"),
             VarBounds::Formatted { formatter_name, .. } => f
                 .debug_struct("Formatted")
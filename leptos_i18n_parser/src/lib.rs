@@ -2,6 +2,7 @@
 #![deny(warnings)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod binary;
 pub mod formatters;
 pub mod parse_locales;
 pub mod utils;
@@ -130,6 +130,7 @@ pub enum Error {
     NoFileFormats,
     MultipleFilesFormats,
     MissingTranslationsURI,
+    MissingTranslationsHashes,
     InvalidFormatterArgName {
         loc: Location,
         name: String,
@@ -160,6 +161,10 @@ pub enum Error {
         arg_name: Key,
         foreign_key: KeyPath,
     },
+    InvalidMarkdown {
+        loc: Location,
+        err: String,
+    },
     UnknownLocaleInInherit {
         loc: &'static panic::Location<'static>,
         locale: String,
@@ -343,7 +348,14 @@ impl Display for Error {
             Error::MissingTranslationsURI => {
                 write!(
                     f,
-                    "{:?} config option is missing. You are using dynamic loading in CSR, that value is required.",
+                    "{:?} config option is missing. You are using dynamic loading in CSR or with static translations, that value is required.",
+                    cfg_file::Field::TRANSLATIONS_URI
+                )
+            }
+            Error::MissingTranslationsHashes => {
+                write!(
+                    f,
+                    "{:?} config option contains the \"{{hash}}\" placeholder, but the files hashes are unknown. Generate the `i18n` module with a manifest of translations files written with hashed file names.",
                     cfg_file::Field::TRANSLATIONS_URI
                 )
             }
@@ -375,6 +387,9 @@ impl Display for Error {
                 f,
                 "Invalid component attribute value {attr_value:?} for attribute {attr_name:?} at {loc}: {err}"
             ),
+            Error::InvalidMarkdown { loc, err } => {
+                write!(f, "Invalid markdown at {loc}: {err}")
+            }
             Error::InvalidForeignKeyArgForAttribute {
                 loc,
                 arg_name,
@@ -485,6 +500,9 @@ pub enum Warning {
         formatter_name: String,
         chars: String,
     },
+    LiteUnsupportedKey {
+        key_path: KeyPath,
+    },
     Custom(String),
 }
 
@@ -536,6 +554,10 @@ impl Display for Warning {
                 f,
                 "File path for locale {locale:?} in namespace {ns:?} is not valid UTF8, can't add it to build script depedencies. Path: {path:?}"
             ),
+            Warning::LiteUnsupportedKey { key_path } => write!(
+                f,
+                "Key \"{key_path}\" uses plurals, ranges or formatters, it is not generated in the lite module"
+            ),
             Warning::Custom(warn) => write!(f, "{warn}"),
             Warning::UnexpectedCharsAfterFormatter {
                 loc,
@@ -65,7 +65,11 @@ pub struct Locale {
     pub top_locale_name: Key,
     pub name: Key,
     pub keys: BTreeMap<Key, ParsedValue>,
+    /// Keys parsed as Markdown, see `InterpolationKeys::is_markdown_comp`.
+    pub markdown_keys: BTreeSet<Key>,
     pub strings: Vec<Rc<str>>,
+    /// Indices in `strings` of the strings used by each key, in the order they are used.
+    pub key_strings: BTreeMap<Rc<str>, Vec<usize>>,
     pub top_locale_string_count: usize,
 }
 
@@ -97,6 +101,9 @@ pub struct VarInfo {
 pub struct InterpolationKeys {
     components: BTreeSet<Key>,
     components_self_closed: BTreeSet<Key>,
+    components_attributes: BTreeMap<Key, ComponentAttributes>,
+    /// Components coming from the Markdown of a translation.
+    markdown_components: BTreeSet<Key>,
     variables: BTreeMap<Key, VarInfo>,
 }
 
@@ -109,6 +116,64 @@ pub enum LiteralType {
     Float,
 }
 
+impl std::fmt::Display for LiteralType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LiteralType::String => f.write_str("string"),
+            LiteralType::Bool => f.write_str("boolean"),
+            LiteralType::Signed => f.write_str("signed integer"),
+            LiteralType::Unsigned => f.write_str("unsigned integer"),
+            LiteralType::Float => f.write_str("float"),
+        }
+    }
+}
+
+/// Type of a component attribute: a literal, or the variable it is bound to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeType {
+    Literal(LiteralType),
+    Variable(Key),
+}
+
+impl std::fmt::Display for AttributeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeType::Literal(lit_type) => std::fmt::Display::fmt(lit_type, f),
+            AttributeType::Variable(key) => {
+                let name = key.name.strip_prefix("var_").unwrap_or(&key.name);
+                write!(f, "variable {name:?}")
+            }
+        }
+    }
+}
+
+/// Attributes given to a component, across all its uses in all locales.
+#[derive(Debug, Default)]
+pub struct ComponentAttributes {
+    uses: usize,
+    attributes: BTreeMap<String, (AttributeType, usize)>,
+    conflicts: BTreeMap<String, (AttributeType, AttributeType)>,
+}
+
+impl ComponentAttributes {
+    /// Iterate over the attributes with their type, and if they are missing from some uses of the component.
+    ///
+    /// Attributes given different types by some uses are skipped, see `conflicts`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AttributeType, bool)> {
+        self.attributes
+            .iter()
+            .filter(|(name, _)| !self.conflicts.contains_key(*name))
+            .map(|(name, (attr_type, count))| (name.as_str(), attr_type, *count < self.uses))
+    }
+
+    /// Iterate over the attributes given different types by some uses of the component, with the first two types found.
+    pub fn conflicts(&self) -> impl Iterator<Item = (&str, &AttributeType, &AttributeType)> {
+        self.conflicts
+            .iter()
+            .map(|(name, (type1, type2))| (name.as_str(), type1, type2))
+    }
+}
+
 #[derive(Debug)]
 pub enum InterpolOrLit {
     Interpol(InterpolationKeys),
@@ -267,6 +332,37 @@ impl InterpolationKeys {
         self.components.insert(key);
     }
 
+    /// Register a use of the component `comp` with the given attributes.
+    pub fn push_comp_attributes<'a>(
+        &mut self,
+        comp: &Key,
+        attributes: impl IntoIterator<Item = (&'a str, AttributeType)>,
+    ) {
+        let comp_attrs = self.components_attributes.entry(comp.clone()).or_default();
+        comp_attrs.uses += 1;
+        let mut seen = BTreeSet::new();
+        for (name, attr_type) in attributes {
+            if !seen.insert(name) {
+                continue;
+            }
+            match comp_attrs.attributes.entry(name.to_owned()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((attr_type, 1));
+                }
+                Entry::Occupied(mut entry) => {
+                    let (old_type, count) = entry.get_mut();
+                    *count += 1;
+                    if *old_type != attr_type {
+                        comp_attrs
+                            .conflicts
+                            .entry(name.to_owned())
+                            .or_insert_with(|| (old_type.clone(), attr_type));
+                    }
+                }
+            }
+        }
+    }
+
     pub fn push_count(
         &mut self,
         key_path: &mut KeyPath,
@@ -312,6 +408,25 @@ impl InterpolationKeys {
     pub fn iter_comps_self_closed(&self) -> impl Iterator<Item = Key> + '_ {
         self.components_self_closed.iter().cloned()
     }
+
+    pub fn get_comp_attributes(&self, comp: &Key) -> Option<&ComponentAttributes> {
+        self.components_attributes.get(comp)
+    }
+
+    /// Mark the components of `markdown`, the keys of a Markdown translation, as coming from Markdown.
+    pub fn push_markdown_comps(&mut self, markdown: &InterpolationKeys) {
+        let comps = markdown
+            .components
+            .iter()
+            .chain(&markdown.components_self_closed);
+        self.markdown_components.extend(comps.cloned());
+    }
+
+    /// Components coming from Markdown are rendered as the HTML element they are named after
+    /// when they are not given to `t!`.
+    pub fn is_markdown_comp(&self, comp: &Key) -> bool {
+        self.markdown_components.contains(comp)
+    }
 }
 
 impl BuildersKeysInner {
@@ -660,6 +775,9 @@ impl Locale {
                 diag,
                 options,
             )?;
+            if self.markdown_keys.contains(key) {
+                push_markdown_comps(value, keys, &mut pushed_key)?;
+            }
         }
 
         if !options.suppress_key_warnings {
@@ -686,14 +804,34 @@ impl Locale {
         for (key, value) in &mut self.keys {
             value.reduce();
             let mut pushed_key = key_path.push_key(key.clone());
-            let locale_value =
+            let mut locale_value =
                 value.make_locale_value(&self.top_locale_name, &mut pushed_key, strings)?;
+            if self.markdown_keys.contains(key) {
+                push_markdown_comps(value, &mut locale_value, &mut pushed_key)?;
+            }
             let key = pushed_key.pop().unwrap_at("make_builder_keys_1");
             keys.0.insert(key, locale_value);
         }
         Ok(keys)
     }
 
+    pub fn strip_inherited_values(&mut self, parent: &Locale) {
+        for (key, value) in &mut self.keys {
+            let Some(parent_value) = parent.keys.get(key) else {
+                continue;
+            };
+            if let (
+                ParsedValue::Subkeys(Some(subkeys)),
+                ParsedValue::Subkeys(Some(parent_subkeys)),
+            ) = (&mut *value, parent_value)
+            {
+                subkeys.strip_inherited_values(parent_subkeys);
+            } else if value == parent_value {
+                *value = ParsedValue::Default;
+            }
+        }
+    }
+
     pub fn update_top_locale_name(&mut self, top_locale_name: &Key) {
         self.top_locale_name = top_locale_name.clone();
         for value in self.keys.values_mut() {
@@ -708,6 +846,25 @@ impl Locale {
     }
 }
 
+/// The components of a Markdown translation can be given a default rendering, see `InterpolationKeys::is_markdown_comp`.
+fn push_markdown_comps(
+    value: &ParsedValue,
+    locale_value: &mut LocaleValue,
+    key_path: &mut KeyPath,
+) -> Result<()> {
+    let LocaleValue::Value {
+        value: InterpolOrLit::Interpol(keys),
+        ..
+    } = locale_value
+    else {
+        return Ok(());
+    };
+    if let InterpolOrLit::Interpol(markdown) = value.get_keys(key_path)? {
+        keys.push_markdown_comps(&markdown);
+    }
+    Ok(())
+}
+
 impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
     type Value = Locale;
 
@@ -715,7 +872,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
     where
         D: serde::Deserializer<'de>,
     {
-        let keys = deserializer.deserialize_map(self.clone())?;
+        let (keys, markdown_keys) = deserializer.deserialize_map(self.clone())?;
         let Self {
             name,
             top_locale_name,
@@ -724,37 +881,59 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
         Ok(Locale {
             name,
             keys,
+            markdown_keys,
             top_locale_name,
             strings: vec![],
+            key_strings: BTreeMap::new(),
             top_locale_string_count: 0,
         })
     }
 }
 
 impl<'de> serde::de::Visitor<'de> for LocaleSeed<'_> {
-    type Value = BTreeMap<Key, ParsedValue>;
+    type Value = (BTreeMap<Key, ParsedValue>, BTreeSet<Key>);
 
     fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
         let mut keys = BTreeMap::new();
+        let mut markdown_keys = BTreeSet::new();
 
         while let Some(locale_key) = map.next_key::<Key>()? {
+            #[cfg(feature = "markdown")]
+            let (locale_key, markdown) = match super::markdown::strip_markdown_suffix(&locale_key) {
+                Some(key) => (key, true),
+                None => (locale_key, false),
+            };
+            #[cfg(not(feature = "markdown"))]
+            let markdown = false;
+
             let pushed_key = self.key_path.push_key(locale_key.clone());
             let value = map.next_value_seed(ParsedValueSeed {
                 top_locale_name: &self.top_locale_name,
                 key: &locale_key,
                 key_path: &pushed_key,
                 in_range: false,
+                markdown,
                 foreign_keys_paths: self.foreign_keys_paths,
                 diag: self.diag,
                 formatters: self.formatters,
             })?;
+            // `help` and `help_md` would be the same key.
+            if keys.contains_key(&locale_key) && (markdown || markdown_keys.contains(&locale_key)) {
+                return Err(serde::de::Error::custom(Error::InvalidMarkdown {
+                    loc: Location::new(self.top_locale_name.clone(), (*pushed_key).clone()),
+                    err: "the key is declared both with and without the markdown suffix".to_owned(),
+                }));
+            }
+            if markdown {
+                markdown_keys.insert(locale_key.clone());
+            }
             keys.insert(locale_key, value);
         }
 
-        Ok(keys)
+        Ok((keys, markdown_keys))
     }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -0,0 +1,466 @@
+//! Parsing of the keys written in Markdown, enabled by the "markdown" feature.
+//!
+//! A key with the `_md` suffix, such as `help_md`, is parsed as Markdown and accessed without the suffix (`help`).
+//! The Markdown elements are mapped to components named after the HTML tag they would render to:
+//! `*emphasis*` is `<em>`, `[a link](/url)` is `<a href="/url">`, a list is `<ul>` with `<li>` items, and so on.
+//! The text is still interpolated, so variables and foreign keys work inside paragraphs.
+//!
+//! A value made of a single paragraph is not wrapped in a `<p>` component, so short keys only need the inline components.
+
+use pulldown_cmark::{Event, Options, Parser, Tag};
+
+use super::{
+    error::{BoxedError, Error, Result},
+    parsed_value::{Attribute, AttributeValue, Attributes, Literal, ParsedValue},
+};
+use crate::utils::{Key, ParseContext, UnwrapAt};
+
+/// Suffix of the keys parsed as Markdown.
+pub const MARKDOWN_SUFFIX: &str = "_md";
+
+/// Return the key without the Markdown suffix if it has one.
+pub fn strip_markdown_suffix(key: &Key) -> Option<Key> {
+    let name = key.name.strip_suffix(MARKDOWN_SUFFIX)?;
+    Key::new(name)
+}
+
+struct Element {
+    tag: &'static str,
+    attributes: Vec<Attribute>,
+    children: Vec<ParsedValue>,
+    /// The text of code blocks and of image descriptions is not interpolated.
+    raw_text: bool,
+}
+
+impl Element {
+    fn new(tag: &'static str) -> Self {
+        Element {
+            tag,
+            attributes: Vec::new(),
+            children: Vec::new(),
+            raw_text: false,
+        }
+    }
+
+    fn raw_text(self) -> Self {
+        Element {
+            raw_text: true,
+            ..self
+        }
+    }
+
+    fn attr(mut self, key: &str, value: AttributeValue) -> Self {
+        self.attributes.push(Attribute {
+            key: key.to_owned(),
+            value: Some(value),
+        });
+        self
+    }
+
+    fn from_tag(ctx: &ParseContext, tag: Tag) -> Result<Self> {
+        let element = match tag {
+            Tag::Paragraph => Element::new("p"),
+            Tag::Heading { level, .. } => Element::new(match level as usize {
+                1 => "h1",
+                2 => "h2",
+                3 => "h3",
+                4 => "h4",
+                5 => "h5",
+                _ => "h6",
+            }),
+            Tag::BlockQuote(_) => Element::new("blockquote"),
+            Tag::CodeBlock(_) => Element::new("pre").raw_text(),
+            Tag::List(None) => Element::new("ul"),
+            Tag::List(Some(1)) => Element::new("ol"),
+            Tag::List(Some(start)) => {
+                Element::new("ol").attr("start", AttributeValue::Literal(Literal::Unsigned(start)))
+            }
+            Tag::Item => Element::new("li"),
+            Tag::Emphasis => Element::new("em"),
+            Tag::Strong => Element::new("strong"),
+            Tag::Link {
+                dest_url, title, ..
+            } => {
+                let element = Element::new("a").attr("href", url_value(ctx, &dest_url)?);
+                with_title(element, &title)
+            }
+            Tag::Image {
+                dest_url, title, ..
+            } => {
+                let element = Element::new("img")
+                    .raw_text()
+                    .attr("src", url_value(ctx, &dest_url)?);
+                with_title(element, &title)
+            }
+            tag => {
+                return Err(invalid_markdown(
+                    ctx,
+                    format!("unsupported element {tag:?}"),
+                ));
+            }
+        };
+        Ok(element)
+    }
+
+    fn into_component(self) -> ParsedValue {
+        let Element {
+            tag,
+            mut attributes,
+            children,
+            ..
+        } = self;
+        let key = Key::new(&format!("comp_{tag}")).unwrap_at("markdown_into_component");
+        // images are self-closed, their description is the `alt` attribute.
+        let inner = if tag == "img" {
+            let alt = children
+                .iter()
+                .filter_map(|child| match child {
+                    ParsedValue::Literal(Literal::String(s, _)) => Some(s.as_str()),
+                    _ => None,
+                })
+                .collect::<String>();
+            attributes.push(Attribute {
+                key: "alt".to_owned(),
+                value: Some(AttributeValue::Literal(string_literal(alt))),
+            });
+            None
+        } else {
+            Some(Box::new(into_value(children)))
+        };
+        ParsedValue::Component {
+            key,
+            inner,
+            attributes: Attributes(attributes),
+        }
+    }
+}
+
+fn with_title(element: Element, title: &str) -> Element {
+    if title.is_empty() {
+        element
+    } else {
+        element.attr(
+            "title",
+            AttributeValue::Literal(string_literal(title.to_owned())),
+        )
+    }
+}
+
+/// An URL of `{{ name }}` is the variable `name`, for links to pages only known at runtime.
+fn url_value(ctx: &ParseContext, url: &str) -> Result<AttributeValue> {
+    match url
+        .strip_prefix("{{")
+        .and_then(|url| url.strip_suffix("}}"))
+    {
+        Some(name) => {
+            let key = Key::try_new(&format!("var_{}", name.trim()))
+                .map_err(|_| invalid_markdown(ctx, format!("invalid variable in URL {url:?}")))?;
+            Ok(AttributeValue::Variable(key))
+        }
+        None => Ok(AttributeValue::Literal(string_literal(url.to_owned()))),
+    }
+}
+
+fn string_literal(s: String) -> Literal {
+    Literal::String(s, usize::MAX)
+}
+
+fn invalid_markdown(ctx: &ParseContext, err: String) -> BoxedError {
+    Error::InvalidMarkdown {
+        loc: ctx.into(),
+        err,
+    }
+    .into()
+}
+
+fn into_value(mut values: Vec<ParsedValue>) -> ParsedValue {
+    match values.len() {
+        0 => ParsedValue::default(),
+        1 => values.pop().unwrap_at("markdown_into_value"),
+        _ => ParsedValue::Bloc(values),
+    }
+}
+
+/// Stack of the elements being parsed, the text is buffered as Markdown can split it in multiple events.
+struct Builder<'a> {
+    text_ctx: ParseContext<'a>,
+    stack: Vec<Element>,
+    root: Vec<ParsedValue>,
+    text: String,
+}
+
+impl Builder<'_> {
+    fn push(&mut self, value: ParsedValue) {
+        match self.stack.last_mut() {
+            Some(element) => element.children.push(value),
+            None => self.root.push(value),
+        }
+    }
+
+    fn flush_text(&mut self) -> Result<()> {
+        if self.text.is_empty() {
+            return Ok(());
+        }
+        let text = std::mem::take(&mut self.text);
+        let value = if self.stack.last().is_some_and(|element| element.raw_text) {
+            ParsedValue::Literal(string_literal(text))
+        } else {
+            ParsedValue::new(&self.text_ctx, &text)?
+        };
+        self.push(value);
+        Ok(())
+    }
+
+    fn push_empty(&mut self, tag: &'static str) {
+        let key = Key::new(&format!("comp_{tag}")).unwrap_at("markdown_push_empty");
+        self.push(ParsedValue::Component {
+            key,
+            inner: None,
+            attributes: Attributes::default(),
+        });
+    }
+}
+
+/// Parse `value` as Markdown.
+pub fn parse_markdown(ctx: &ParseContext, value: &str) -> Result<ParsedValue> {
+    let mut builder = Builder {
+        text_ctx: ParseContext {
+            parse_fns: ParsedValue::TEXT_FNS,
+            ..*ctx
+        },
+        stack: Vec::new(),
+        root: Vec::new(),
+        text: String::new(),
+    };
+
+    for event in Parser::new_ext(value, Options::empty()) {
+        if !matches!(event, Event::Text(_) | Event::SoftBreak) {
+            builder.flush_text()?;
+        }
+        match event {
+            Event::Start(tag) => {
+                let element = Element::from_tag(ctx, tag)?;
+                builder.stack.push(element);
+            }
+            Event::End(_) => {
+                let element = builder.stack.pop().unwrap_at("parse_markdown_1");
+                builder.push(element.into_component());
+            }
+            Event::Text(text) => builder.text.push_str(&text),
+            Event::SoftBreak => builder.text.push('\n'),
+            Event::Code(code) => {
+                let key = Key::new("comp_code").unwrap_at("parse_markdown_2");
+                builder.push(ParsedValue::Component {
+                    key,
+                    inner: Some(Box::new(ParsedValue::Literal(string_literal(
+                        code.into_string(),
+                    )))),
+                    attributes: Attributes::default(),
+                });
+            }
+            Event::HardBreak => builder.push_empty("br"),
+            Event::Rule => builder.push_empty("hr"),
+            Event::Html(html) | Event::InlineHtml(html) => {
+                return Err(invalid_markdown(
+                    ctx,
+                    format!("HTML is not supported in markdown, found {html:?}"),
+                ));
+            }
+            event => {
+                return Err(invalid_markdown(
+                    ctx,
+                    format!("unsupported markdown {event:?}"),
+                ));
+            }
+        }
+    }
+    builder.flush_text()?;
+
+    let mut root = builder.root;
+    if let [ParsedValue::Component { key, inner, .. }] = root.as_mut_slice()
+        && &*key.name == "comp_p"
+    {
+        return Ok(inner.take().map(|inner| *inner).unwrap_or_default());
+    }
+    Ok(into_value(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::{
+        formatters::{Formatters, VarBounds},
+        parse_locales::{
+            ForeignKeysPaths, StringIndexer,
+            error::Diagnostics,
+            locale::{DefaultTo, Locale, LocaleValue},
+            options::ParseOptions,
+        },
+        utils::{KeyPath, Loc},
+    };
+
+    fn parse(value: &str) -> Result<ParsedValue> {
+        let key_path = KeyPath::new(None);
+        let locale = Key::new("en").unwrap();
+        let foreign_keys_paths = ForeignKeysPaths::new();
+        let formatters = Formatters::new();
+        let diag = Diagnostics::new();
+        let ctx = ParseContext {
+            loc: Loc {
+                key_path: &key_path,
+                locale: &locale,
+            },
+            foreign_keys_paths: &foreign_keys_paths,
+            formatters: &formatters,
+            diag: &diag,
+            parse_fns: ParsedValue::DEFAULT_FNS,
+        };
+        parse_markdown(&ctx, value)
+    }
+
+    fn lit(s: &str) -> ParsedValue {
+        ParsedValue::Literal(string_literal(s.to_owned()))
+    }
+
+    fn comp(tag: &str, inner: ParsedValue) -> ParsedValue {
+        ParsedValue::Component {
+            key: Key::new(&format!("comp_{tag}")).unwrap(),
+            inner: Some(Box::new(inner)),
+            attributes: Attributes::default(),
+        }
+    }
+
+    #[test]
+    fn inline_paragraph() {
+        assert_eq!(
+            parse("Hello *world* and **you**").unwrap(),
+            ParsedValue::Bloc(vec![
+                lit("Hello "),
+                comp("em", lit("world")),
+                lit(" and "),
+                comp("strong", lit("you")),
+            ])
+        );
+    }
+
+    #[test]
+    fn interpolation_in_paragraphs() {
+        let value = parse("Hi {{ name }}!\n\nBye").unwrap();
+        let var = ParsedValue::Variable {
+            key: Key::new("var_name").unwrap(),
+            bounds: VarBounds::None,
+        };
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                comp("p", ParsedValue::Bloc(vec![lit("Hi "), var, lit("!")])),
+                comp("p", lit("Bye")),
+            ])
+        );
+    }
+
+    #[test]
+    fn links_and_lists() {
+        let value = parse("- [docs](/docs \"Docs\")\n- [profile](<{{ url }}>)").unwrap();
+        let link = |inner: &str, href: AttributeValue, title: Option<&str>| {
+            let mut attributes = vec![Attribute {
+                key: "href".to_owned(),
+                value: Some(href),
+            }];
+            if let Some(title) = title {
+                attributes.push(Attribute {
+                    key: "title".to_owned(),
+                    value: Some(AttributeValue::Literal(string_literal(title.to_owned()))),
+                });
+            }
+            ParsedValue::Component {
+                key: Key::new("comp_a").unwrap(),
+                inner: Some(Box::new(lit(inner))),
+                attributes: Attributes(attributes),
+            }
+        };
+        assert_eq!(
+            value,
+            comp(
+                "ul",
+                ParsedValue::Bloc(vec![
+                    comp(
+                        "li",
+                        link(
+                            "docs",
+                            AttributeValue::Literal(string_literal("/docs".to_owned())),
+                            Some("Docs")
+                        )
+                    ),
+                    comp(
+                        "li",
+                        link(
+                            "profile",
+                            AttributeValue::Variable(Key::new("var_url").unwrap()),
+                            None
+                        )
+                    ),
+                ])
+            )
+        );
+    }
+
+    #[test]
+    fn code_is_not_interpolated() {
+        assert_eq!(parse("`{{ a }}`").unwrap(), comp("code", lit("{{ a }}")));
+    }
+
+    #[test]
+    fn html_is_rejected() {
+        assert!(parse("a <b>b</b>").is_err());
+    }
+
+    #[test]
+    fn markdown_components() {
+        let key = Key::new("note").unwrap();
+        let locale = |name: &str, value: ParsedValue, markdown: bool| {
+            let name = Key::new(name).unwrap();
+            Locale {
+                top_locale_name: name.clone(),
+                name,
+                keys: BTreeMap::from([(key.clone(), value)]),
+                markdown_keys: markdown.then(|| key.clone()).into_iter().collect(),
+                strings: vec![],
+                key_strings: BTreeMap::new(),
+                top_locale_string_count: 0,
+            }
+        };
+        let mut en = locale("en", parse("Read the *docs*").unwrap(), true);
+        let mut fr = locale("fr", comp("b", lit("doc")), false);
+
+        let mut key_path = KeyPath::new(None);
+        let mut keys = en
+            .make_builder_keys(&mut key_path, &mut StringIndexer::new(false))
+            .unwrap();
+        fr.merge(
+            &mut keys,
+            fr.top_locale_name.clone(),
+            &DefaultTo::Explicit(en.name.clone()),
+            &mut key_path,
+            &mut StringIndexer::new(false),
+            &Diagnostics::new(),
+            &ParseOptions::default(),
+        )
+        .unwrap();
+
+        let Some(LocaleValue::Value { value, .. }) = keys.0.get(&key) else {
+            panic!("missing key");
+        };
+        let keys = value.is_interpol().unwrap();
+        let markdown_comps = keys
+            .iter_comps()
+            .filter(|comp| keys.is_markdown_comp(comp))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            markdown_comps,
+            BTreeSet::from([Key::new("comp_em").unwrap()])
+        );
+    }
+}
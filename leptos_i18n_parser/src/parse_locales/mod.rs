@@ -11,6 +11,8 @@ use locale::{BuildersKeys, BuildersKeysInner, DefaultTo, Locale, LocalesOrNamesp
 pub mod cfg_file;
 pub mod error;
 pub mod locale;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod options;
 pub mod parsed_value;
 pub mod plurals;
@@ -267,13 +269,16 @@ fn check_locales_inner(
     options: &ParseOptions,
 ) -> Result<BuildersKeysInner> {
     let icu_locales = locales_to_icu(locales)?;
+    if options.runtime_fallback {
+        strip_inherited_values(locales, extensions, &icu_locales);
+    }
     let (default_locale, other_locales) =
         locales.split_first_mut().unwrap_at("check_locales_inner_1");
     let mut key_path = KeyPath::new(namespace);
 
-    let mut string_indexer = StringIndexer::default();
+    let mut string_indexer = StringIndexer::new(options.deduplicate_strings);
     let mut default_keys = default_locale.make_builder_keys(&mut key_path, &mut string_indexer)?;
-    default_locale.strings = string_indexer.get_strings();
+    (default_locale.strings, default_locale.key_strings) = string_indexer.get_strings();
     default_locale.top_locale_string_count = default_locale.strings.len();
 
     for locale in other_locales {
@@ -287,7 +292,7 @@ fn check_locales_inner(
             options.suppress_key_warnings,
         );
 
-        let mut string_indexer = StringIndexer::default();
+        let mut string_indexer = StringIndexer::new(options.deduplicate_strings);
 
         locale.merge(
             &mut default_keys,
@@ -298,7 +303,7 @@ fn check_locales_inner(
             diag,
             options,
         )?;
-        locale.strings = string_indexer.get_strings();
+        (locale.strings, locale.key_strings) = string_indexer.get_strings();
         locale.top_locale_string_count = locale.strings.len();
     }
 
@@ -307,27 +312,87 @@ fn check_locales_inner(
     Ok(default_keys)
 }
 
+/// Replace the values of a locale that are identical to the ones of the locale it inherits from by defaults,
+/// so its translation unit only contains its overrides and the parent strings are looked up at runtime.
+///
+/// Locales only defaulting to the default locale are left untouched,
+/// a value identical to the default one there is more likely to be an untranslated string.
+fn strip_inherited_values(
+    locales: &mut [Locale],
+    extensions: &BTreeMap<Key, Key>,
+    icu_locales: &BTreeMap<Key, icu_locale::Locale>,
+) {
+    let Some(default_locale) = locales.first().map(|locale| locale.top_locale_name.clone()) else {
+        return;
+    };
+    let originals = locales.to_vec();
+    for locale in locales.iter_mut().skip(1) {
+        let default_to = get_locale_fallback(
+            extensions,
+            icu_locales,
+            &default_locale,
+            &locale.top_locale_name,
+            false,
+        );
+        let DefaultTo::Explicit(parent) = default_to else {
+            continue;
+        };
+        if let Some(parent) = originals
+            .iter()
+            .find(|locale| locale.top_locale_name == parent)
+        {
+            locale.strip_inherited_values(parent);
+        }
+    }
+}
+
+/// Indices of the strings used by each key, by key path.
+pub type KeyStrings = BTreeMap<Rc<str>, Vec<usize>>;
+
 #[derive(Default)]
 pub struct StringIndexer {
     current: HashMap<Rc<str>, usize>,
     acc: Vec<Rc<str>>,
+    current_key_path: Rc<str>,
+    key_strings: KeyStrings,
+    no_dedup: bool,
 }
 
 impl StringIndexer {
-    pub fn push_str(&mut self, s: &str) -> usize {
-        if let Some(index) = self.current.get(s) {
-            *index
-        } else {
-            let i = self.acc.len();
-            let s: Rc<str> = Rc::from(s);
-            self.acc.push(s.clone());
-            self.current.insert(s, i);
-            i
+    /// Create an indexer, identical strings share the same index if `deduplicate` is true.
+    pub fn new(deduplicate: bool) -> Self {
+        StringIndexer {
+            no_dedup: !deduplicate,
+            ..Default::default()
         }
     }
 
-    pub fn get_strings(self) -> Vec<Rc<str>> {
-        self.acc
+    /// Set the key path recorded for the strings pushed next.
+    pub fn set_key_path(&mut self, key_path: &KeyPath) {
+        self.current_key_path = Rc::from(key_path.to_string());
+    }
+
+    pub fn push_str(&mut self, s: &str) -> usize {
+        let index = match self.current.get(s) {
+            Some(index) if !self.no_dedup => *index,
+            _ => {
+                let i = self.acc.len();
+                let s: Rc<str> = Rc::from(s);
+                self.acc.push(s.clone());
+                self.current.insert(s, i);
+                i
+            }
+        };
+        self.key_strings
+            .entry(self.current_key_path.clone())
+            .or_default()
+            .push(index);
+        index
+    }
+
+    /// Return the strings and the indices of the strings used by each key.
+    pub fn get_strings(self) -> (Vec<Rc<str>>, KeyStrings) {
+        (self.acc, self.key_strings)
     }
 }
 
@@ -351,6 +416,7 @@ impl ForeignKeysPaths {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use parsed_value::{Literal, ParsedValue};
 
     macro_rules! make_icu_locale {
         ($val: literal) => {
@@ -418,4 +484,83 @@ mod tests {
         check_fallback!(icu_locales, "en-US", "en");
         check_fallback!(icu_locales, "en"); // default
     }
+
+    fn make_locale(name: &str, keys: &[(&str, &str)]) -> Locale {
+        let name = Key::new(name).unwrap();
+        let keys = keys
+            .iter()
+            .map(|(key, value)| {
+                let value = ParsedValue::Literal(Literal::String(value.to_string(), usize::MAX));
+                (Key::new(key).unwrap(), value)
+            })
+            .collect();
+        Locale {
+            top_locale_name: name.clone(),
+            name,
+            keys,
+            markdown_keys: BTreeSet::new(),
+            strings: vec![],
+            key_strings: BTreeMap::new(),
+            top_locale_string_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_strip_inherited_values() {
+        let mut locales = [
+            make_locale("en", &[("hello", "Hello"), ("color", "colour")]),
+            make_locale("en-US", &[("hello", "Hello"), ("color", "color")]),
+            make_locale("fr", &[("hello", "Hello"), ("color", "couleur")]),
+        ];
+        let icu_locales = locales_to_icu(&locales).unwrap();
+        strip_inherited_values(&mut locales, &BTreeMap::new(), &icu_locales);
+
+        let hello = Key::new("hello").unwrap();
+        let color = Key::new("color").unwrap();
+        // en-US inherits from en, only its override is kept
+        assert_eq!(locales[1].keys[&hello], ParsedValue::Default);
+        assert_ne!(locales[1].keys[&color], ParsedValue::Default);
+        // fr only defaults to en, nothing is stripped
+        assert_ne!(locales[2].keys[&hello], ParsedValue::Default);
+        // the default locale is never stripped
+        assert_ne!(locales[0].keys[&hello], ParsedValue::Default);
+    }
+
+    #[test]
+    fn test_string_indexer_key_paths() {
+        let mut indexer = StringIndexer::default();
+        let mut key_path = KeyPath::new(Some(Key::new("ns").unwrap()));
+        {
+            let pushed_key = key_path.push_key(Key::new("a").unwrap());
+            indexer.set_key_path(&pushed_key);
+            assert_eq!(indexer.push_str("hello"), 0);
+        }
+        {
+            let pushed_key = key_path.push_key(Key::new("b").unwrap());
+            indexer.set_key_path(&pushed_key);
+            assert_eq!(indexer.push_str("hello"), 0);
+            assert_eq!(indexer.push_str("world"), 1);
+        }
+        let (strings, key_strings) = indexer.get_strings();
+        assert_eq!(strings, [Rc::from("hello"), Rc::from("world")]);
+        // a string shared by several keys is recorded for each of them, under their own path
+        assert_eq!(key_strings.len(), 2);
+        assert_eq!(key_strings[&Rc::from("ns::a")], [0]);
+        assert_eq!(key_strings[&Rc::from("ns::b")], [0, 1]);
+    }
+
+    #[test]
+    fn test_string_indexer_no_dedup() {
+        let mut indexer = StringIndexer::new(false);
+        let mut key_path = KeyPath::new(None);
+        for key in ["a", "b"] {
+            let pushed_key = key_path.push_key(Key::new(key).unwrap());
+            indexer.set_key_path(&pushed_key);
+            indexer.push_str("hello");
+        }
+        let (strings, key_strings) = indexer.get_strings();
+        assert_eq!(strings, [Rc::from("hello"), Rc::from("hello")]);
+        assert_eq!(key_strings[&Rc::from("a")], [0]);
+        assert_eq!(key_strings[&Rc::from("b")], [1]);
+    }
 }
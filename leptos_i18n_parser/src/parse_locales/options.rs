@@ -38,6 +38,9 @@ pub struct ParseOptions {
     pub suppress_key_warnings: bool,
     pub interpolate_display: bool,
     pub show_keys_only: bool,
+    pub runtime_fallback: bool,
+    /// Identical strings are stored once, disabled to replace the strings of a single key at runtime.
+    pub deduplicate_strings: bool,
     pub formatters: Formatters,
 }
 
@@ -77,6 +80,8 @@ impl ParseOptions {
             suppress_key_warnings: false,
             interpolate_display: false,
             show_keys_only: false,
+            runtime_fallback: false,
+            deduplicate_strings: true,
             formatters: Formatters::new(),
         }
     }
@@ -109,6 +114,20 @@ impl ParseOptions {
         }
     }
 
+    pub fn runtime_fallback(self, runtime_fallback: bool) -> Self {
+        Self {
+            runtime_fallback,
+            ..self
+        }
+    }
+
+    pub fn deduplicate_strings(self, deduplicate_strings: bool) -> Self {
+        Self {
+            deduplicate_strings,
+            ..self
+        }
+    }
+
     pub fn with_custom_parser<P: Parser>(self, parser: P) -> Self {
         Self {
             file_format: FileFormat::Custom(Arc::new(parser)),
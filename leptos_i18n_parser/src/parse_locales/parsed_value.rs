@@ -1,4 +1,9 @@
-use std::{cell::RefCell, collections::BTreeMap, fmt::Display, mem};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    mem,
+};
 
 use serde::{
     Deserialize,
@@ -15,8 +20,8 @@ use super::{
     ForeignKeysPaths, StringIndexer,
     error::{Diagnostics, Error, Result},
     locale::{
-        DefaultTo, DefaultedLocales, InterpolOrLit, InterpolationKeys, LiteralType, Locale,
-        LocaleSeed, LocaleValue, LocalesOrNamespaces, RangeOrPlural,
+        AttributeType, DefaultTo, DefaultedLocales, InterpolOrLit, InterpolationKeys, LiteralType,
+        Locale, LocaleSeed, LocaleValue, LocalesOrNamespaces, RangeOrPlural,
     },
     plurals::Plurals,
     ranges::Ranges,
@@ -161,6 +166,9 @@ impl ParsedValue {
         ParsedValue::find_variable,
     ];
 
+    /// Parse functions for text that can't contain components, such as the text of Markdown keys.
+    pub const TEXT_FNS: &[ParseFn] = &[ParsedValue::find_foreign_key, ParsedValue::find_variable];
+
     pub fn new(ctx: &ParseContext, value: &str) -> Result<Self> {
         let parsed_value = ctx.parse_fns.iter().find_map(|f| f(ctx, value));
         match parsed_value {
@@ -874,7 +882,9 @@ impl ParsedValue {
                     top_locale_name: top_locale.clone(),
                     name: default_locale.name.clone(),
                     keys: dummy_keys,
+                    markdown_keys: BTreeSet::new(),
                     strings: vec![],
+                    key_strings: BTreeMap::new(),
                     top_locale_string_count: 0,
                 };
                 *this = ParsedValue::Subkeys(None);
@@ -909,6 +919,7 @@ impl ParsedValue {
                     ..
                 },
             ) => {
+                strings.set_key_path(key_path);
                 lit.index_strings(strings);
                 let other_lit_type = match interpol_or_lit {
                     InterpolOrLit::Interpol(_) => return Ok(()),
@@ -935,6 +946,7 @@ impl ParsedValue {
                     ..
                 },
             ) => {
+                strings.set_key_path(key_path);
                 self.index_strings(strings);
                 self.get_keys_inner(key_path, interpol_or_lit, false)
             }
@@ -1080,6 +1092,7 @@ impl ParsedValue {
             }
             ParsedValue::Default => Err(Error::ExplicitDefaultInDefault(key_path.clone()).into()),
             this => {
+                strings.set_key_path(key_path);
                 this.index_strings(strings);
                 this.get_keys(key_path).map(|value| LocaleValue::Value {
                     value,
@@ -1094,6 +1107,18 @@ impl ParsedValue {
         key_path: &mut KeyPath,
         keys: &mut InterpolOrLit,
         is_top: bool,
+    ) -> Result<()> {
+        self.get_keys_nested(key_path, keys, is_top, false)
+    }
+
+    /// `nested` is `true` when the value is in the children of a component,
+    /// the attributes variables are then captured by the children closure.
+    pub(crate) fn get_keys_nested(
+        &self,
+        key_path: &mut KeyPath,
+        keys: &mut InterpolOrLit,
+        is_top: bool,
+        nested: bool,
     ) -> Result<()> {
         match self {
             ParsedValue::Literal(lit_type) if is_top => {
@@ -1110,13 +1135,20 @@ impl ParsedValue {
                 attributes,
             } => {
                 if let Some(inner) = inner {
-                    inner.get_keys_inner(key_path, keys, false)?;
+                    inner.get_keys_nested(key_path, keys, false, true)?;
                     keys.get_interpol_keys_mut().push_comp(key.clone());
                 } else {
                     keys.get_interpol_keys_mut()
                         .push_comp_self_closed(key.clone());
                 }
-                attributes.get_keys_inner(key_path, keys)?;
+                attributes.get_keys_inner(key_path, keys, nested)?;
+                keys.get_interpol_keys_mut().push_comp_attributes(
+                    key,
+                    attributes
+                        .0
+                        .iter()
+                        .map(|attr| (attr.key.as_str(), attr.get_type())),
+                );
             }
             ParsedValue::Dummy(dummies) => {
                 let interpol_keys = keys.get_interpol_keys_mut();
@@ -1133,11 +1165,11 @@ impl ParsedValue {
             }
             ParsedValue::Bloc(values) => {
                 for value in values {
-                    value.get_keys_inner(key_path, keys, false)?;
+                    value.get_keys_nested(key_path, keys, false, nested)?;
                 }
             }
             ParsedValue::Ranges(ranges) => {
-                ranges.get_keys_inner(key_path, keys)?;
+                ranges.get_keys_inner(key_path, keys, nested)?;
                 let range_type = ranges.get_type();
                 keys.get_interpol_keys_mut().push_count(
                     key_path,
@@ -1149,7 +1181,7 @@ impl ParsedValue {
                 foreign_key
                     .borrow()
                     .as_inner("get_keys_inner")
-                    .get_keys_inner(key_path, keys, false)?;
+                    .get_keys_nested(key_path, keys, false, nested)?;
             }
             ParsedValue::Plurals(Plurals {
                 forms,
@@ -1163,9 +1195,9 @@ impl ParsedValue {
                     count_key.clone(),
                 )?;
                 for value in forms.values() {
-                    value.get_keys_inner(key_path, keys, false)?;
+                    value.get_keys_nested(key_path, keys, false, nested)?;
                 }
-                other.get_keys_inner(key_path, keys, false)?;
+                other.get_keys_nested(key_path, keys, false, nested)?;
             }
         }
         Ok(())
@@ -1268,9 +1300,14 @@ impl Attributes {
         }
     }
 
-    pub fn get_keys_inner(&self, key_path: &mut KeyPath, keys: &mut InterpolOrLit) -> Result<()> {
+    pub fn get_keys_inner(
+        &self,
+        key_path: &mut KeyPath,
+        keys: &mut InterpolOrLit,
+        nested: bool,
+    ) -> Result<()> {
         for attr in &self.0 {
-            attr.get_keys_inner(key_path, keys)?;
+            attr.get_keys_inner(key_path, keys, nested)?;
         }
         Ok(())
     }
@@ -1343,9 +1380,22 @@ impl Attribute {
         }
     }
 
-    pub fn get_keys_inner(&self, key_path: &mut KeyPath, keys: &mut InterpolOrLit) -> Result<()> {
+    pub fn get_type(&self) -> AttributeType {
+        match &self.value {
+            None => AttributeType::Literal(LiteralType::Bool),
+            Some(AttributeValue::Literal(lit)) => AttributeType::Literal(lit.get_type()),
+            Some(AttributeValue::Variable(key)) => AttributeType::Variable(key.clone()),
+        }
+    }
+
+    pub fn get_keys_inner(
+        &self,
+        key_path: &mut KeyPath,
+        keys: &mut InterpolOrLit,
+        nested: bool,
+    ) -> Result<()> {
         if let Some(value) = &self.value {
-            value.get_keys_inner(key_path, keys)
+            value.get_keys_inner(key_path, keys, nested)
         } else {
             Ok(())
         }
@@ -1399,12 +1449,21 @@ impl AttributeValue {
         Ok(())
     }
 
-    pub fn get_keys_inner(&self, _key_path: &mut KeyPath, keys: &mut InterpolOrLit) -> Result<()> {
+    pub fn get_keys_inner(
+        &self,
+        _key_path: &mut KeyPath,
+        keys: &mut InterpolOrLit,
+        nested: bool,
+    ) -> Result<()> {
         match self {
             AttributeValue::Literal(_) => Ok(()),
             AttributeValue::Variable(key) => {
-                keys.get_interpol_keys_mut()
-                    .push_var(key.clone(), VarBounds::AttributeValue);
+                let bounds = if nested {
+                    VarBounds::NestedAttributeValue
+                } else {
+                    VarBounds::AttributeValue
+                };
+                keys.get_interpol_keys_mut().push_var(key.clone(), bounds);
                 Ok(())
             }
         }
@@ -1415,6 +1474,8 @@ impl AttributeValue {
 pub struct ParsedValueSeed<'a> {
     pub top_locale_name: &'a Key,
     pub in_range: bool,
+    /// The value is parsed as Markdown, see the `markdown` module.
+    pub markdown: bool,
     pub key_path: &'a KeyPath,
     pub key: &'a Key,
     pub foreign_keys_paths: &'a ForeignKeysPaths,
@@ -1450,6 +1511,13 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             formatters: self.formatters,
             parse_fns: ParsedValue::DEFAULT_FNS,
         };
+        #[cfg(feature = "markdown")]
+        let pv = if self.markdown {
+            super::markdown::parse_markdown(&ctx, v)
+        } else {
+            ParsedValue::new(&ctx, v)
+        };
+        #[cfg(not(feature = "markdown"))]
         let pv = ParsedValue::new(&ctx, v);
 
         let pv = match pv {
@@ -1498,6 +1566,12 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         if self.in_range {
             return Err(serde::de::Error::custom(Error::RangeSubkeys));
         }
+        if self.markdown {
+            return Err(serde::de::Error::custom(Error::InvalidMarkdown {
+                loc: Location::new(self.top_locale_name.clone(), self.key_path.clone()),
+                err: "markdown keys can't have subkeys".to_owned(),
+            }));
+        }
 
         let map_de = MapAccessDeserializer::new(map);
 
@@ -1779,4 +1853,76 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn comp_attributes_across_values() {
+        let mut key_path = KeyPath::new(None);
+        let mut keys = InterpolOrLit::Lit(LiteralType::String);
+        new_parsed_value("<a href=\"/en\" title=\"Home\">home</a>")
+            .get_keys_inner(&mut key_path, &mut keys, true)
+            .unwrap();
+        new_parsed_value("<a href=\"/fr\">accueil</a>")
+            .get_keys_inner(&mut key_path, &mut keys, true)
+            .unwrap();
+
+        let attrs = keys
+            .is_interpol()
+            .unwrap()
+            .get_comp_attributes(&new_key("comp_a"))
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        let string_type = AttributeType::Literal(LiteralType::String);
+        assert_eq!(
+            attrs,
+            vec![("href", &string_type, false), ("title", &string_type, true)]
+        );
+
+        new_parsed_value("<a href={{ url }}>accueil</a>")
+            .get_keys_inner(&mut key_path, &mut keys, true)
+            .unwrap();
+        let comp_attrs = keys
+            .is_interpol()
+            .unwrap()
+            .get_comp_attributes(&new_key("comp_a"))
+            .unwrap();
+        assert_eq!(
+            comp_attrs.iter().collect::<Vec<_>>(),
+            vec![("title", &string_type, true)]
+        );
+        assert_eq!(
+            comp_attrs.conflicts().collect::<Vec<_>>(),
+            vec![(
+                "href",
+                &string_type,
+                &AttributeType::Variable(new_key("var_url"))
+            )]
+        );
+    }
+
+    #[test]
+    fn nested_attribute_bounds() {
+        let mut key_path = KeyPath::new(None);
+        let mut keys = InterpolOrLit::Lit(LiteralType::String);
+        new_parsed_value("<a href={{ top }}>top</a> <p><a href={{ nested }}>nested</a></p>")
+            .get_keys_inner(&mut key_path, &mut keys, true)
+            .unwrap();
+
+        let bounds = keys
+            .is_interpol()
+            .unwrap()
+            .iter_vars()
+            .map(|(key, infos)| (key.name.to_string(), infos.bounds.iter().cloned().collect()))
+            .collect::<Vec<(String, Vec<VarBounds>)>>();
+        assert_eq!(
+            bounds,
+            vec![
+                (
+                    "var_nested".to_string(),
+                    vec![VarBounds::NestedAttributeValue]
+                ),
+                ("var_top".to_string(), vec![VarBounds::AttributeValue]),
+            ]
+        );
+    }
 }
@@ -701,28 +701,34 @@ impl Ranges {
         }
     }
 
-    pub fn get_keys_inner(&self, key_path: &mut KeyPath, keys: &mut InterpolOrLit) -> Result<()> {
+    pub fn get_keys_inner(
+        &self,
+        key_path: &mut KeyPath,
+        keys: &mut InterpolOrLit,
+        nested: bool,
+    ) -> Result<()> {
         fn inner<T>(
             v: &RangesInner<T>,
             key_path: &mut KeyPath,
             keys: &mut InterpolOrLit,
+            nested: bool,
         ) -> Result<()> {
             for (_, value) in v {
-                value.get_keys_inner(key_path, keys, false)?;
+                value.get_keys_nested(key_path, keys, false, nested)?;
             }
             Ok(())
         }
         match &self.inner {
-            UntypedRangesInner::I8(v) => inner(v, key_path, keys),
-            UntypedRangesInner::I16(v) => inner(v, key_path, keys),
-            UntypedRangesInner::I32(v) => inner(v, key_path, keys),
-            UntypedRangesInner::I64(v) => inner(v, key_path, keys),
-            UntypedRangesInner::U8(v) => inner(v, key_path, keys),
-            UntypedRangesInner::U16(v) => inner(v, key_path, keys),
-            UntypedRangesInner::U32(v) => inner(v, key_path, keys),
-            UntypedRangesInner::U64(v) => inner(v, key_path, keys),
-            UntypedRangesInner::F32(v) => inner(v, key_path, keys),
-            UntypedRangesInner::F64(v) => inner(v, key_path, keys),
+            UntypedRangesInner::I8(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::I16(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::I32(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::I64(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::U8(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::U16(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::U32(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::U64(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::F32(v) => inner(v, key_path, keys, nested),
+            UntypedRangesInner::F64(v) => inner(v, key_path, keys, nested),
         }
     }
 
@@ -1,6 +1,8 @@
 pub mod key;
 
-use std::fmt::{Debug, Display};
+use std::fmt::{Debug, Display, Write};
+
+use sha2::{Digest, Sha256};
 
 pub use key::{Key, KeyPath};
 
@@ -13,6 +15,17 @@ use crate::{
     },
 };
 
+/// Hash of some content, the first 8 bytes of its SHA-256 as hex.
+///
+/// Used for the hashed translations file names and the build hash of the translations cached by the client.
+pub fn content_hash(content: &[u8]) -> String {
+    let digest = Sha256::digest(content);
+    digest[..8].iter().fold(String::new(), |mut hash, byte| {
+        let _ = write!(hash, "{byte:02x}");
+        hash
+    })
+}
+
 pub type ParseFn = fn(&ParseContext, &str) -> Option<Result<ParsedValue>>;
 
 #[derive(Clone, Copy)]
@@ -134,6 +134,23 @@ fn test_comp_with_attributes_as_string_with_fn() {
     );
 }
 
+#[test]
+fn test_comp_with_typed_attributes() {
+    let en = td!(Locale::en, comp_with_attrs, <div> = |attrs, children| {
+        leptos::view! { <div id=attrs.id data-foo=attrs.foo>{children()}</div> }
+    }, id = "en");
+    assert_eq_rendered!(en, "<div id=\"en\" data-foo=\"bar\">test</div>");
+    let fr = td!(Locale::fr, comp_with_attrs, <div> = |attrs, children| {
+        let bools = [attrs.bool, attrs.true_bool, attrs.false_bool];
+        let num = attrs.num.map(|num| num.to_string());
+        leptos::view! { <div id=attrs.id data-bools=format!("{bools:?}") data-num=num>{children()}</div> }
+    }, id = "fr");
+    assert_eq_rendered!(
+        fr,
+        "<div id=\"fr\" data-bools=\"[Some(true), Some(true), Some(false)]\" data-num=\"17\">test</div>"
+    );
+}
+
 #[test]
 fn test_comp_with_attributes_self_closed() {
     let en = td!(Locale::en, comp_with_attrs_self_closed, <br/> = <br />, id = "en");
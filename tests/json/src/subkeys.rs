@@ -57,3 +57,27 @@ fn subkey_2_string() {
     let fr = td_string!(Locale::fr, subkeys.subkey_2, <b>);
     assert_eq!(fr, "<span id=\"my_id\">subkey_2</span>");
 }
+
+#[test]
+fn subkeys_entries() {
+    let en: Vec<_> = td_entries!(Locale::en, subkeys).collect();
+    assert_eq!(en, [("iter", "iter"), ("subkey_1", "subkey_1")]);
+    let fr: Vec<_> = td_entries!(Locale::fr, subkeys).collect();
+    assert_eq!(fr, [("iter", "iter"), ("subkey_1", "subkey_1")]);
+}
+
+#[test]
+fn subkey_named_iter() {
+    let en = td!(Locale::en, subkeys.iter);
+    assert_eq_rendered!(en, "iter");
+}
+
+#[test]
+fn subkeys_key_handle() {
+    use leptos_i18n::KeyHandle;
+
+    let handle = keys::subkeys::subkey_1;
+    assert_eq!(handle.translate(Locale::en), "subkey_1");
+    assert_eq!(handle.translate(Locale::fr), "subkey_1");
+    assert_eq!(<keys::subkeys::subkey_1 as KeyHandle>::PATH, "subkeys.subkey_1");
+}
@@ -0,0 +1,6 @@
+#![deny(warnings)]
+#![cfg(test)]
+
+include!(concat!(env!("OUT_DIR"), "/i18n/mod.rs"));
+
+mod tests;
@@ -0,0 +1,114 @@
+use crate::i18n::*;
+
+#[test]
+fn click_to_change_lang() {
+    let en = td_string!(Locale::en, click_to_change_lang);
+    assert_eq!(en, "Click to change language");
+    let fr = td_string!(Locale::fr, click_to_change_lang);
+    assert_eq!(fr, "Cliquez pour changez de langue");
+}
+
+#[test]
+fn click_count() {
+    for count in -5..5 {
+        let en = td_string!(Locale::en, click_count, count);
+        assert_eq!(en, format!("You clicked {} times", count));
+        let fr = td_string!(Locale::fr, click_count, count = count);
+        assert_eq!(fr, format!("Vous avez cliqué {} fois", count));
+    }
+}
+
+#[test]
+fn display() {
+    let count = "whatever impl display";
+    let en = td_display!(Locale::en, click_count, count);
+    assert_eq!(
+        format!("[{}]", en),
+        format!("[You clicked {} times]", count)
+    );
+}
+
+#[test]
+fn subkeys() {
+    let en = td_string!(Locale::en, subkeys.subkey_1);
+    assert_eq!(en, "subkey_1");
+    let en = td_string!(Locale::en, subkeys.subkey_2);
+    assert_eq!(en, "<b>subkey_2</b>");
+    let fr = td_string!(Locale::fr, subkeys.subkey_3, count = 3);
+    assert_eq!(fr, "3");
+}
+
+#[test]
+fn defaulted_string() {
+    let en = td_string!(Locale::en, defaulted_string);
+    assert_eq!(en, "this string is declared in locale en");
+    let fr = td_string!(Locale::fr, defaulted_string);
+    assert_eq!(fr, "this string is declared in locale en");
+}
+
+#[test]
+fn foreign_key_to_interpolation() {
+    let en = td_string!(Locale::en, foreign_key_to_interpolation, count = 4);
+    assert_eq!(en, "before You clicked 4 times after");
+    let fr = td_string!(Locale::fr, foreign_key_to_interpolation, count = 4);
+    assert_eq!(fr, "avant Vous avez cliqué 4 fois après");
+}
+
+#[test]
+fn components() {
+    let en = td_string!(Locale::en, comp_with_attrs, id = "test");
+    assert_eq!(en, "<div id=\"test\" foo=\"bar\">test</div>");
+    let fr = td_string!(Locale::fr, comp_with_attrs, id = 5);
+    assert_eq!(fr, "<div id=\"5\" bool>test</div>");
+    let en = td_string!(Locale::en, self_closed);
+    assert_eq!(en, "hello<br />world");
+}
+
+#[test]
+fn literals() {
+    assert!(Locale::en.get_keys().lit_bool());
+    assert!(!Locale::fr.get_keys().lit_bool());
+    assert_eq!(Locale::en.get_keys().lit_num(), 59);
+    assert_eq!(Locale::fr.get_keys().lit_num(), 17);
+}
+
+#[test]
+fn locale_enum() {
+    assert_eq!(Locale::default(), Locale::en);
+    assert_eq!(Locale::get_all(), &[Locale::en, Locale::fr]);
+    assert_eq!("fr".parse::<Locale>(), Ok(Locale::fr));
+    assert_eq!(Locale::fr.to_string(), "fr");
+    assert_eq!(Locale::en.as_str(), "en");
+}
+
+#[test]
+fn translate_dyn() {
+    let en = Locale::en.translate_dyn("click_to_change_lang");
+    assert_eq!(en.as_deref(), Some("Click to change language"));
+    let fr = Locale::fr.translate_dyn("subkeys.subkey_1");
+    assert_eq!(fr.as_deref(), Some("subkey_1"));
+    let fr = Locale::fr.translate_dyn("defaulted_string");
+    assert_eq!(fr.as_deref(), Some("this string is declared in locale en"));
+    let en = Locale::en.translate_dyn("lit_num");
+    assert_eq!(en.as_deref(), Some("59"));
+    let fr = Locale::fr.translate_dyn("lit_bool");
+    assert_eq!(fr.as_deref(), Some("false"));
+}
+
+#[test]
+fn translate_dyn_with_args() {
+    let en = Locale::en.translate_dyn_with_args("click_count", &[("count", "3")]);
+    assert_eq!(en.as_deref(), Some("You clicked 3 times"));
+    let fr = Locale::fr.translate_dyn_with_args("foreign_key_to_interpolation", &[("count", "3")]);
+    assert_eq!(fr.as_deref(), Some("avant Vous avez cliqué 3 fois après"));
+    let en = Locale::en.translate_dyn("click_count");
+    assert_eq!(en.as_deref(), Some("You clicked {{ count }} times"));
+}
+
+#[test]
+fn translate_dyn_unknown_keys() {
+    assert_eq!(Locale::en.translate_dyn("unknown"), None);
+    assert_eq!(Locale::en.translate_dyn("subkeys"), None);
+    assert_eq!(Locale::en.translate_dyn("subkeys.subkey_2"), None);
+    assert_eq!(Locale::en.translate_dyn("cardinal_plural"), None);
+}
@@ -1,4 +1,4 @@
-use leptos_i18n_build::{Config, ParseOptions, TranslationsInfos};
+use leptos_i18n_build::{Config, ParseOptions, TranslationsInfos, options::CodegenOptions};
 use std::{error::Error, path::PathBuf};
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -20,7 +20,9 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     translations_infos.rerun_if_locales_changed();
 
-    translations_infos.generate_i18n_module(i18n_mod_directory)?;
+    let options = CodegenOptions::new().translate_dyn(true);
+
+    translations_infos.generate_i18n_module_with_options(i18n_mod_directory, options)?;
 
     Ok(())
 }
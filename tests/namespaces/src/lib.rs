@@ -7,3 +7,5 @@ mod first_ns;
 mod scoped;
 #[cfg(test)]
 mod second_ns;
+#[cfg(test)]
+mod translate_dyn;
@@ -0,0 +1,19 @@
+use crate::i18n::*;
+
+#[test]
+fn namespaced_paths() {
+    let en = Locale::en.translate_dyn("first_namespace.common_key");
+    assert_eq!(en.as_deref(), Some("first namespace"));
+    let fr = Locale::fr.translate_dyn("second_namespace::common_key");
+    assert_eq!(fr.as_deref(), Some("deuxième namespace"));
+    assert_eq!(Locale::en.translate_dyn("common_key"), None);
+}
+
+#[test]
+fn subkeys_with_args() {
+    let fr =
+        Locale::fr.translate_dyn_with_args("second_namespace.subkeys.subkey_3", &[("count", "4")]);
+    assert_eq!(fr.as_deref(), Some("4"));
+    let en = Locale::en.translate_dyn("second_namespace.foreign_key_to_another_namespace");
+    assert_eq!(en.as_deref(), Some("before first namespace after"));
+}